@@ -3,273 +3,233 @@ use std::path::Path;
 
 fn main() {
     let openrpc_path = Path::new("../shared/openrpc.json");
-    let out_path = Path::new("src/generated.rs");
 
     println!("cargo:rerun-if-changed={}", openrpc_path.display());
     println!("cargo:rerun-if-changed=build.rs");
 
-    // Read the OpenRPC spec
+    // Read the OpenRPC spec; the types are generated by `types/build.rs`
     let openrpc_content = fs::read_to_string(openrpc_path).expect("Failed to read openrpc.json");
     let openrpc: serde_json::Value =
         serde_json::from_str(&openrpc_content).expect("Failed to parse openrpc.json");
 
-    // Extract the JSON Schema from OpenRPC's components.schemas
     let schemas = openrpc
         .get("components")
         .and_then(|c| c.get("schemas"))
         .expect("OpenRPC must have components.schemas");
 
-    // Create a JSON Schema document with definitions
-    let json_schema = serde_json::json!({
-        "$schema": "http://json-schema.org/draft-07/schema#",
-        "definitions": schemas
-    });
-
-    // Convert $refs from OpenRPC format to JSON Schema format
-    let json_schema_str = serde_json::to_string(&json_schema)
-        .expect("Failed to serialize schema")
-        .replace("#/components/schemas/", "#/definitions/");
-
-    let mut schema: serde_json::Value =
-        serde_json::from_str(&json_schema_str).expect("Failed to parse converted schema");
-
-    // Preprocess: expand allOf[$ref, $ref] into oneOf with cartesian product
-    // This helps typify generate better enum variants instead of merging
-    expand_allof_refs(&mut schema);
-
-    // Generate Rust types with typify
-    let mut type_space = typify::TypeSpace::default();
-    type_space
-        .add_root_schema(serde_json::from_value(schema).expect("Failed to convert schema"))
-        .expect("Failed to add schema to type space");
-
-    let code = type_space.to_stream().to_string();
-
-    // Format with prettyplease
-    let formatted = prettyplease_format(&code).unwrap_or(code);
-
-    // Strip verbose JSON schema doc blocks and fix doctests
-    let stripped = strip_json_schema_docs(&formatted);
-
-    fs::write(out_path, stripped).expect("Failed to write generated.rs");
-}
-
-fn prettyplease_format(code: &str) -> Option<String> {
-    let syntax_tree = syn::parse_file(code).ok()?;
-    Some(prettyplease::unparse(&syntax_tree))
+    // Generate the server-side handler trait from the method list
+    let handler = generate_handler(&openrpc);
+    let formatted = prettyplease_format(&handler).expect("Failed to parse generated handler");
+    fs::write("src/server/handler.rs", formatted).expect("Failed to write server/handler.rs");
+
+    // Generate the method table used by the CLI
+    let methods = generate_methods(&openrpc);
+    let formatted = prettyplease_format(&methods).expect("Failed to parse generated methods");
+    fs::write("src/methods.rs", formatted).expect("Failed to write methods.rs");
+
+    // Embed the schemas, without documentation, for runtime response validation
+    let mut schemas = schemas.clone();
+    strip_schema_docs(&mut schemas);
+    let out_dir = std::env::var("OUT_DIR").expect("cargo sets OUT_DIR");
+    fs::write(
+        Path::new(&out_dir).join("schemas.json"),
+        serde_json::to_string(&schemas).expect("Failed to serialize schemas"),
+    )
+    .expect("Failed to write schemas.json");
 }
 
-/// Expand allOf schemas containing only $ref items into oneOf with cartesian product.
-///
-/// This transforms:
-/// ```json
-/// { "allOf": [{ "$ref": "#/definitions/A" }, { "$ref": "#/definitions/B" }] }
-/// ```
-///
-/// Into an expanded oneOf where A and B's oneOf variants are combined.
-/// This helps typify generate meaningful enum variant names instead of Variant0, Variant1, etc.
-fn expand_allof_refs(schema: &mut serde_json::Value) {
-    if let serde_json::Value::Object(obj) = schema {
-        // First, recursively process nested schemas
-        for (_, v) in obj.iter_mut() {
-            expand_allof_refs(v);
-        }
-
-        // Process definitions
-        if let Some(serde_json::Value::Object(defs)) = obj.get_mut("definitions") {
-            let def_keys: Vec<String> = defs.keys().cloned().collect();
-
-            for key in def_keys {
-                if let Some(def) = defs.get(&key).cloned() {
-                    if let Some(expanded) = try_expand_allof(&def, defs) {
-                        defs.insert(key, expanded);
+/// Remove the keywords that do not affect validation from a schema
+fn strip_schema_docs(schema: &mut serde_json::Value) {
+    match schema {
+        serde_json::Value::Object(map) => {
+            for keyword in ["description", "title", "default"] {
+                map.remove(keyword);
+            }
+            for (keyword, value) in map.iter_mut() {
+                match (keyword.as_str(), value) {
+                    // Keys of these maps are property names, not keywords
+                    ("properties" | "patternProperties", serde_json::Value::Object(properties)) => {
+                        properties.values_mut().for_each(strip_schema_docs)
                     }
+                    (_, value) => strip_schema_docs(value),
                 }
             }
         }
+        serde_json::Value::Array(schemas) => schemas.iter_mut().for_each(strip_schema_docs),
+        _ => {}
     }
 }
 
-/// Try to expand an allOf schema into oneOf with cartesian product.
-/// Returns None if not applicable.
-fn try_expand_allof(
-    schema: &serde_json::Value,
-    definitions: &serde_json::Map<String, serde_json::Value>,
-) -> Option<serde_json::Value> {
-    let obj = schema.as_object()?;
-    let all_of = obj.get("allOf")?.as_array()?;
-
-    // Check if all items are $ref
-    let refs: Vec<&str> = all_of
-        .iter()
-        .filter_map(|item| {
-            item.as_object()
-                .and_then(|o| o.get("$ref"))
-                .and_then(|r| r.as_str())
-        })
-        .collect();
-
-    if refs.len() != all_of.len() || refs.len() < 2 {
-        return None; // Not all items are refs, or too few
-    }
-
-    // Resolve each ref and get its oneOf variants (or treat as single variant)
-    let mut variant_groups: Vec<Vec<serde_json::Value>> = Vec::new();
-
-    for ref_path in &refs {
-        let ref_name = ref_path.strip_prefix("#/definitions/")?;
-        let ref_schema = definitions.get(ref_name)?;
-
-        let variants = if let Some(one_of) = ref_schema.get("oneOf").and_then(|v| v.as_array()) {
-            one_of.clone()
-        } else {
-            // Single variant - wrap in array
-            vec![ref_schema.clone()]
-        };
-
-        variant_groups.push(variants);
-    }
-
-    // Generate cartesian product
-    let mut combined_variants = vec![serde_json::json!({})];
-
-    for group in variant_groups {
-        let mut new_combined = Vec::new();
-        for existing in &combined_variants {
-            for variant in &group {
-                if let Some(merged) = merge_variant_properties(existing, variant) {
-                    new_combined.push(merged);
-                }
-            }
-        }
-        combined_variants = new_combined;
-    }
-
-    // Build the expanded oneOf schema
-    let title = obj.get("title").cloned();
-    let mut result = serde_json::json!({
-        "oneOf": combined_variants
-    });
-
-    if let Some(t) = title {
-        result
-            .as_object_mut()
-            .unwrap()
-            .insert("title".to_string(), t);
-    }
-
-    Some(result)
+/// Schema type name referenced by a method param or result
+fn schema_type_name(schema: &serde_json::Value) -> String {
+    schema
+        .get("$ref")
+        .and_then(|r| r.as_str())
+        .and_then(|r| r.strip_prefix("#/components/schemas/"))
+        .expect("method params and results must reference a schema")
+        .to_string()
 }
 
-/// Merge properties from two variant objects into one.
-fn merge_variant_properties(
-    a: &serde_json::Value,
-    b: &serde_json::Value,
-) -> Option<serde_json::Value> {
-    let a_obj = a.as_object()?;
-    let b_obj = b.as_object()?;
-
-    let mut result = serde_json::Map::new();
-
-    // Merge properties
-    let mut props = serde_json::Map::new();
-    if let Some(a_props) = a_obj.get("properties").and_then(|p| p.as_object()) {
-        for (k, v) in a_props {
-            props.insert(k.clone(), v.clone());
-        }
-    }
-    if let Some(b_props) = b_obj.get("properties").and_then(|p| p.as_object()) {
-        for (k, v) in b_props {
-            props.insert(k.clone(), v.clone());
-        }
-    }
-    if !props.is_empty() {
-        result.insert("properties".to_string(), serde_json::Value::Object(props));
-    }
-
-    // Merge required arrays
-    let mut required: Vec<String> = Vec::new();
-    if let Some(a_req) = a_obj.get("required").and_then(|r| r.as_array()) {
-        for r in a_req {
-            if let Some(s) = r.as_str() {
-                if !required.contains(&s.to_string()) {
-                    required.push(s.to_string());
-                }
-            }
-        }
-    }
-    if let Some(b_req) = b_obj.get("required").and_then(|r| r.as_array()) {
-        for r in b_req {
-            if let Some(s) = r.as_str() {
-                if !required.contains(&s.to_string()) {
-                    required.push(s.to_string());
-                }
-            }
-        }
-    }
-    if !required.is_empty() {
-        result.insert("required".to_string(), serde_json::json!(required));
-    }
-
-    // Generate a title from combined variant titles
-    let a_title = a_obj.get("title").and_then(|t| t.as_str()).unwrap_or("");
-    let b_title = b_obj.get("title").and_then(|t| t.as_str()).unwrap_or("");
-    if !a_title.is_empty() || !b_title.is_empty() {
-        let combined_title = if a_title.is_empty() {
-            b_title.to_string()
-        } else if b_title.is_empty() {
-            a_title.to_string()
-        } else {
-            format!("{}{}", b_title, a_title)
-        };
-        result.insert("title".to_string(), serde_json::json!(combined_title));
+/// Generate the `METHODS` table describing every OpenRPC method.
+fn generate_methods(openrpc: &serde_json::Value) -> String {
+    let methods = openrpc
+        .get("methods")
+        .and_then(|m| m.as_array())
+        .expect("OpenRPC must have methods");
+    let mut entries = String::new();
+    let mut markers = String::new();
+    for method in methods {
+        let name = method["name"].as_str().expect("method must have a name");
+        let summary = method["summary"].as_str().unwrap_or_default();
+        let request = schema_type_name(&method["params"][0]["schema"]);
+        let response = schema_type_name(&method["result"]["schema"]);
+        let deprecated = method["deprecated"].as_bool().unwrap_or(false);
+        entries.push_str(&format!(
+            "Method {{ name: {name:?}, summary: {summary:?}, request: {request:?}, response: {response:?}, deprecated: {deprecated} }},",
+        ));
+
+        let marker: String = name
+            .split('_')
+            .map(|word| {
+                let word = word.to_lowercase();
+                let mut chars = word.chars();
+                chars
+                    .next()
+                    .map(|first| first.to_uppercase().chain(chars).collect())
+                    .unwrap_or_default()
+            })
+            .collect::<Vec<String>>()
+            .concat();
+        let deprecated = if deprecated { " Deprecated." } else { "" };
+        markers.push_str(&format!(
+            "#[doc = {summary:?}]
+            #[doc = \"\"]
+            #[doc = \"JSON-RPC method `{name}`.{deprecated}\"]
+            #[derive(Clone, Copy, Debug)]
+            pub struct {marker};
+
+            impl RpcMethod for {marker} {{
+                const NAME: &'static str = {name:?};
+                type Request = crate::types::{request};
+                type Response = crate::types::{response};
+            }}
+            ",
+        ));
     }
-
-    result.insert("type".to_string(), serde_json::json!("object"));
-
-    Some(serde_json::Value::Object(result))
+    format!(
+        "#![doc = \"Table of the JSON-RPC methods, generated by `build.rs` from `openrpc.json`.\"]
+
+        #[doc = \"A JSON-RPC method\"]
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub struct Method {{
+            #[doc = \"Method name sent in requests, e.g. `EXPERIMENTAL_changes`\"]
+            pub name: &'static str,
+            pub summary: &'static str,
+            #[doc = \"Name of the request type in [`types`](crate::types)\"]
+            pub request: &'static str,
+            #[doc = \"Name of the response type in [`types`](crate::types)\"]
+            pub response: &'static str,
+            pub deprecated: bool,
+        }}
+
+        #[doc = \"Every method of the NEAR JSON-RPC API, in `openrpc.json` order\"]
+        pub const METHODS: &[Method] = &[{entries}];
+
+        #[doc = \"Look up a method by name\"]
+        pub fn find(name: &str) -> Option<&'static Method> {{
+            METHODS.iter().find(|method| method.name == name)
+        }}
+
+        #[doc = \"A JSON-RPC method with its request and response types.\"]
+        #[doc = \"\"]
+        #[doc = \"Every method has a marker type implementing this trait, named after the method, e.g.\"]
+        #[doc = \"[`ExperimentalChanges`] for `EXPERIMENTAL_changes`.\"]
+        pub trait RpcMethod {{
+            #[doc = \"Method name sent in requests\"]
+            const NAME: &'static str;
+            type Request: serde::Serialize;
+            type Response: serde::de::DeserializeOwned;
+        }}
+
+        {markers}
+        "
+    )
 }
 
-/// Strip JSON schema documentation blocks from generated code.
-/// These are collapsible `<details>` blocks containing raw JSON schemas
-/// that bloat the generated file significantly.
-/// Also marks code examples with `ignore` to prevent doctest failures.
-fn strip_json_schema_docs(code: &str) -> String {
-    let mut result = Vec::new();
-    let mut in_details_block = false;
-
-    for line in code.lines() {
-        let trimmed = line.trim();
-
-        // Detect start of JSON schema details block
-        if trimmed.starts_with("///")
-            && trimmed.contains("<details>")
-            && trimmed.contains("JSON schema")
-        {
-            in_details_block = true;
-            continue;
-        }
-
-        // Detect end of details block
-        if in_details_block && trimmed.starts_with("///") && trimmed.contains("</details>") {
-            in_details_block = false;
-            continue;
-        }
-
-        // Skip lines inside details block
-        if in_details_block {
-            continue;
-        }
+fn prettyplease_format(code: &str) -> Option<String> {
+    let syntax_tree = syn::parse_file(code).ok()?;
+    Some(prettyplease::unparse(&syntax_tree))
+}
 
-        // Mark code blocks with `ignore` to prevent doctest failures
-        // (These examples reference external crates like near_account_id)
-        // Handle both /// ``` and bare ``` in block comments
-        if trimmed == "/// ```" || trimmed == "///```" || trimmed == "```" {
-            result.push(line.replace("```", "```ignore"));
-        } else {
-            result.push(line.to_string());
+/// Generate the `NearRpcHandler` trait with one method per OpenRPC method, and the
+/// `route` function dispatching a method name to it.
+///
+/// Every method takes the request type referenced by the method's single param and
+/// defaults to a `METHOD_NOT_FOUND` error.
+fn generate_handler(openrpc: &serde_json::Value) -> String {
+    let methods = openrpc
+        .get("methods")
+        .and_then(|m| m.as_array())
+        .expect("OpenRPC must have methods");
+
+    let mut trait_methods = String::new();
+    let mut routes = String::new();
+    for method in methods {
+        let name = method["name"].as_str().expect("method must have a name");
+        let fn_name = name.to_lowercase();
+        let request = schema_type_name(&method["params"][0]["schema"]);
+        let response = schema_type_name(&method["result"]["schema"]);
+
+        if let Some(summary) = method.get("summary").and_then(|s| s.as_str()) {
+            trait_methods.push_str(&format!("#[doc = {:?}] #[doc = \"\"]", summary));
         }
+        trait_methods.push_str(&format!(
+            "#[doc = {:?}]
+            fn {fn_name}(&self, request: {request}) -> impl Future<Output = Result<{response}, RpcError>> + Send {{
+                let _ = request;
+                async {{ Err(RpcError::method_not_found({name:?})) }}
+            }}",
+            format!("JSON-RPC method `{name}`"),
+        ));
+        routes.push_str(&format!(
+            "{name:?} => super::respond(handler.{fn_name}(super::params(params)?).await),"
+        ));
     }
 
-    result.join("\n")
+    format!(
+        "#![doc = \"Server-side handler trait, generated by `build.rs` from `openrpc.json`.\"]
+        use crate::client::RpcError;
+        use crate::types::*;
+        use serde_json::Value;
+        use std::future::Future;
+
+        #[doc = \"Server-side implementation of the NEAR JSON-RPC methods.\"]
+        #[doc = \"\"]
+        #[doc = \"Every method defaults to a `METHOD_NOT_FOUND` error, so implementations only\"]
+        #[doc = \"override the methods they serve. Serve a handler with a [`Dispatcher`](super::Dispatcher).\"]
+        pub trait NearRpcHandler: Send + Sync {{
+            #[doc = \"Answer a request by method name with its params as sent.\"]
+            #[doc = \"\"]
+            #[doc = \"Defaults to parsing `params` into the method's request type and calling the\"]
+            #[doc = \"typed method below. Override to handle requests without parsing them, e.g. to\"]
+            #[doc = \"forward them in a proxy.\"]
+            fn dispatch(&self, method: &str, params: Value) -> impl Future<Output = Result<Value, RpcError>> + Send
+            where
+                Self: Sized,
+            {{
+                route(self, method, params)
+            }}
+
+            {trait_methods}
+        }}
+
+        #[doc = \"Call the `handler` method for the JSON-RPC `method` with JSON `params`.\"]
+        pub(crate) async fn route<H: NearRpcHandler>(handler: &H, method: &str, params: Value) -> Result<Value, RpcError> {{
+            match method {{
+                {routes}
+                _ => Err(RpcError::method_not_found(method)),
+            }}
+        }}
+        "
+    )
 }
@@ -0,0 +1,190 @@
+//! Capture the light client test fixtures from mainnet.
+//!
+//! Run with:
+//! cargo run --example capture_light_client_fixtures --features light-client -- [<transaction hash> <sender id>]
+//!
+//! Writes:
+//! - `tests/fixtures/block_headers.json`: the last block of an epoch and the first block of
+//!   the next one, each with its light client view
+//! - `tests/fixtures/light_client_blocks.json`: a head about two and a half epochs back, the
+//!   block producers of its epoch, and the two `next_light_client_block` responses that carry
+//!   a light client across the next epoch boundary, with their block hashes
+//! - `tests/fixtures/light_client_proof.json`, given a transaction: its
+//!   `EXPERIMENTAL_light_client_proof`, with the final block used as the light client head
+
+#![cfg_attr(target_arch = "wasm32", allow(dead_code, unused_imports))]
+
+use near_rpc_client::{types::*, NearRpcClient};
+use serde_json::{json, Value};
+
+const ARCHIVAL_RPC: &str = "https://archival-rpc.mainnet.near.org";
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+#[cfg(not(target_arch = "wasm32"))]
+#[tokio::main]
+async fn main() -> Result<()> {
+    let client = NearRpcClient::new(ARCHIVAL_RPC);
+    let head = client
+        .block(RpcBlockRequest::Finality(Finality::Final))
+        .await?
+        .header;
+    println!("Light client head {} at height {}", head.hash, head.height);
+
+    let epoch_end = capture_blocks(&client, &head).await?;
+    capture_headers(&client, &head, epoch_end).await?;
+
+    let mut args = std::env::args().skip(1);
+    let (Some(transaction_hash), Some(sender_id)) = (args.next(), args.next()) else {
+        println!("No transaction given, skipping light_client_proof.json");
+        return Ok(());
+    };
+    let proof = client
+        .light_client_proof(RpcLightClientExecutionProofRequest::Transaction {
+            light_client_head: head.hash.clone(),
+            sender_id: sender_id.parse()?,
+            transaction_hash: transaction_hash.parse()?,
+        })
+        .await?;
+    write(
+        "light_client_proof.json",
+        json!({
+            "source": source(&head),
+            "light_client_head": head,
+            "proof": proof,
+        }),
+    )
+}
+
+/// Captures `light_client_blocks.json`, returning the height of its first block, which is
+/// close to the end of an epoch
+async fn capture_blocks(client: &NearRpcClient, head: &BlockHeaderView) -> Result<u64> {
+    let epoch_length = client
+        .protocol_config(RpcProtocolConfigRequest::Finality(Finality::Final))
+        .await?
+        .epoch_length;
+    let start = block_at(client, head.height - epoch_length * 5 / 2).await?;
+    let start_lite = client
+        .light_client_block_proof(RpcLightClientBlockProofRequest {
+            block_hash: start.hash.clone(),
+            light_client_head: head.hash.clone(),
+        })
+        .await?
+        .block_header_lite;
+    let epoch_block_producers = client
+        .validators_ordered(RpcValidatorsOrderedRequest {
+            block_id: Some(BlockId::CryptoHash(start.hash.clone())),
+        })
+        .await?;
+
+    let mut heights = Vec::new();
+    let mut blocks = Vec::new();
+    let mut block_hashes = Vec::new();
+    let mut last_block_hash = start.hash.clone();
+    for _ in 0..2 {
+        let block = client
+            .next_light_client_block(RpcLightClientNextBlockRequest {
+                last_block_hash: last_block_hash.clone(),
+            })
+            .await?;
+        let height = block.inner_lite.as_ref().ok_or("no inner_lite")?.height;
+        last_block_hash = block_at(client, height).await?.hash;
+        println!("Next light client block {last_block_hash} at height {height}");
+        heights.push(height);
+        blocks.push(block);
+        block_hashes.push(last_block_hash.clone());
+    }
+    write(
+        "light_client_blocks.json",
+        json!({
+            "source": source(head),
+            "head": start_lite,
+            "head_hash": start.hash,
+            "epoch_block_producers": epoch_block_producers,
+            "blocks": blocks,
+            "block_hashes": block_hashes,
+        }),
+    )?;
+    Ok(heights[0])
+}
+
+/// Captures `block_headers.json` at the first epoch boundary after `height`
+async fn capture_headers(
+    client: &NearRpcClient,
+    head: &BlockHeaderView,
+    height: u64,
+) -> Result<()> {
+    let mut last = block_at(client, height).await?;
+    let mut height = last.height;
+    let first = loop {
+        height += 1;
+        // Heights without a block are skipped.
+        let Ok(block) = client
+            .block(RpcBlockRequest::BlockId(BlockId::BlockHeight(height)))
+            .await
+        else {
+            if height > last.height + 10 {
+                return Err(format!("no block found above height {}", last.height).into());
+            }
+            continue;
+        };
+        let block = block.header;
+        if block.epoch_id != last.epoch_id {
+            break block;
+        }
+        last = block;
+    };
+    println!(
+        "Epoch boundary between heights {} and {}",
+        last.height, first.height
+    );
+
+    let mut headers = Vec::new();
+    for header in [last, first] {
+        let lite = client
+            .light_client_block_proof(RpcLightClientBlockProofRequest {
+                block_hash: header.hash.clone(),
+                light_client_head: head.hash.clone(),
+            })
+            .await?
+            .block_header_lite;
+        headers.push(json!({ "header": header, "lite": lite }));
+    }
+    write(
+        "block_headers.json",
+        json!({ "source": source(head), "headers": headers }),
+    )
+}
+
+/// Header of the block at `height`, or of the closest block below it if it was skipped
+async fn block_at(client: &NearRpcClient, height: u64) -> Result<BlockHeaderView> {
+    for height in (height.saturating_sub(10)..=height).rev() {
+        if let Ok(block) = client
+            .block(RpcBlockRequest::BlockId(BlockId::BlockHeight(height)))
+            .await
+        {
+            return Ok(block.header);
+        }
+    }
+    Err(format!("no block found below height {height}").into())
+}
+
+/// Where and when a fixture was captured
+fn source(head: &BlockHeaderView) -> Value {
+    json!({
+        "rpc": ARCHIVAL_RPC,
+        "head_height": head.height,
+        "head_hash": head.hash,
+    })
+}
+
+fn write(name: &str, fixture: Value) -> Result<()> {
+    let path = format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"));
+    std::fs::write(&path, serde_json::to_string_pretty(&fixture)? + "\n")?;
+    println!("Wrote {path}");
+    Ok(())
+}
+
+/// The example needs a `tokio` runtime, which is not available on wasm32
+#[cfg(target_arch = "wasm32")]
+fn main() {}
@@ -2,8 +2,11 @@
 //!
 //! Run with: cargo run --example mainnet
 
+#![cfg_attr(target_arch = "wasm32", allow(dead_code, unused_imports))]
+
 use near_rpc_client::{client::Result, types::*, NearRpcClient};
 
+#[cfg(not(target_arch = "wasm32"))]
 #[tokio::main]
 async fn main() -> Result<()> {
     let client = NearRpcClient::mainnet();
@@ -116,3 +119,7 @@ async fn main() -> Result<()> {
     println!("=== All tests passed! ===");
     Ok(())
 }
+
+/// The example needs a `tokio` runtime, which is not available on wasm32
+#[cfg(target_arch = "wasm32")]
+fn main() {}
@@ -0,0 +1,116 @@
+//! Caching, failing-over JSON-RPC proxy for NEAR RPC nodes.
+//!
+//! ```text
+//! near-rpc-proxy --upstream https://rpc.mainnet.near.org --upstream https://free.rpc.fastnear.com \
+//!     --listen 0.0.0.0:3030 --client-rps 20 --metrics-listen 0.0.0.0:9090
+//! ```
+
+use clap::Parser;
+use metrics_exporter_prometheus::PrometheusBuilder;
+use near_rpc_client::cache::MemoryCache;
+use near_rpc_client::metrics::MetricsFacade;
+use near_rpc_client::proxy::Proxy;
+use near_rpc_client::NearRpcClient;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::net::TcpListener;
+
+#[derive(Parser)]
+#[command(
+    version,
+    about = "Caching, failing-over JSON-RPC proxy for NEAR RPC nodes"
+)]
+struct Args {
+    /// Upstream RPC URL, in order of preference; repeat for failover
+    #[arg(long = "upstream", required = true)]
+    upstreams: Vec<String>,
+
+    /// Address to serve JSON-RPC on
+    #[arg(long, default_value = "127.0.0.1:3030")]
+    listen: SocketAddr,
+
+    /// Maximum number of cached immutable responses; 0 disables caching
+    #[arg(long, default_value_t = 100_000)]
+    cache_entries: usize,
+
+    /// Maximum total size of cached responses in bytes
+    #[arg(long)]
+    cache_bytes: Option<usize>,
+
+    /// Send identical concurrent requests upstream only once
+    #[arg(long, default_value_t = true, action = clap::ArgAction::Set)]
+    coalesce: bool,
+
+    /// Seconds a failed upstream is tried after the healthy ones
+    #[arg(long, default_value_t = 30)]
+    cooldown: u64,
+
+    /// Requests per second allowed per client IP address
+    #[arg(long, value_parser = positive_rate)]
+    client_rps: Option<f64>,
+
+    /// Burst of requests allowed per client IP address; defaults to one second worth
+    #[arg(long)]
+    client_burst: Option<u32>,
+
+    /// Largest accepted request body in bytes
+    #[arg(long, default_value_t = 4 * 1024 * 1024)]
+    max_body_bytes: usize,
+
+    /// Address to serve Prometheus metrics on, at any path
+    #[arg(long)]
+    metrics_listen: Option<SocketAddr>,
+}
+
+fn positive_rate(value: &str) -> Result<f64, String> {
+    match value.parse::<f64>() {
+        Ok(rate) if rate.is_finite() && rate > 0.0 => Ok(rate),
+        Ok(_) => Err("must be a positive number".to_string()),
+        Err(error) => Err(error.to_string()),
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+
+    if let Some(address) = args.metrics_listen {
+        PrometheusBuilder::new()
+            .with_http_listener(address)
+            .install()?;
+    }
+
+    let cache = (args.cache_entries > 0).then(|| {
+        let cache = MemoryCache::new(args.cache_entries);
+        Arc::new(match args.cache_bytes {
+            Some(max_bytes) => cache.with_max_bytes(max_bytes),
+            None => cache,
+        })
+    });
+    let upstreams = args.upstreams.iter().map(|url| {
+        let mut client = NearRpcClient::new(url).with_metrics(MetricsFacade);
+        if let Some(cache) = &cache {
+            client = client.with_cache(cache.clone());
+        }
+        if args.coalesce {
+            client = client.with_coalescing();
+        }
+        client
+    });
+    let mut proxy = Proxy::new(upstreams)
+        .with_cooldown(Duration::from_secs(args.cooldown))
+        .with_max_body_size(args.max_body_bytes);
+    if let Some(rate) = args.client_rps {
+        let burst = args.client_burst.unwrap_or(rate.ceil() as u32);
+        proxy = proxy.with_client_rate_limit(rate, burst);
+    }
+
+    let listener = TcpListener::bind(args.listen).await?;
+    eprintln!("near-rpc-proxy listening on {}", listener.local_addr()?);
+    tokio::select! {
+        _ = proxy.serve(listener) => {}
+        result = tokio::signal::ctrl_c() => result?,
+    }
+    Ok(())
+}
@@ -0,0 +1,600 @@
+//! Command-line client for the NEAR JSON-RPC API.
+//!
+//! ```text
+//! near-rpc block --finality final
+//! near-rpc --network testnet query view-account wrap.testnet -o table
+//! near-rpc tx 9FtHUFBQsZ2MG77K3x3MJ9wjX3UT8zE1TczCrhZEcG8U miraclx.near
+//! near-rpc EXPERIMENTAL_changes changes_type=account_changes account_ids='["wrap.near"]' finality=final
+//! ```
+//!
+//! Common methods have dedicated subcommands. Every other method of the API is called by
+//! its name, with params given as one JSON object or as `key=value` pairs.
+
+mod output;
+mod repl;
+
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use clap::{Args, Parser, Subcommand};
+use near_rpc_client::client::Error;
+use near_rpc_client::methods::{self, Method};
+use near_rpc_client::NearRpcClient;
+use output::Format;
+use serde_json::{json, Map, Value};
+use std::process::ExitCode;
+
+#[derive(Parser)]
+#[command(
+    name = "near-rpc",
+    version,
+    about = "Call NEAR JSON-RPC methods from the command line",
+    after_help = methods_help()
+)]
+struct Cli {
+    /// Network to connect to: mainnet, testnet, betanet, local, or an RPC URL
+    #[arg(long, short, global = true, default_value = "mainnet")]
+    network: String,
+
+    /// Output format
+    #[arg(long, short, global = true, value_enum, default_value_t)]
+    output: Format,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Block details for a height, hash or finality
+    Block {
+        /// Finality (optimistic, near-final or final), block height or hash
+        #[arg(conflicts_with_all = ["finality", "block_id"])]
+        block: Option<String>,
+        #[command(flatten)]
+        block_ref: BlockRef,
+    },
+    /// Details of a chunk, by hash or by block and shard
+    Chunk {
+        /// Chunk hash
+        #[arg(required_unless_present = "block_id", conflicts_with_all = ["block_id", "shard_id"])]
+        chunk_id: Option<String>,
+        /// Block height or hash
+        #[arg(long, requires = "shard_id")]
+        block_id: Option<String>,
+        #[arg(long)]
+        shard_id: Option<u64>,
+    },
+    /// Gas price at a block, or the latest one
+    GasPrice {
+        /// Block height or hash
+        block_id: Option<String>,
+    },
+    /// View accounts, access keys, contract code and state, or call view functions
+    #[command(subcommand)]
+    Query(Query),
+    /// Status of a transaction
+    Tx {
+        tx_hash: String,
+        /// Account that signed the transaction
+        sender_account_id: String,
+        /// Execution stage to wait for, e.g. FINAL
+        #[arg(long)]
+        wait_until: Option<String>,
+    },
+    /// Send a signed transaction
+    SendTx {
+        /// Base64 encoded signed transaction
+        signed_tx_base64: String,
+        /// Execution stage to wait for, e.g. FINAL
+        #[arg(long)]
+        wait_until: Option<String>,
+    },
+    /// Send a signed transaction without waiting for it (deprecated, use send-tx)
+    BroadcastTxAsync {
+        /// Base64 encoded signed transaction
+        signed_tx_base64: String,
+    },
+    /// Send a signed transaction and wait until it executes (deprecated, use send-tx)
+    BroadcastTxCommit {
+        /// Base64 encoded signed transaction
+        signed_tx_base64: String,
+    },
+    /// Status of the node, including chain ID, latest block and sync status
+    Status,
+    /// Health of the node
+    Health,
+    /// Peers of the node
+    NetworkInfo,
+    /// Validators of an epoch, the current one by default
+    Validators {
+        #[arg(long, conflicts_with = "block_id")]
+        epoch_id: Option<String>,
+        /// Block height or hash in the epoch
+        #[arg(long)]
+        block_id: Option<String>,
+    },
+    /// Receipt by ID
+    Receipt { receipt_id: String },
+    /// Protocol configuration at a block
+    ProtocolConfig(BlockRef),
+    /// Genesis configuration of the network
+    GenesisConfig,
+    /// Configuration of the node
+    ClientConfig,
+    /// Interactive shell with completion, history and variables
+    Repl,
+    #[command(external_subcommand)]
+    Method(Vec<String>),
+}
+
+#[derive(Subcommand)]
+enum Query {
+    /// Account balance, storage usage and code hash
+    ViewAccount {
+        account_id: String,
+        #[command(flatten)]
+        block: BlockRef,
+    },
+    /// Access key of an account
+    ViewAccessKey {
+        account_id: String,
+        /// Public key, e.g. ed25519:...
+        public_key: String,
+        #[command(flatten)]
+        block: BlockRef,
+    },
+    /// All access keys of an account
+    ViewAccessKeyList {
+        account_id: String,
+        #[command(flatten)]
+        block: BlockRef,
+    },
+    /// Contract code of an account, base64 encoded
+    ViewCode {
+        account_id: String,
+        #[command(flatten)]
+        block: BlockRef,
+    },
+    /// Contract state under a key prefix
+    ViewState {
+        account_id: String,
+        /// Key prefix, as UTF-8 text
+        #[arg(long, default_value = "")]
+        prefix: String,
+        /// Include a proof of the state
+        #[arg(long)]
+        include_proof: bool,
+        #[command(flatten)]
+        block: BlockRef,
+    },
+    /// Call a view function; a JSON result is printed decoded
+    CallFunction {
+        account_id: String,
+        method_name: String,
+        /// Arguments as JSON
+        #[arg(default_value = "{}")]
+        args: String,
+        #[command(flatten)]
+        block: BlockRef,
+    },
+}
+
+/// Values of `--finality`
+const FINALITIES: &[&str] = &["optimistic", "near-final", "final"];
+
+/// Block to read from, the latest final one by default
+#[derive(Args)]
+struct BlockRef {
+    /// Finality of the latest block: optimistic, near-final or final
+    #[arg(long, default_value = "final", conflicts_with = "block_id")]
+    finality: String,
+    /// Block height or hash
+    #[arg(long)]
+    block_id: Option<String>,
+}
+
+impl BlockRef {
+    fn params(&self) -> Map<String, Value> {
+        let mut params = Map::new();
+        match &self.block_id {
+            Some(block_id) => params.insert("block_id".to_string(), block_id_value(block_id)),
+            None => params.insert("finality".to_string(), json!(self.finality)),
+        };
+        params
+    }
+}
+
+/// A block height is sent as a number, anything else as a hash
+fn block_id_value(block_id: &str) -> Value {
+    match block_id.parse::<u64>() {
+        Ok(height) => json!(height),
+        Err(_) => json!(block_id),
+    }
+}
+
+fn query(request_type: &str, account_id: &str, block: &BlockRef, fields: Value) -> Value {
+    let mut params = block.params();
+    params.insert("request_type".to_string(), json!(request_type));
+    params.insert("account_id".to_string(), json!(account_id));
+    if let Value::Object(fields) = fields {
+        params.extend(fields);
+    }
+    Value::Object(params)
+}
+
+fn send(signed_tx_base64: &str, wait_until: &Option<String>) -> Value {
+    let mut params = json!({ "signed_tx_base64": signed_tx_base64 });
+    if let Some(wait_until) = wait_until {
+        params["wait_until"] = json!(wait_until);
+    }
+    params
+}
+
+impl Command {
+    /// The method to call and its params
+    fn request(&self) -> Result<(&str, Value), String> {
+        Ok(match self {
+            Command::Block { block, block_ref } => {
+                let params = match block {
+                    Some(finality) if FINALITIES.contains(&finality.as_str()) => {
+                        json!({ "finality": finality })
+                    }
+                    Some(block_id) => json!({ "block_id": block_id_value(block_id) }),
+                    None => Value::Object(block_ref.params()),
+                };
+                ("block", params)
+            }
+            Command::Chunk {
+                chunk_id,
+                block_id,
+                shard_id,
+            } => match (chunk_id, block_id) {
+                (Some(chunk_id), _) => ("chunk", json!({ "chunk_id": chunk_id })),
+                (None, block_id) => (
+                    "chunk",
+                    json!({
+                        "block_id": block_id.as_deref().map(block_id_value),
+                        "shard_id": shard_id,
+                    }),
+                ),
+            },
+            Command::GasPrice { block_id } => (
+                "gas_price",
+                json!({ "block_id": block_id.as_deref().map(block_id_value) }),
+            ),
+            Command::Query(query) => ("query", query.params()?),
+            Command::Tx {
+                tx_hash,
+                sender_account_id,
+                wait_until,
+            } => {
+                let mut params =
+                    json!({ "tx_hash": tx_hash, "sender_account_id": sender_account_id });
+                if let Some(wait_until) = wait_until {
+                    params["wait_until"] = json!(wait_until);
+                }
+                ("tx", params)
+            }
+            Command::SendTx {
+                signed_tx_base64,
+                wait_until,
+            } => ("send_tx", send(signed_tx_base64, wait_until)),
+            Command::BroadcastTxAsync { signed_tx_base64 } => {
+                ("broadcast_tx_async", json!([signed_tx_base64]))
+            }
+            Command::BroadcastTxCommit { signed_tx_base64 } => {
+                ("broadcast_tx_commit", json!([signed_tx_base64]))
+            }
+            Command::Status => ("status", json!({})),
+            Command::Health => ("health", json!({})),
+            Command::NetworkInfo => ("network_info", json!({})),
+            Command::Validators { epoch_id, block_id } => match (epoch_id, block_id) {
+                (Some(epoch_id), _) => ("validators", json!({ "epoch_id": epoch_id })),
+                (None, Some(block_id)) => (
+                    "validators",
+                    json!({ "block_id": block_id_value(block_id) }),
+                ),
+                (None, None) => ("validators", json!("latest")),
+            },
+            Command::Receipt { receipt_id } => {
+                ("EXPERIMENTAL_receipt", json!({ "receipt_id": receipt_id }))
+            }
+            Command::ProtocolConfig(block) => (
+                "EXPERIMENTAL_protocol_config",
+                Value::Object(block.params()),
+            ),
+            Command::GenesisConfig => ("EXPERIMENTAL_genesis_config", json!({})),
+            Command::ClientConfig => ("EXPERIMENTAL_client_config", json!({})),
+            Command::Repl => return Err("the REPL is not an RPC method".to_string()),
+            Command::Method(args) => {
+                let (name, params) = args.split_first().expect("clap passes the subcommand");
+                let method = find_method(name).ok_or_else(|| {
+                    format!("unknown method `{name}`, see `near-rpc --help` for the list")
+                })?;
+                (method.name, method_params(params)?)
+            }
+        })
+    }
+}
+
+impl Query {
+    fn params(&self) -> Result<Value, String> {
+        Ok(match self {
+            Query::ViewAccount { account_id, block } => {
+                query("view_account", account_id, block, json!({}))
+            }
+            Query::ViewAccessKey {
+                account_id,
+                public_key,
+                block,
+            } => query(
+                "view_access_key",
+                account_id,
+                block,
+                json!({ "public_key": public_key }),
+            ),
+            Query::ViewAccessKeyList { account_id, block } => {
+                query("view_access_key_list", account_id, block, json!({}))
+            }
+            Query::ViewCode { account_id, block } => {
+                query("view_code", account_id, block, json!({}))
+            }
+            Query::ViewState {
+                account_id,
+                prefix,
+                include_proof,
+                block,
+            } => query(
+                "view_state",
+                account_id,
+                block,
+                json!({
+                    "prefix_base64": BASE64.encode(prefix),
+                    "include_proof": include_proof,
+                }),
+            ),
+            Query::CallFunction {
+                account_id,
+                method_name,
+                args,
+                block,
+            } => {
+                let args: Value = serde_json::from_str(args)
+                    .map_err(|error| format!("function args must be JSON: {error}"))?;
+                query(
+                    "call_function",
+                    account_id,
+                    block,
+                    json!({
+                        "method_name": method_name,
+                        "args_base64": BASE64.encode(args.to_string()),
+                    }),
+                )
+            }
+        })
+    }
+}
+
+/// Find a method by its exact name, or written as a subcommand, e.g. `experimental-changes`
+fn find_method(name: &str) -> Option<&'static Method> {
+    let normalize = |name: &str| name.to_ascii_lowercase().replace('-', "_");
+    methods::find(name).or_else(|| {
+        let name = normalize(name);
+        methods::METHODS
+            .iter()
+            .find(|method| normalize(method.name) == name)
+    })
+}
+
+/// Params of a method called by name: one JSON object or array, or `key=value` pairs.
+///
+/// Values of pairs are parsed as JSON when they are valid JSON, and taken as strings
+/// otherwise, so `block_id=123` sends a number and `account_id=wrap.near` a string.
+fn method_params(args: &[String]) -> Result<Value, String> {
+    if let [arg] = args {
+        if let Ok(params @ (Value::Object(_) | Value::Array(_))) = serde_json::from_str(arg) {
+            return Ok(params);
+        }
+    }
+    let mut params = Map::new();
+    for arg in args {
+        let (key, value) = arg
+            .split_once('=')
+            .ok_or_else(|| format!("expected JSON params or key=value, got `{arg}`"))?;
+        let value = serde_json::from_str(value).unwrap_or_else(|_| json!(value));
+        params.insert(key.to_string(), value);
+    }
+    Ok(Value::Object(params))
+}
+
+/// Replace the bytes returned by a view function with the JSON or text they encode
+fn decode_function_result(response: &mut Value) {
+    let Some(bytes) = response.get("result").and_then(Value::as_array) else {
+        return;
+    };
+    let Some(bytes) = bytes
+        .iter()
+        .map(|byte| byte.as_u64().and_then(|byte| u8::try_from(byte).ok()))
+        .collect::<Option<Vec<u8>>>()
+    else {
+        return;
+    };
+    if let Ok(value) = serde_json::from_slice(&bytes) {
+        response["result"] = value;
+    } else if let Ok(text) = String::from_utf8(bytes) {
+        response["result"] = json!(text);
+    }
+}
+
+fn methods_help() -> String {
+    let mut help = String::from(
+        "Any other method can be called by name, with JSON params or key=value pairs:\n",
+    );
+    for method in methods::METHODS {
+        let deprecated = if method.deprecated {
+            " (deprecated)"
+        } else {
+            ""
+        };
+        help.push_str(&format!(
+            "  {:<40} {}{deprecated}\n",
+            method.name, method.summary
+        ));
+    }
+    help
+}
+
+fn client(network: &str) -> NearRpcClient {
+    match network {
+        "mainnet" => NearRpcClient::mainnet(),
+        "testnet" => NearRpcClient::testnet(),
+        "betanet" => NearRpcClient::betanet(),
+        "local" => NearRpcClient::local(),
+        url => NearRpcClient::new(url),
+    }
+}
+
+/// Why a command produced no response
+enum Failure {
+    /// The command line does not make a valid request
+    Usage(String),
+    Call(Error),
+}
+
+impl Failure {
+    /// Print to stderr, RPC errors in the output `format`
+    fn print(&self, format: Format) {
+        match self {
+            Failure::Usage(error) => eprintln!("error: {error}"),
+            Failure::Call(Error::Rpc(error)) => {
+                let error = serde_json::to_value(error).expect("RPC errors serialize");
+                eprint!("{}", output::render(&error, format));
+            }
+            Failure::Call(error) => eprintln!("error: {error}"),
+        }
+    }
+}
+
+/// Call the method of `command`
+async fn execute(client: &NearRpcClient, command: &Command) -> Result<Value, Failure> {
+    let (method, params) = command.request().map_err(Failure::Usage)?;
+    let mut response = client
+        .call_raw(method, params)
+        .await
+        .map_err(Failure::Call)?;
+    if matches!(command, Command::Query(Query::CallFunction { .. })) {
+        decode_function_result(&mut response);
+    }
+    Ok(response)
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let client = client(&cli.network);
+    if let Command::Repl = cli.command {
+        return repl::run(client, cli.output).await;
+    }
+
+    match execute(&client, &cli.command).await {
+        Ok(response) => {
+            print!("{}", output::render(&response, cli.output));
+            ExitCode::SUCCESS
+        }
+        Err(failure) => {
+            failure.print(cli.output);
+            match failure {
+                Failure::Usage(_) => ExitCode::from(2),
+                Failure::Call(_) => ExitCode::FAILURE,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(args: &[&str]) -> (String, Value) {
+        let cli = Cli::try_parse_from([&["near-rpc"], args].concat()).unwrap();
+        let (method, params) = cli.command.request().unwrap();
+        (method.to_string(), params)
+    }
+
+    #[test]
+    fn test_requests() {
+        assert_eq!(
+            request(&["block", "--block-id", "123"]),
+            ("block".to_string(), json!({ "block_id": 123 }))
+        );
+        assert_eq!(request(&["block", "123"]).1, json!({ "block_id": 123 }));
+        assert_eq!(
+            request(&["block", "near-final"]).1,
+            json!({ "finality": "near-final" })
+        );
+        assert_eq!(request(&["block"]).1, json!({ "finality": "final" }));
+        assert!(Cli::try_parse_from(["near-rpc", "block", "final", "--block-id", "1"]).is_err());
+        assert_eq!(
+            request(&["query", "view-account", "wrap.near"]),
+            (
+                "query".to_string(),
+                json!({ "request_type": "view_account", "account_id": "wrap.near", "finality": "final" })
+            )
+        );
+        let (_, params) = request(&["query", "call-function", "a.near", "get", r#"{"x":1}"#]);
+        assert_eq!(params["args_base64"], BASE64.encode(r#"{"x":1}"#));
+        assert_eq!(
+            request(&["tx", "hash", "a.near", "--wait-until", "FINAL"]).1,
+            json!({ "tx_hash": "hash", "sender_account_id": "a.near", "wait_until": "FINAL" })
+        );
+        assert_eq!(request(&["validators"]).1, json!("latest"));
+        assert!(Cli::try_parse_from([
+            "near-rpc",
+            "block",
+            "--finality",
+            "final",
+            "--block-id",
+            "1"
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn test_method_by_name() {
+        assert_eq!(
+            request(&[
+                "experimental-changes",
+                "block_id=5",
+                "account_ids=[\"a.near\"]",
+                "changes_type=account_changes"
+            ]),
+            (
+                "EXPERIMENTAL_changes".to_string(),
+                json!({ "block_id": 5, "account_ids": ["a.near"], "changes_type": "account_changes" })
+            )
+        );
+        assert_eq!(
+            request(&["EXPERIMENTAL_receipt", r#"{"receipt_id":"r"}"#]).1,
+            json!({ "receipt_id": "r" })
+        );
+        assert_eq!(
+            request(&["maintenance_windows", "account_id=a.near"]).1["account_id"],
+            "a.near"
+        );
+
+        let cli = Cli::try_parse_from(["near-rpc", "no-such-method"]).unwrap();
+        assert!(cli.command.request().is_err());
+        let cli = Cli::try_parse_from(["near-rpc", "changes", "oops"]).unwrap();
+        assert!(cli.command.request().is_err());
+    }
+
+    #[test]
+    fn test_decode_function_result() {
+        let mut response = json!({ "result": b"{\"a\":1}".to_vec(), "logs": [] });
+        decode_function_result(&mut response);
+        assert_eq!(response["result"], json!({ "a": 1 }));
+        let mut response = json!({ "result": [0xff, 0x00] });
+        decode_function_result(&mut response);
+        assert_eq!(response["result"], json!([0xff, 0x00]));
+    }
+}
@@ -0,0 +1,174 @@
+//! Output formats for responses.
+
+use serde_json::Value;
+
+/// Format of responses printed to stdout
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum Format {
+    /// Indented JSON
+    #[default]
+    Json,
+    /// JSON on a single line
+    Compact,
+    /// Aligned key/value rows, with lists of records as columns
+    Table,
+}
+
+/// Render `value` in `format`, ending with a newline
+pub fn render(value: &Value, format: Format) -> String {
+    match format {
+        Format::Json => serde_json::to_string_pretty(value).expect("JSON values serialize") + "\n",
+        Format::Compact => value.to_string() + "\n",
+        Format::Table => table(value),
+    }
+}
+
+/// Render `value` for humans.
+///
+/// Nested objects are flattened into `path  value` rows, e.g. `header.height  123`. Lists of
+/// flat objects, like validators or chunks, are printed after the rows as column tables
+/// titled by their path.
+pub fn table(value: &Value) -> String {
+    let mut rows = Vec::new();
+    let mut lists = Vec::new();
+    flatten(value, String::new(), &mut rows, &mut lists);
+
+    let mut out = String::new();
+    let width = rows.iter().map(|(key, _)| key.len()).max().unwrap_or(0);
+    for (key, value) in &rows {
+        if key.is_empty() {
+            out.push_str(value);
+        } else {
+            out.push_str(&format!("{key:width$}  {value}"));
+        }
+        out.push('\n');
+    }
+    for (path, records) in lists {
+        if !out.is_empty() {
+            out.push('\n');
+        }
+        if !path.is_empty() {
+            out.push_str(&format!("{path}:\n"));
+        }
+        out.push_str(&columns(records));
+    }
+    out
+}
+
+fn flatten<'a>(
+    value: &'a Value,
+    path: String,
+    rows: &mut Vec<(String, String)>,
+    lists: &mut Vec<(String, &'a [Value])>,
+) {
+    let child = |key: &str| match path.is_empty() {
+        true => key.to_string(),
+        false => format!("{path}.{key}"),
+    };
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map {
+                flatten(value, child(key), rows, lists);
+            }
+        }
+        Value::Array(values) if !values.is_empty() && values.iter().all(is_record) => {
+            lists.push((path, values));
+        }
+        Value::Array(values) if values.iter().all(is_scalar) => {
+            let values: Vec<String> = values.iter().map(scalar).collect();
+            rows.push((path, values.join(", ")));
+        }
+        Value::Array(values) => {
+            for (index, value) in values.iter().enumerate() {
+                flatten(value, format!("{path}[{index}]"), rows, lists);
+            }
+        }
+        value => rows.push((path, scalar(value))),
+    }
+}
+
+fn is_scalar(value: &Value) -> bool {
+    !matches!(value, Value::Object(_) | Value::Array(_))
+}
+
+/// A non-empty object of scalars, printable as a table row
+fn is_record(value: &Value) -> bool {
+    matches!(value, Value::Object(map) if !map.is_empty() && map.values().all(is_scalar))
+}
+
+fn scalar(value: &Value) -> String {
+    match value {
+        Value::String(value) => value.clone(),
+        Value::Null => "-".to_string(),
+        value => value.to_string(),
+    }
+}
+
+fn columns(records: &[Value]) -> String {
+    let mut headers: Vec<&str> = Vec::new();
+    for record in records {
+        for key in record.as_object().into_iter().flat_map(|map| map.keys()) {
+            if !headers.contains(&key.as_str()) {
+                headers.push(key);
+            }
+        }
+    }
+    let cells: Vec<Vec<String>> = records
+        .iter()
+        .map(|record| {
+            let cell = |header: &&str| record.get(*header).map_or("-".to_string(), scalar);
+            headers.iter().map(cell).collect()
+        })
+        .collect();
+    let widths: Vec<usize> = headers
+        .iter()
+        .enumerate()
+        .map(|(i, header)| {
+            let widest = cells.iter().map(|row| row[i].len()).max().unwrap_or(0);
+            widest.max(header.len())
+        })
+        .collect();
+
+    let line = |values: Vec<&str>| {
+        let padded: Vec<String> = values
+            .iter()
+            .zip(&widths)
+            .map(|(value, width)| format!("{value:width$}"))
+            .collect();
+        padded.join("  ").trim_end().to_string() + "\n"
+    };
+    let mut out = line(headers.clone());
+    for row in &cells {
+        out.push_str(&line(row.iter().map(String::as_str).collect()));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_table() {
+        let value = json!({
+            "header": { "height": 123, "hash": "abc", "approvals": [null, "sig"] },
+            "chunks": [
+                { "shard_id": 0, "chunk_hash": "c0" },
+                { "shard_id": 1, "chunk_hash": "c1", "gas_used": 5 },
+            ],
+        });
+        assert_eq!(
+            table(&value),
+            "header.approvals  -, sig\n\
+             header.hash       abc\n\
+             header.height     123\n\
+             \n\
+             chunks:\n\
+             chunk_hash  shard_id  gas_used\n\
+             c0          0         -\n\
+             c1          1         5\n"
+        );
+        assert_eq!(table(&json!("hash")), "hash\n");
+    }
+}
@@ -0,0 +1,441 @@
+//! Interactive shell, `near-rpc repl`.
+//!
+//! Lines are the subcommands of `near-rpc`, plus variables:
+//!
+//! ```text
+//! near> $b = block final
+//! near> chunk $b.chunks[0].chunk_hash
+//! near> $b.header.height
+//! ```
+
+use crate::output::{self, Format};
+use crate::{execute, methods_help, Command};
+use clap::{CommandFactory, Parser};
+use near_rpc_client::methods::METHODS;
+use near_rpc_client::NearRpcClient;
+use rustyline::completion::Completer;
+use rustyline::error::ReadlineError;
+use rustyline::history::DefaultHistory;
+use rustyline::{Context, Editor, Helper, Highlighter, Hinter, Validator};
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+/// A line of input to the REPL
+#[derive(Parser)]
+#[command(
+    name = "",
+    no_binary_name = true,
+    after_help = repl_help()
+)]
+struct Line {
+    /// Output format of this command
+    #[arg(long, short, global = true, value_enum)]
+    output: Option<Format>,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+fn repl_help() -> String {
+    "`$name = <command>` stores the response of a command, `$name.field[0]` reuses part of it \
+     in later commands, and `$_` is the last response. `vars` lists the variables, `exit` \
+     quits.\n\n"
+        .to_string()
+        + &methods_help()
+}
+
+/// Words completed at the start of a line besides subcommands and methods
+const BUILTINS: &[&str] = &["exit", "help", "quit", "vars"];
+
+/// Variables and account IDs seen in the session, used for completion
+#[derive(Default, Helper, Hinter, Highlighter, Validator)]
+struct Session {
+    variables: BTreeMap<String, Value>,
+    accounts: BTreeSet<String>,
+}
+
+impl Session {
+    /// Value at a path like `$b.chunks[0].chunk_hash`, without the `$`
+    fn lookup(&self, path: &str) -> Result<&Value, String> {
+        let mut segments = path_segments(path);
+        let name = segments.next().unwrap_or_default();
+        let mut value = self
+            .variables
+            .get(name)
+            .ok_or_else(|| format!("`${name}` is not set"))?;
+        for segment in segments {
+            value = match segment.parse::<usize>() {
+                Ok(index) if value.is_array() => value.get(index),
+                _ => value.get(segment),
+            }
+            .ok_or_else(|| format!("`${path}` does not exist"))?;
+        }
+        Ok(value)
+    }
+
+    /// Remember the account IDs in a request or response for completion
+    fn collect_accounts(&mut self, value: &Value) {
+        match value {
+            Value::Object(map) => {
+                for (key, value) in map {
+                    match value {
+                        Value::String(account) if is_account_field(key) => {
+                            self.accounts.insert(account.clone());
+                        }
+                        value => self.collect_accounts(value),
+                    }
+                }
+            }
+            Value::Array(values) => values.iter().for_each(|value| self.collect_accounts(value)),
+            _ => {}
+        }
+    }
+
+    /// Split a line into arguments, substituting variables.
+    ///
+    /// Arguments are separated by whitespace and may be quoted with `'` or `"`. Variables
+    /// are substituted outside single quotes; strings are inserted as is and other values
+    /// as JSON.
+    fn split(&self, line: &str) -> Result<Vec<String>, String> {
+        let mut args = Vec::new();
+        let mut arg: Option<String> = None;
+        let mut quote = None;
+        let mut chars = line.char_indices();
+        while let Some((i, c)) = chars.next() {
+            match (quote, c) {
+                (Some(q), c) if c == q => quote = None,
+                (None, '\'' | '"') => {
+                    quote = Some(c);
+                    arg.get_or_insert_with(String::new);
+                }
+                (None, c) if c.is_whitespace() => args.extend(arg.take()),
+                (Some('\''), c) => arg.get_or_insert_with(String::new).push(c),
+                (_, '$') => {
+                    let len = path_len(&line[i + 1..]);
+                    let path = &line[i + 1..i + 1 + len];
+                    let text = match self.lookup(path)? {
+                        Value::String(text) => text.clone(),
+                        value => value.to_string(),
+                    };
+                    arg.get_or_insert_with(String::new).push_str(&text);
+                    for _ in 0..len {
+                        chars.next();
+                    }
+                }
+                (_, c) => arg.get_or_insert_with(String::new).push(c),
+            }
+        }
+        if quote.is_some() {
+            return Err("unterminated quote".to_string());
+        }
+        args.extend(arg);
+        Ok(args)
+    }
+
+    /// Words that can complete `word`, the word under the cursor after `previous` words
+    fn candidates(&self, previous: &[&str], word: &str) -> Vec<String> {
+        let line = Line::command();
+        let words: Vec<String> = if let Some(path) = word.strip_prefix('$') {
+            match path.rsplit_once('.') {
+                Some((parent, _)) => match self.lookup(parent) {
+                    Ok(Value::Object(map)) => {
+                        map.keys().map(|key| format!("${parent}.{key}")).collect()
+                    }
+                    _ => Vec::new(),
+                },
+                None => self
+                    .variables
+                    .keys()
+                    .map(|name| format!("${name}"))
+                    .collect(),
+            }
+        } else if word.starts_with('-') {
+            let mut command = &line;
+            for name in previous {
+                match command.find_subcommand(name) {
+                    Some(subcommand) => command = subcommand,
+                    None => break,
+                }
+            }
+            command
+                .get_arguments()
+                .chain(line.get_arguments())
+                .filter_map(|arg| arg.get_long())
+                .map(|long| format!("--{long}"))
+                .collect()
+        } else {
+            match previous {
+                [] => {
+                    let subcommands = line.get_subcommands().map(|command| command.get_name());
+                    let methods = METHODS.iter().map(|method| method.name);
+                    let builtins = BUILTINS.iter().copied();
+                    subcommands
+                        .chain(methods)
+                        .chain(builtins)
+                        .map(String::from)
+                        .collect()
+                }
+                [name] => match line.find_subcommand(name) {
+                    Some(command) if command.has_subcommands() => command
+                        .get_subcommands()
+                        .map(|command| command.get_name().to_string())
+                        .collect(),
+                    _ => self.accounts.iter().cloned().collect(),
+                },
+                _ => self.accounts.iter().cloned().collect(),
+            }
+        };
+        let mut words: Vec<String> = words
+            .into_iter()
+            .filter(|candidate| candidate.starts_with(word))
+            .collect();
+        words.sort();
+        words.dedup();
+        words
+    }
+}
+
+impl Completer for Session {
+    type Candidate = String;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _ctx: &Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<String>)> {
+        let line = &line[..pos];
+        let mut start = line.rfind(char::is_whitespace).map_or(0, |i| i + 1);
+        let mut previous: Vec<&str> = line[..start].split_whitespace().collect();
+        if let [variable, "=", ..] = previous[..] {
+            if variable.starts_with('$') {
+                previous.drain(..2);
+            }
+        }
+        // Complete the value of `key=value` params
+        if !line[start..].starts_with('-') && !line[start..].starts_with('$') {
+            if let Some(i) = line[start..].find('=') {
+                start += i + 1;
+                previous.push("=");
+            }
+        }
+        Ok((start, self.candidates(&previous, &line[start..])))
+    }
+}
+
+/// Whether a field of requests or responses holds an account ID
+fn is_account_field(key: &str) -> bool {
+    key.ends_with("account_id")
+        || matches!(
+            key,
+            "signer_id" | "receiver_id" | "predecessor_id" | "author" | "proposer"
+        )
+}
+
+/// Length of the variable path at the start of `text`, e.g. `b.chunks[0].chunk_hash`
+fn path_len(text: &str) -> usize {
+    text.find(|c: char| !(c.is_alphanumeric() || matches!(c, '_' | '.' | '[' | ']')))
+        .unwrap_or(text.len())
+}
+
+/// Variable name and field names or indexes of a path
+fn path_segments(path: &str) -> impl Iterator<Item = &str> {
+    path.split(['.', '[', ']'])
+        .filter(|segment| !segment.is_empty())
+}
+
+/// `$name = command` assignments
+fn assignment(line: &str) -> Option<(&str, &str)> {
+    let (variable, command) = line.split_once('=')?;
+    let name = variable.trim().strip_prefix('$')?;
+    let valid = !name.is_empty() && name.chars().all(|c| c.is_alphanumeric() || c == '_');
+    (valid && !command.starts_with('=')).then_some((name, command.trim()))
+}
+
+fn history_path() -> Option<PathBuf> {
+    std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".near_rpc_history"))
+}
+
+/// Run the REPL until `exit` or end of input
+pub async fn run(client: NearRpcClient, format: Format) -> ExitCode {
+    let mut editor = match Editor::<Session, DefaultHistory>::new() {
+        Ok(editor) => editor,
+        Err(error) => {
+            eprintln!("error: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    editor.set_helper(Some(Session::default()));
+    let history = history_path();
+    if let Some(history) = &history {
+        let _ = editor.load_history(history);
+    }
+
+    loop {
+        let input = match editor.readline("near> ") {
+            Ok(input) => input,
+            Err(ReadlineError::Interrupted) => continue,
+            Err(ReadlineError::Eof) => break,
+            Err(error) => {
+                eprintln!("error: {error}");
+                break;
+            }
+        };
+        let input = input.trim();
+        if input.is_empty() {
+            continue;
+        }
+        let _ = editor.add_history_entry(input);
+        let session = editor.helper_mut().expect("the session is set");
+
+        match input {
+            "exit" | "quit" => break,
+            "vars" => {
+                for (name, value) in &session.variables {
+                    println!("${name} = {}", summary(value));
+                }
+                continue;
+            }
+            _ => {}
+        }
+
+        let (variable, command) = match assignment(input) {
+            Some((variable, command)) => (Some(variable), command),
+            None => (None, input),
+        };
+        let args = match session.split(command) {
+            Ok(args) => args,
+            Err(error) => {
+                eprintln!("error: {error}");
+                continue;
+            }
+        };
+        // A lone variable prints its value
+        if let (None, Some(path)) = (variable, command.strip_prefix('$')) {
+            if let Ok(value) = session.lookup(path) {
+                print!("{}", output::render(value, format));
+                continue;
+            }
+        }
+        let line = match Line::try_parse_from(&args) {
+            Ok(line) => line,
+            Err(error) => {
+                let _ = error.print();
+                continue;
+            }
+        };
+        let format = line.output.unwrap_or(format);
+
+        match execute(&client, &line.command).await {
+            Ok(response) => {
+                if let Ok((_, params)) = line.command.request() {
+                    session.collect_accounts(&params);
+                }
+                session.collect_accounts(&response);
+                match variable {
+                    Some(variable) => {
+                        session
+                            .variables
+                            .insert(variable.to_string(), response.clone());
+                    }
+                    None => print!("{}", output::render(&response, format)),
+                }
+                session.variables.insert("_".to_string(), response);
+            }
+            Err(failure) => failure.print(format),
+        }
+    }
+
+    if let Some(history) = &history {
+        let _ = editor.save_history(history);
+    }
+    ExitCode::SUCCESS
+}
+
+/// One-line description of a variable for `vars`
+fn summary(value: &Value) -> String {
+    match value {
+        Value::Object(map) => {
+            let keys: Vec<&str> = map.keys().map(String::as_str).collect();
+            format!("{{ {} }}", keys.join(", "))
+        }
+        Value::Array(values) => format!("[{} items]", values.len()),
+        value => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn session() -> Session {
+        let mut session = Session::default();
+        let block = json!({
+            "author": "node0",
+            "header": { "height": 9 },
+            "chunks": [{ "chunk_hash": "Ch1" }, { "chunk_hash": "Ch2" }],
+        });
+        session.collect_accounts(&block);
+        session.variables.insert("b".to_string(), block);
+        session
+    }
+
+    #[test]
+    fn test_split() {
+        let session = session();
+        assert_eq!(
+            session.split("chunk $b.chunks[1].chunk_hash").unwrap(),
+            ["chunk", "Ch2"]
+        );
+        assert_eq!(
+            session
+                .split(r#"block --block-id=$b.header.height 'a $b' "x y""#)
+                .unwrap(),
+            ["block", "--block-id=9", "a $b", "x y"]
+        );
+        assert_eq!(session.split("'' ").unwrap(), [""]);
+        assert!(session.split("chunk $c").is_err());
+        assert!(session.split("chunk $b.chunks[2]").is_err());
+        assert!(session.split("query 'x").is_err());
+    }
+
+    #[test]
+    fn test_assignment() {
+        assert_eq!(assignment("$b = block final"), Some(("b", "block final")));
+        assert_eq!(assignment("changes block_id=1"), None);
+        assert_eq!(assignment("$b == 1"), None);
+    }
+
+    #[test]
+    fn test_complete() {
+        let session = session();
+        let history = DefaultHistory::new();
+        let ctx = Context::new(&history);
+        let complete = |line: &str| session.complete(line, line.len(), &ctx).unwrap();
+
+        let (start, words) = complete("$x = EXPERIMENTAL_rec");
+        assert_eq!(
+            (start, words),
+            (5, vec!["EXPERIMENTAL_receipt".to_string()])
+        );
+        assert!(complete("gas").1.contains(&"gas_price".to_string()));
+        assert!(complete("gas").1.contains(&"gas-price".to_string()));
+        assert_eq!(
+            complete("query view-acc").1,
+            ["view-access-key", "view-access-key-list", "view-account"]
+        );
+        assert_eq!(complete("block --fin").1, ["--finality"]);
+        assert_eq!(complete("chunk $b.ch").1, ["$b.chunks"]);
+        assert_eq!(
+            complete("query view-account no"),
+            (19, vec!["node0".to_string()])
+        );
+        assert_eq!(
+            complete("changes account_id=n"),
+            (19, vec!["node0".to_string()])
+        );
+    }
+}
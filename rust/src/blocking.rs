@@ -0,0 +1,407 @@
+//! Blocking JSON-RPC client for NEAR Protocol.
+//!
+//! [`NearRpcBlockingClient`] has the methods of the async
+//! [`NearRpcClient`](crate::NearRpcClient) and returns the same [`Error`], for synchronous
+//! tools and build scripts that should not start an async runtime. It is built on
+//! `reqwest::blocking`, so it must not be used from within an async runtime.
+//!
+//! The async client's cache, coalescing, rate limiting and metrics are not available here.
+//!
+//! ```no_run
+//! use near_rpc_client::NearRpcBlockingClient;
+//!
+//! fn main() -> near_rpc_client::blocking::Result<()> {
+//!     let client = NearRpcBlockingClient::mainnet();
+//!     let status = client.status()?;
+//!     println!("Chain ID: {}", status.chain_id);
+//!     Ok(())
+//! }
+//! ```
+
+use crate::jsonrpc::{self, RpcRequest};
+use crate::methods::RpcMethod;
+use crate::types::*;
+use crate::validation::{self, Validation};
+use reqwest::blocking::Client;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+pub use crate::jsonrpc::{
+    DecodeError, Error, HttpErrorKind, Lenient, Result, RpcError, RpcErrorCause, WithRaw,
+};
+
+/// Blocking NEAR RPC client
+pub struct NearRpcBlockingClient {
+    client: Client,
+    url: String,
+    request_id: AtomicU64,
+    validation: Option<Validation>,
+    validation_warnings: validation::Warnings,
+}
+
+impl NearRpcBlockingClient {
+    /// Create a new client with custom URL
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            client: Client::new(),
+            url: url.into(),
+            request_id: AtomicU64::new(1),
+            validation: None,
+            validation_warnings: Default::default(),
+        }
+    }
+
+    /// Create a client for NEAR Mainnet
+    pub fn mainnet() -> Self {
+        Self::new("https://rpc.mainnet.near.org")
+    }
+
+    /// Create a client for NEAR Testnet
+    pub fn testnet() -> Self {
+        Self::new("https://rpc.testnet.near.org")
+    }
+
+    /// Create a client for NEAR Betanet
+    pub fn betanet() -> Self {
+        Self::new("https://rpc.betanet.near.org")
+    }
+
+    /// Create a client for local development
+    pub fn local() -> Self {
+        Self::new("http://localhost:3030")
+    }
+
+    /// URL of the endpoint
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Check every response against the JSON Schema of its method in `openrpc.json`.
+    ///
+    /// See [`NearRpcClient::with_validation`](crate::NearRpcClient::with_validation).
+    pub fn with_validation(mut self, mode: Validation) -> Self {
+        self.validation = Some(mode);
+        self
+    }
+
+    /// Validation issues of the most recent responses that did not fail their call.
+    ///
+    /// See [`NearRpcClient::take_validation_warnings`](crate::NearRpcClient::take_validation_warnings).
+    pub fn take_validation_warnings(&self) -> Vec<validation::ValidationError> {
+        self.validation_warnings.take()
+    }
+
+    fn call<P: Serialize, R: for<'de> Deserialize<'de>>(
+        &self,
+        method: &'static str,
+        params: P,
+    ) -> Result<R> {
+        let result = self.call_raw(method, serde_json::to_value(params)?)?;
+        Ok(DecodeError::decode(method, &self.url, &result)?)
+    }
+
+    /// Call `method` with JSON `params` and return the JSON `result`.
+    pub fn call_raw(&self, method: &str, params: Value) -> Result<Value> {
+        let id = self.request_id.fetch_add(1, Ordering::Relaxed);
+        let request = RpcRequest::new(id, method, params);
+        let response = self.client.post(&self.url).json(&request).send()?;
+        let status = response.status();
+        let endpoint = response.url().to_string();
+        let headers = response.headers().clone();
+        let body = response.bytes()?;
+        let result = jsonrpc::read_body(method, id, status, endpoint, &headers, &body)?;
+        if let Some(mode) = self.validation {
+            validation::enforce(method, &result, mode, &self.validation_warnings)?;
+        }
+        Ok(result)
+    }
+
+    /// Call the method `M` with its typed request.
+    ///
+    /// ```no_run
+    /// use near_rpc_client::{methods, types::*, NearRpcBlockingClient};
+    ///
+    /// # fn example() -> near_rpc_client::blocking::Result<()> {
+    /// let client = NearRpcBlockingClient::mainnet();
+    /// let request = RpcGasPriceRequest { block_id: None };
+    /// let gas_price = client.request::<methods::GasPrice>(request)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn request<M: RpcMethod>(&self, request: M::Request) -> Result<M::Response> {
+        let result = self.call_raw(M::NAME, serde_json::to_value(request)?)?;
+        Ok(DecodeError::decode(M::NAME, &self.url, &result)?)
+    }
+
+    /// Call the method `M` and return the typed response along with its raw JSON.
+    pub fn request_with_raw<M: RpcMethod>(
+        &self,
+        request: M::Request,
+    ) -> Result<WithRaw<M::Response>> {
+        let raw = self.call_raw(M::NAME, serde_json::to_value(request)?)?;
+        let value = DecodeError::decode(M::NAME, &self.url, &raw)?;
+        Ok(WithRaw { value, raw })
+    }
+
+    /// Call the method `M` without failing when the response does not match its type.
+    ///
+    /// See [`NearRpcClient::request_lenient`](crate::NearRpcClient::request_lenient).
+    pub fn request_lenient<M: RpcMethod>(
+        &self,
+        request: M::Request,
+    ) -> Result<Lenient<M::Response>> {
+        let raw = self.call_raw(M::NAME, serde_json::to_value(request)?)?;
+        let parsed = DecodeError::decode(M::NAME, &self.url, &raw);
+        Ok(Lenient { raw, parsed })
+    }
+
+    // ==================== Core Methods ====================
+
+    /// Returns the current status of the node including chain ID, latest block, and sync status.
+    pub fn status(&self) -> Result<RpcStatusResponse> {
+        self.call("status", serde_json::json!({}))
+    }
+
+    /// Returns health status of the node. Returns empty object if healthy.
+    pub fn health(&self) -> Result<RpcHealthResponse> {
+        self.call("health", serde_json::json!({}))
+    }
+
+    /// Queries the current state of node network connections.
+    pub fn network_info(&self) -> Result<RpcNetworkInfoResponse> {
+        self.call("network_info", serde_json::json!({}))
+    }
+
+    // ==================== Block/Chunk Methods ====================
+
+    /// Returns block details for given height or hash.
+    pub fn block(&self, request: RpcBlockRequest) -> Result<RpcBlockResponse> {
+        self.call("block", request)
+    }
+
+    /// Returns details of a specific chunk.
+    pub fn chunk(&self, request: RpcChunkRequest) -> Result<RpcChunkResponse> {
+        self.call("chunk", request)
+    }
+
+    /// Returns gas price for a specific block.
+    pub fn gas_price(&self, request: RpcGasPriceRequest) -> Result<RpcGasPriceResponse> {
+        self.call("gas_price", request)
+    }
+
+    // ==================== Account/State Query Methods ====================
+
+    /// Query the blockchain state (view account, view code, view state, call function, etc.)
+    pub fn query(&self, request: RpcQueryRequest) -> Result<RpcQueryResponse> {
+        self.call("query", request)
+    }
+
+    // ==================== Transaction Methods ====================
+
+    /// Sends a signed transaction asynchronously. Returns immediately with transaction hash.
+    pub fn broadcast_tx_async(&self, request: RpcSendTransactionRequest) -> Result<CryptoHash> {
+        self.call("broadcast_tx_async", request)
+    }
+
+    /// Sends a signed transaction and waits for it to complete. Returns full execution result.
+    pub fn broadcast_tx_commit(
+        &self,
+        request: RpcSendTransactionRequest,
+    ) -> Result<RpcTransactionResponse> {
+        self.call("broadcast_tx_commit", request)
+    }
+
+    /// Sends a signed transaction (alias for broadcast_tx_commit).
+    pub fn send_tx(&self, request: RpcSendTransactionRequest) -> Result<RpcTransactionResponse> {
+        self.call("send_tx", request)
+    }
+
+    /// Queries status of a transaction by hash.
+    pub fn tx(&self, request: RpcTransactionStatusRequest) -> Result<RpcTransactionResponse> {
+        self.call("tx", request)
+    }
+
+    // ==================== Validator Methods ====================
+
+    /// Queries active validators on the network for a given epoch.
+    pub fn validators(&self, request: RpcValidatorRequest) -> Result<RpcValidatorResponse> {
+        self.call("validators", request)
+    }
+
+    /// Returns validators ordered by stake for given epoch.
+    pub fn validators_ordered(
+        &self,
+        request: RpcValidatorsOrderedRequest,
+    ) -> Result<ValidatorStakeViews> {
+        self.call("EXPERIMENTAL_validators_ordered", request)
+    }
+
+    // ==================== Light Client Methods ====================
+
+    /// Returns execution proof for light clients (transaction or receipt).
+    pub fn light_client_proof(
+        &self,
+        request: RpcLightClientExecutionProofRequest,
+    ) -> Result<RpcLightClientExecutionProofResponse> {
+        self.call("light_client_proof", request)
+    }
+
+    /// Returns the next light client block.
+    pub fn next_light_client_block(
+        &self,
+        request: RpcLightClientNextBlockRequest,
+    ) -> Result<RpcLightClientNextBlockResponse> {
+        self.call("next_light_client_block", request)
+    }
+
+    /// Returns block proof for light clients.
+    pub fn light_client_block_proof(
+        &self,
+        request: RpcLightClientBlockProofRequest,
+    ) -> Result<RpcLightClientBlockProofResponse> {
+        self.call("light_client_block_proof", request)
+    }
+
+    // ==================== State Changes Methods ====================
+
+    /// Returns changes in block for given block height or hash.
+    pub fn changes_in_block(
+        &self,
+        request: RpcStateChangesInBlockRequest,
+    ) -> Result<RpcStateChangesInBlockByTypeResponse> {
+        self.call("EXPERIMENTAL_changes_in_block", request)
+    }
+
+    /// Returns state changes for specific state change kinds.
+    pub fn changes(
+        &self,
+        request: RpcStateChangesInBlockByTypeRequest,
+    ) -> Result<RpcStateChangesInBlockResponse> {
+        self.call("EXPERIMENTAL_changes", request)
+    }
+
+    /// Returns changes in block (alias for changes_in_block).
+    pub fn block_effects(
+        &self,
+        request: RpcStateChangesInBlockRequest,
+    ) -> Result<RpcStateChangesInBlockByTypeResponse> {
+        self.call("block_effects", request)
+    }
+
+    // ==================== Config Methods ====================
+
+    /// Returns protocol configuration for given block.
+    pub fn protocol_config(
+        &self,
+        request: RpcProtocolConfigRequest,
+    ) -> Result<RpcProtocolConfigResponse> {
+        self.call("EXPERIMENTAL_protocol_config", request)
+    }
+
+    /// Returns genesis configuration of the network.
+    pub fn genesis_config(&self) -> Result<GenesisConfig> {
+        self.call("genesis_config", serde_json::json!({}))
+    }
+
+    /// Returns client configuration.
+    pub fn client_config(&self) -> Result<RpcClientConfigResponse> {
+        self.call("client_config", serde_json::json!({}))
+    }
+
+    // ==================== Other Methods ====================
+
+    /// Returns receipt by receipt_id.
+    pub fn receipt(&self, request: RpcReceiptRequest) -> Result<RpcReceiptResponse> {
+        self.call("EXPERIMENTAL_receipt", request)
+    }
+
+    /// Returns maintenance windows.
+    pub fn maintenance_windows(&self) -> Result<BlockHeightRanges> {
+        self.call("maintenance_windows", serde_json::json!({}))
+    }
+
+    /// Returns split storage info.
+    pub fn split_storage_info(&self) -> Result<RpcSplitStorageInfoResponse> {
+        self.call("EXPERIMENTAL_split_storage_info", serde_json::json!({}))
+    }
+
+    /// Returns congestion level for a chunk.
+    pub fn congestion_level(
+        &self,
+        request: RpcCongestionLevelRequest,
+    ) -> Result<RpcCongestionLevelResponse> {
+        self.call("EXPERIMENTAL_congestion_level", request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+
+    /// Answer one request per body on a local port, and return the requests received
+    fn serve(bodies: Vec<(&'static str, Value)>) -> (String, std::thread::JoinHandle<Vec<Value>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = std::thread::spawn(move || {
+            let mut requests = Vec::new();
+            for (status, body) in bodies {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut request = [0; 4096];
+                let len = stream.read(&mut request).unwrap();
+                let request = String::from_utf8_lossy(&request[..len]).to_string();
+                let (_, json) = request.split_once("\r\n\r\n").unwrap();
+                requests.push(serde_json::from_str(json).unwrap());
+                let body = body.to_string();
+                let response = format!(
+                    "HTTP/1.1 {status}\r\nconnection: close\r\ncontent-length: {}\r\n\r\n{body}",
+                    body.len()
+                );
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+            requests
+        });
+        (url, handle)
+    }
+
+    #[test]
+    fn test_blocking_calls() {
+        let (url, server) = serve(vec![
+            (
+                "200 OK",
+                json!({ "jsonrpc": "2.0", "id": 1, "result": { "gas_price": "100000000" } }),
+            ),
+            (
+                "200 OK",
+                json!({
+                    "jsonrpc": "2.0",
+                    "id": 2,
+                    "error": RpcError::handler_error("UNKNOWN_BLOCK", json!({})),
+                }),
+            ),
+            ("503 Service Unavailable", Value::String("down".into())),
+        ]);
+        let client = NearRpcBlockingClient::new(url);
+
+        let response = client
+            .gas_price(RpcGasPriceRequest { block_id: None })
+            .unwrap();
+        assert_eq!(response.gas_price.0, "100000000");
+
+        let error = client.status().unwrap_err();
+        assert!(matches!(&error, Error::Rpc(error) if error.code == -32000));
+
+        let error = client.health().unwrap_err();
+        assert_eq!(error.http_error_kind(), Some(HttpErrorKind::Server));
+
+        let requests = server.join().unwrap();
+        assert_eq!(
+            requests[0],
+            json!({ "jsonrpc": "2.0", "id": 1, "method": "gas_price", "params": {} })
+        );
+        assert_eq!(requests[2]["method"], "health");
+    }
+}
@@ -0,0 +1,219 @@
+//! Response caching for immutable RPC data.
+//!
+//! Only responses that can never change are cached: blocks and chunks addressed by hash,
+//! receipts, transactions whose execution is final, the genesis config, and any
+//! block-scoped method (`query`, `EXPERIMENTAL_protocol_config`, ...) pinned to a block hash.
+//! Requests using a finality or `latest` always go to the network.
+
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Mutex;
+
+pub use crate::transport::BoxFuture;
+
+/// Storage for cached responses.
+///
+/// Implement this to keep responses on disk or in a shared store such as Redis. Keys are
+/// derived from the method name and serialized params; values are the JSON-RPC `result`.
+pub trait CacheBackend: Send + Sync {
+    /// Look up a cached result
+    fn get<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Option<Value>>;
+
+    /// Store a result
+    fn insert(&self, key: String, value: Value) -> BoxFuture<'_, ()>;
+}
+
+impl<T: CacheBackend + ?Sized> CacheBackend for std::sync::Arc<T> {
+    fn get<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Option<Value>> {
+        (**self).get(key)
+    }
+
+    fn insert(&self, key: String, value: Value) -> BoxFuture<'_, ()> {
+        (**self).insert(key, value)
+    }
+}
+
+/// Methods whose result is fixed once `block_id` is a block hash
+const BLOCK_SCOPED_METHODS: &[&str] = &[
+    "block",
+    "chunk",
+    "query",
+    "gas_price",
+    "validators",
+    "block_effects",
+    "EXPERIMENTAL_changes",
+    "EXPERIMENTAL_changes_in_block",
+    "EXPERIMENTAL_protocol_config",
+];
+
+/// Cache key for a request, or `None` if its response may change over time.
+pub fn cache_key(method: &str, params: &Value) -> Option<String> {
+    let cacheable = match method {
+        "genesis_config" => true,
+        "EXPERIMENTAL_receipt" => params.get("receipt_id").is_some(),
+        "chunk" if params.get("chunk_id").is_some() => true,
+        "tx" | "EXPERIMENTAL_tx_status" => params.get("tx_hash").is_some(),
+        method if BLOCK_SCOPED_METHODS.contains(&method) => {
+            // Block heights may still be reorganized; only hashes are immutable.
+            matches!(params.get("block_id"), Some(Value::String(_)))
+        }
+        _ => false,
+    };
+    cacheable.then(|| format!("{method}:{params}"))
+}
+
+/// Whether a successful result for `method` may be stored.
+pub fn is_cacheable_result(method: &str, result: &Value) -> bool {
+    match method {
+        // Transactions are only immutable once fully executed and finalized.
+        "tx" | "EXPERIMENTAL_tx_status" => {
+            result.get("final_execution_status") == Some(&Value::String("FINAL".to_string()))
+        }
+        _ => true,
+    }
+}
+
+/// In-memory LRU cache bounded by entry count and total size of the cached JSON.
+pub struct MemoryCache {
+    max_entries: usize,
+    max_bytes: usize,
+    inner: Mutex<Lru>,
+}
+
+#[derive(Default)]
+struct Lru {
+    entries: HashMap<String, Entry>,
+    order: BTreeMap<u64, String>,
+    tick: u64,
+    bytes: usize,
+}
+
+struct Entry {
+    value: Value,
+    size: usize,
+    tick: u64,
+}
+
+impl MemoryCache {
+    /// Create a cache holding at most `max_entries` responses
+    pub fn new(max_entries: usize) -> Self {
+        Self {
+            max_entries,
+            max_bytes: usize::MAX,
+            inner: Mutex::new(Lru::default()),
+        }
+    }
+
+    /// Also bound the total size of cached responses, measured as serialized JSON
+    pub fn with_max_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_bytes = max_bytes;
+        self
+    }
+
+    /// Number of cached responses
+    pub fn len(&self) -> usize {
+        self.inner.lock().unwrap().entries.len()
+    }
+
+    /// Whether the cache is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn get_sync(&self, key: &str) -> Option<Value> {
+        let mut lru = self.inner.lock().unwrap();
+        lru.tick += 1;
+        let tick = lru.tick;
+        let entry = lru.entries.get_mut(key)?;
+        let previous = std::mem::replace(&mut entry.tick, tick);
+        let value = entry.value.clone();
+        lru.order.remove(&previous);
+        lru.order.insert(tick, key.to_string());
+        Some(value)
+    }
+
+    fn insert_sync(&self, key: String, value: Value) {
+        let size = key.len() + value.to_string().len();
+        if size > self.max_bytes || self.max_entries == 0 {
+            return;
+        }
+        let mut lru = self.inner.lock().unwrap();
+        lru.tick += 1;
+        let tick = lru.tick;
+        if let Some(old) = lru.entries.remove(&key) {
+            lru.order.remove(&old.tick);
+            lru.bytes -= old.size;
+        }
+        while lru.entries.len() >= self.max_entries || lru.bytes + size > self.max_bytes {
+            let Some((_, oldest)) = lru.order.pop_first() else {
+                break;
+            };
+            if let Some(evicted) = lru.entries.remove(&oldest) {
+                lru.bytes -= evicted.size;
+            }
+        }
+        lru.bytes += size;
+        lru.order.insert(tick, key.clone());
+        lru.entries.insert(key, Entry { value, size, tick });
+    }
+}
+
+impl CacheBackend for MemoryCache {
+    fn get<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Option<Value>> {
+        Box::pin(async move { self.get_sync(key) })
+    }
+
+    fn insert(&self, key: String, value: Value) -> BoxFuture<'_, ()> {
+        Box::pin(async move { self.insert_sync(key, value) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_cache_key() {
+        let hash = "6RWmTYhXCzjMjoY3Mz1rfFcnBm8E6XeDDbFEPUA4sv1w";
+        assert!(cache_key("block", &json!({ "block_id": hash })).is_some());
+        assert!(cache_key("block", &json!({ "block_id": 1234 })).is_none());
+        assert!(cache_key("block", &json!({ "finality": "final" })).is_none());
+        assert!(cache_key("chunk", &json!({ "chunk_id": hash })).is_some());
+        assert!(cache_key("chunk", &json!({ "block_id": hash, "shard_id": 0 })).is_some());
+        assert!(cache_key("query", &json!({ "finality": "optimistic" })).is_none());
+        assert!(cache_key("validators", &json!("latest")).is_none());
+        assert!(cache_key("EXPERIMENTAL_receipt", &json!({ "receipt_id": hash })).is_some());
+        assert!(cache_key("genesis_config", &json!({})).is_some());
+        assert!(cache_key("status", &json!({})).is_none());
+
+        assert!(is_cacheable_result(
+            "tx",
+            &json!({ "final_execution_status": "FINAL" })
+        ));
+        assert!(!is_cacheable_result(
+            "tx",
+            &json!({ "final_execution_status": "EXECUTED_OPTIMISTIC" })
+        ));
+    }
+
+    #[test]
+    fn test_memory_cache_eviction() {
+        let cache = MemoryCache::new(2);
+        cache.insert_sync("a".to_string(), json!(1));
+        cache.insert_sync("b".to_string(), json!(2));
+        assert_eq!(cache.get_sync("a"), Some(json!(1)));
+        cache.insert_sync("c".to_string(), json!(3));
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get_sync("b"), None);
+        assert_eq!(cache.get_sync("a"), Some(json!(1)));
+
+        let cache = MemoryCache::new(100).with_max_bytes(10);
+        cache.insert_sync("a".to_string(), json!("1234"));
+        cache.insert_sync("b".to_string(), json!("5678"));
+        assert_eq!(cache.get_sync("a"), None);
+        assert_eq!(cache.get_sync("b"), Some(json!("5678")));
+        cache.insert_sync("c".to_string(), json!("too large to cache"));
+        assert_eq!(cache.get_sync("c"), None);
+    }
+}
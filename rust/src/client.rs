@@ -1,59 +1,23 @@
 //! Async JSON-RPC client for NEAR Protocol.
 
+use crate::cache::{self, CacheBackend};
+use crate::coalesce::SingleFlight;
+use crate::jsonrpc::{self, RpcRequest};
+use crate::methods::RpcMethod;
+use crate::metrics::{self, MetricsHook};
+#[cfg(feature = "tokio")]
+use crate::rate_limit::RateLimiter;
+use crate::transport::Transport;
 use crate::types::*;
-use reqwest::Client;
+use crate::validation::{self, Validation};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
 
-/// JSON-RPC request wrapper
-#[derive(Debug, Serialize)]
-struct RpcRequest<T> {
-    jsonrpc: &'static str,
-    id: u64,
-    method: &'static str,
-    params: T,
-}
-
-/// JSON-RPC response wrapper
-#[derive(Debug, Deserialize)]
-struct RpcResponse<T> {
-    #[allow(dead_code)]
-    jsonrpc: String,
-    #[allow(dead_code)]
-    id: u64,
-    #[serde(flatten)]
-    result: RpcResult<T>,
-}
-
-#[derive(Debug, Deserialize)]
-#[serde(untagged)]
-enum RpcResult<T> {
-    Ok { result: T },
-    Err { error: RpcError },
-}
-
-/// JSON-RPC error
-#[derive(Debug, Deserialize, thiserror::Error)]
-#[error("RPC error {code}: {message}")]
-pub struct RpcError {
-    pub code: i64,
-    pub message: String,
-    pub data: Option<serde_json::Value>,
-}
-
-/// Client error type
-#[derive(Debug, thiserror::Error)]
-pub enum Error {
-    #[error("HTTP error: {0}")]
-    Http(#[from] reqwest::Error),
-    #[error("RPC error: {0}")]
-    Rpc(#[from] RpcError),
-    #[error("JSON error: {0}")]
-    Json(#[from] serde_json::Error),
-}
-
-/// Result type alias for client operations
-pub type Result<T> = std::result::Result<T, Error>;
+pub use crate::jsonrpc::{
+    DecodeError, Error, HttpErrorKind, Lenient, Result, RpcError, RpcErrorCause, WithRaw,
+};
 
 /// NEAR RPC client
 /// 
@@ -73,69 +37,348 @@ pub type Result<T> = std::result::Result<T, Error>;
 /// }
 /// ```
 pub struct NearRpcClient {
-    client: Client,
+    transport: Arc<dyn Transport>,
     url: String,
     request_id: AtomicU64,
+    cache: Option<Arc<dyn CacheBackend>>,
+    /// Coalesced requests share their result and the id they were sent with
+    coalesce: Option<SingleFlight<(Result<Value>, u64)>>,
+    #[cfg(feature = "tokio")]
+    rate_limiter: Option<RateLimiter>,
+    metrics: Option<Arc<dyn MetricsHook>>,
+    validation: Option<Validation>,
+    validation_warnings: validation::Warnings,
+    #[cfg(feature = "tower")]
+    pub(crate) service: Option<crate::service::BoxRpcService>,
+    #[cfg(feature = "tracing")]
+    redact_transactions: bool,
 }
 
 impl NearRpcClient {
     /// Create a new client with custom URL
+    #[cfg(all(feature = "reqwest", not(target_arch = "wasm32")))]
+    pub fn new(url: impl Into<String>) -> Self {
+        Self::from_transport(url, crate::transport::ReqwestTransport::default())
+    }
+
+    /// Create a new client with custom URL, sending requests with `fetch`
+    #[cfg(target_arch = "wasm32")]
     pub fn new(url: impl Into<String>) -> Self {
+        Self::from_transport(url, crate::transport::FetchTransport)
+    }
+
+    /// Create a client sending its requests to `url` with `transport`.
+    ///
+    /// See the [`transport`](crate::transport) module for using another HTTP library or
+    /// executor.
+    pub fn from_transport(url: impl Into<String>, transport: impl Transport + 'static) -> Self {
         Self {
-            client: Client::new(),
+            transport: Arc::new(transport),
             url: url.into(),
             request_id: AtomicU64::new(1),
+            cache: None,
+            coalesce: None,
+            #[cfg(feature = "tokio")]
+            rate_limiter: None,
+            metrics: None,
+            validation: None,
+            validation_warnings: Default::default(),
+            #[cfg(feature = "tower")]
+            service: None,
+            #[cfg(feature = "tracing")]
+            redact_transactions: false,
         }
     }
 
     /// Create a client for NEAR Mainnet
+    #[cfg(any(feature = "reqwest", target_arch = "wasm32"))]
     pub fn mainnet() -> Self {
         Self::new("https://rpc.mainnet.near.org")
     }
 
     /// Create a client for NEAR Testnet
+    #[cfg(any(feature = "reqwest", target_arch = "wasm32"))]
     pub fn testnet() -> Self {
         Self::new("https://rpc.testnet.near.org")
     }
 
     /// Create a client for NEAR Betanet
+    #[cfg(any(feature = "reqwest", target_arch = "wasm32"))]
     pub fn betanet() -> Self {
         Self::new("https://rpc.betanet.near.org")
     }
 
     /// Create a client for local development
+    #[cfg(any(feature = "reqwest", target_arch = "wasm32"))]
     pub fn local() -> Self {
         Self::new("http://localhost:3030")
     }
 
+    /// URL of the endpoint, `"service"` for clients built from a custom service
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Cache responses that can never change in `cache`.
+    ///
+    /// See the [`cache`](crate::cache) module for which requests are cached.
+    ///
+    /// ```no_run
+    /// use near_rpc_client::{cache::MemoryCache, NearRpcClient};
+    ///
+    /// let client = NearRpcClient::mainnet().with_cache(MemoryCache::new(10_000));
+    /// ```
+    pub fn with_cache(mut self, cache: impl CacheBackend + 'static) -> Self {
+        self.cache = Some(Arc::new(cache));
+        self
+    }
+
+    /// Share one HTTP request between concurrent calls with the same method and params.
+    ///
+    /// Useful when many tasks ask for the same data at once, e.g. the latest final block.
+    /// Every caller receives a clone of the same result or error.
+    pub fn with_coalescing(mut self) -> Self {
+        self.coalesce = Some(SingleFlight::default());
+        self
+    }
+
+    /// Throttle outgoing requests with `limiter`.
+    ///
+    /// See the [`rate_limit`](crate::rate_limit) module for details.
+    #[cfg(feature = "tokio")]
+    pub fn with_rate_limiter(mut self, limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(limiter);
+        self
+    }
+
+    /// Report every request sent to the endpoint to `hook`.
+    ///
+    /// See the [`metrics`](crate::metrics) module for ready-made hooks.
+    pub fn with_metrics(mut self, hook: impl MetricsHook + 'static) -> Self {
+        self.metrics = Some(Arc::new(hook));
+        self
+    }
+
+    /// Check every response against the JSON Schema of its method in `openrpc.json`.
+    ///
+    /// With [`Validation::Warn`] the issues are kept for
+    /// [`take_validation_warnings`](Self::take_validation_warnings) and logged to `tracing`.
+    /// With [`Validation::Fail`] calls fail with [`Error::Validation`] instead. See the
+    /// [`validation`](crate::validation) module.
+    pub fn with_validation(mut self, mode: Validation) -> Self {
+        self.validation = Some(mode);
+        self
+    }
+
+    /// Validation issues of the most recent responses that did not fail their call,
+    /// oldest first. Returns each warning once and keeps at most the last 100.
+    pub fn take_validation_warnings(&self) -> Vec<validation::ValidationError> {
+        self.validation_warnings.take()
+    }
+
+    /// Hide signed transactions when logging request params.
+    #[cfg(feature = "tracing")]
+    pub fn with_redacted_transactions(mut self) -> Self {
+        self.redact_transactions = true;
+        self
+    }
+
     fn next_id(&self) -> u64 {
         self.request_id.fetch_add(1, Ordering::Relaxed)
     }
 
-    async fn call<P: Serialize, R: for<'de> Deserialize<'de>>(
+    pub(crate) async fn call<P: Serialize, R: for<'de> Deserialize<'de>>(
         &self,
         method: &'static str,
         params: P,
     ) -> Result<R> {
-        let request = RpcRequest {
-            jsonrpc: "2.0",
-            id: self.next_id(),
+        let result = self.call_raw(method, serde_json::to_value(params)?).await?;
+        Ok(DecodeError::decode(method, &self.url, &result)?)
+    }
+
+    /// Call `method` with JSON `params` and return the JSON `result`.
+    ///
+    /// The request goes through the same cache, coalescing, rate limiting and metrics as
+    /// typed calls. Useful for methods without a typed wrapper, or to forward requests
+    /// without parsing them.
+    pub async fn call_raw(&self, method: &str, params: Value) -> Result<Value> {
+        #[cfg(feature = "tracing")]
+        return crate::instrument::call(method, async {
+            crate::instrument::request(&params, self.redact_transactions);
+            self.call_cached(method, params).await
+        })
+        .await;
+
+        #[cfg(not(feature = "tracing"))]
+        self.call_cached(method, params).await
+    }
+
+    /// Call the method `M` with its typed request.
+    ///
+    /// Every method of the API has a marker type in [`methods`](crate::methods), so this
+    /// reaches methods without a dedicated wrapper too.
+    ///
+    /// ```no_run
+    /// use near_rpc_client::{methods, types::*, NearRpcClient};
+    ///
+    /// # async fn example() -> near_rpc_client::client::Result<()> {
+    /// let client = NearRpcClient::mainnet();
+    /// let request = RpcGasPriceRequest { block_id: None };
+    /// let gas_price = client.request::<methods::GasPrice>(request).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn request<M: RpcMethod>(&self, request: M::Request) -> Result<M::Response> {
+        let result = self.call_raw(M::NAME, serde_json::to_value(request)?).await?;
+        Ok(DecodeError::decode(M::NAME, &self.url, &result)?)
+    }
+
+    /// Call the method `M` and return the typed response along with its raw JSON.
+    pub async fn request_with_raw<M: RpcMethod>(
+        &self,
+        request: M::Request,
+    ) -> Result<WithRaw<M::Response>> {
+        let raw = self.call_raw(M::NAME, serde_json::to_value(request)?).await?;
+        let value = DecodeError::decode(M::NAME, &self.url, &raw)?;
+        Ok(WithRaw { value, raw })
+    }
+
+    /// Call the method `M` without failing when the response does not match its type.
+    ///
+    /// The raw JSON is returned with the typed response or the deserialization error, so
+    /// callers can fall back to the JSON until the types catch up with a nearcore release.
+    /// Transport and JSON-RPC errors still fail the call.
+    pub async fn request_lenient<M: RpcMethod>(
+        &self,
+        request: M::Request,
+    ) -> Result<Lenient<M::Response>> {
+        let raw = self.call_raw(M::NAME, serde_json::to_value(request)?).await?;
+        let parsed = DecodeError::decode(M::NAME, &self.url, &raw);
+        Ok(Lenient { raw, parsed })
+    }
+
+    async fn call_cached(&self, method: &str, params: Value) -> Result<Value> {
+        let Some(cache) = &self.cache else {
+            return self.call_validated(method, params).await;
+        };
+        let Some(key) = cache::cache_key(method, &params) else {
+            return self.call_validated(method, params).await;
+        };
+        if let Some(result) = cache.get(&key).await {
+            #[cfg(feature = "tracing")]
+            crate::instrument::cache_hit();
+            return Ok(result);
+        }
+        let result = self.call_validated(method, params).await?;
+        if cache::is_cacheable_result(method, &result) {
+            cache.insert(key, result.clone()).await;
+        }
+        Ok(result)
+    }
+
+    /// Validate responses before they are cached, so cache hits are not checked again
+    async fn call_validated(&self, method: &str, params: Value) -> Result<Value> {
+        let result = self.call_shared(method, params).await?;
+        if let Some(mode) = self.validation {
+            validation::enforce(method, &result, mode, &self.validation_warnings)?;
+        }
+        Ok(result)
+    }
+
+    async fn call_shared(&self, method: &str, params: Value) -> Result<Value> {
+        let id = self.next_id();
+        let Some(flights) = &self.coalesce else {
+            return self.send(method, params, id).await;
+        };
+        let key = format!("{method}:{params}");
+        #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+        let (result, sent_id) = flights
+            .run(key, async { (self.send(method, params, id).await, id) })
+            .await;
+        // The request was sent by another caller: record it on this caller's span too.
+        #[cfg(feature = "tracing")]
+        if sent_id != id {
+            crate::instrument::sending(self.request_id(sent_id), &self.url);
+        }
+        result
+    }
+
+    /// The id of a request sent with `id`, if it went out as a JSON-RPC request of this
+    /// client rather than through a custom service with its own ids
+    #[cfg(feature = "tracing")]
+    fn request_id(&self, id: u64) -> Option<u64> {
+        #[cfg(feature = "tower")]
+        if self.service.is_some() {
+            return None;
+        }
+        Some(id)
+    }
+
+    async fn send(&self, method: &str, params: Value, id: u64) -> Result<Value> {
+        #[cfg(feature = "tokio")]
+        let _permit = match &self.rate_limiter {
+            Some(limiter) => limiter.acquire(method).await,
+            None => None,
+        };
+
+        let Some(hook) = &self.metrics else {
+            return self.dispatch(method, params, id).await;
+        };
+        let request = metrics::Request {
             method,
-            params,
+            endpoint: &self.url,
         };
+        let in_flight = metrics::InFlight::start(hook.as_ref(), request);
+        let result = self.dispatch(method, params, id).await;
+        in_flight.finish(&result);
+        result
+    }
+
+    async fn dispatch(&self, method: &str, params: Value, id: u64) -> Result<Value> {
+        #[cfg(feature = "tower")]
+        if let Some(service) = &self.service {
+            let result = crate::service::oneshot(service, method, params).await;
+            #[cfg(feature = "tokio")]
+            match &result {
+                Err(Error::HttpStatus {
+                    status: 429,
+                    retry_after,
+                    ..
+                }) => self.record_throttling(method, true, *retry_after),
+                _ => self.record_throttling(method, false, None),
+            }
+            return result;
+        }
+
+        let response = post(self.transport.as_ref(), &self.url, id, method, params).await?;
 
-        let response: RpcResponse<R> = self
-            .client
-            .post(&self.url)
-            .json(&request)
-            .send()
-            .await?
-            .json()
-            .await?;
-
-        match response.result {
-            RpcResult::Ok { result } => Ok(result),
-            RpcResult::Err { error } => Err(Error::Rpc(error)),
+        #[cfg(feature = "tokio")]
+        self.record_throttling(
+            method,
+            response.status() == http::StatusCode::TOO_MANY_REQUESTS,
+            jsonrpc::retry_after(response.headers()),
+        );
+
+        read_result(response, &self.url, method, id)
+    }
+
+    /// Feed whether a request for `method` was throttled back to the rate limiter
+    #[cfg(feature = "tokio")]
+    fn record_throttling(
+        &self,
+        method: &str,
+        throttled: bool,
+        retry_after: Option<std::time::Duration>,
+    ) {
+        let Some(limiter) = &self.rate_limiter else {
+            return;
+        };
+        if throttled {
+            #[cfg(feature = "tracing")]
+            crate::instrument::throttled(retry_after);
+            limiter.on_throttled(method, retry_after);
+        } else {
+            limiter.on_success(method);
         }
     }
 
@@ -312,10 +555,52 @@ impl NearRpcClient {
     }
 }
 
-#[cfg(test)]
+/// POST a single JSON-RPC request to `url`.
+pub(crate) async fn post(
+    transport: &dyn Transport,
+    url: &str,
+    id: u64,
+    method: &str,
+    params: Value,
+) -> Result<http::Response<Vec<u8>>> {
+    #[cfg(feature = "tracing")]
+    crate::instrument::sending(id, url);
+    let body = serde_json::to_vec(&RpcRequest::new(id, method, params))?;
+    let request = http::Request::post(url)
+        .header(http::header::CONTENT_TYPE, "application/json")
+        .body(body)
+        .map_err(|error| Error::Http(Arc::new(error)))?;
+    transport
+        .send(request)
+        .await
+        .map_err(|error| Error::Http(Arc::from(error)))
+}
+
+/// Extract the `result` of the JSON-RPC response from `url` to request `id` for `method`.
+pub(crate) fn read_result(
+    response: http::Response<Vec<u8>>,
+    url: &str,
+    method: &str,
+    id: u64,
+) -> Result<Value> {
+    #[cfg(feature = "tracing")]
+    crate::instrument::response_size(response.body().len());
+    let status = response.status();
+    jsonrpc::read_body(
+        method,
+        id,
+        status,
+        url.to_string(),
+        response.headers(),
+        response.body(),
+    )
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
 mod tests {
     use super::*;
 
+    #[cfg(feature = "reqwest")]
     #[test]
     fn test_client_creation() {
         let client = NearRpcClient::mainnet();
@@ -327,4 +612,222 @@ mod tests {
         let client = NearRpcClient::new("https://custom.rpc.near.org");
         assert_eq!(client.url, "https://custom.rpc.near.org");
     }
+
+    /// Answers with the body of the JSON-RPC request, keeping the request
+    #[derive(Default)]
+    struct Echo(std::sync::Mutex<Vec<http::Request<Vec<u8>>>>);
+
+    impl Transport for Echo {
+        fn send(
+            &self,
+            request: http::Request<Vec<u8>>,
+        ) -> crate::transport::BoxFuture<
+            '_,
+            std::result::Result<http::Response<Vec<u8>>, crate::transport::BoxError>,
+        > {
+            Box::pin(async move {
+                let call: Value = serde_json::from_slice(request.body())?;
+                let body = serde_json::json!({
+                    "jsonrpc": "2.0",
+                    "id": call["id"],
+                    "result": { "gas_price": call["params"]["block_id"].to_string() },
+                });
+                self.0.lock().unwrap().push(request);
+                Ok(http::Response::new(serde_json::to_vec(&body)?))
+            })
+        }
+    }
+
+    /// Run `future` on the current thread, without any async runtime
+    fn block_on<F: std::future::Future>(future: F) -> F::Output {
+        struct Unpark(std::thread::Thread);
+        impl std::task::Wake for Unpark {
+            fn wake(self: Arc<Self>) {
+                self.0.unpark();
+            }
+        }
+        let waker = Arc::new(Unpark(std::thread::current())).into();
+        let mut context = std::task::Context::from_waker(&waker);
+        let mut future = std::pin::pin!(future);
+        loop {
+            match future.as_mut().poll(&mut context) {
+                std::task::Poll::Ready(output) => return output,
+                std::task::Poll::Pending => std::thread::park(),
+            }
+        }
+    }
+
+    #[test]
+    fn test_custom_transport() {
+        let transport = Arc::new(Echo::default());
+        let client =
+            NearRpcClient::from_transport("http://node.test", transport.clone()).with_coalescing();
+
+        let request = RpcGasPriceRequest {
+            block_id: Some(BlockId::BlockHeight(7)),
+        };
+        let gas_price = block_on(client.gas_price(request)).unwrap();
+        assert_eq!(gas_price.gas_price.0, "7");
+
+        let requests = transport.0.lock().unwrap();
+        assert_eq!(requests[0].method(), http::Method::POST);
+        assert_eq!(requests[0].uri(), "http://node.test/");
+        assert_eq!(
+            requests[0].headers()[http::header::CONTENT_TYPE],
+            "application/json"
+        );
+    }
+
+    #[cfg(feature = "testing")]
+    #[tokio::test]
+    async fn test_raw_and_lenient_requests() {
+        use crate::methods::GasPrice;
+        use serde_json::json;
+
+        let server = crate::testing::MockServer::start().await;
+        server
+            .mock("gas_price")
+            .respond(json!({ "gas_price": "1", "new_field": 2 }));
+        server
+            .mock("gas_price")
+            .match_params(json!({ "block_id": 1 }))
+            .respond(json!({ "gas_price": 1 }));
+        let client = server.client();
+        let latest = || RpcGasPriceRequest { block_id: None };
+        let at_height = || RpcGasPriceRequest {
+            block_id: Some(BlockId::BlockHeight(1)),
+        };
+
+        let response = client.request_with_raw::<GasPrice>(latest()).await.unwrap();
+        assert_eq!(response.value.gas_price.0, "1");
+        assert_eq!(response.raw["new_field"], 2);
+
+        assert!(matches!(
+            client.request_with_raw::<GasPrice>(at_height()).await,
+            Err(Error::Decode(_))
+        ));
+        let response = client
+            .request_lenient::<GasPrice>(at_height())
+            .await
+            .unwrap();
+        assert_eq!(response.raw, json!({ "gas_price": 1 }));
+        assert!(response.parsed.is_err());
+        assert!(response.into_parsed().is_err());
+
+        let response = client.request_lenient::<GasPrice>(latest()).await.unwrap();
+        assert_eq!(response.into_parsed().unwrap().gas_price.0, "1");
+    }
+
+    #[cfg(feature = "testing")]
+    #[tokio::test]
+    async fn test_decode_errors() {
+        use serde_json::json;
+
+        let server = crate::testing::MockServer::start().await;
+        server.mock("query").respond(json!({
+            "amount": "1",
+            "locked": "0",
+            "code_hash": "11111111111111111111111111111111",
+            "storage_usage": "large",
+            "block_height": 1,
+            "block_hash": "11111111111111111111111111111111",
+        }));
+        let request = RpcQueryRequest::ViewAccountBlockId {
+            account_id: AccountId("alice.near".to_string()),
+            block_id: BlockId::BlockHeight(1),
+            request_type: "view_account".to_string(),
+        };
+        let Err(Error::Decode(error)) = server.client().query(request).await else {
+            panic!("expected a decode error");
+        };
+        assert_eq!(error.method, "query");
+        assert_eq!(error.endpoint, server.url());
+        assert_eq!(error.issues[1].pointer, "/storage_usage");
+        assert!(error.body.contains(r#""storage_usage":"large""#));
+        assert!(error
+            .to_string()
+            .ends_with("schema mismatch at /storage_usage: expected integer, found string"));
+    }
+
+    /// Answer one request on a local port with the raw HTTP `response`
+    #[cfg(feature = "reqwest")]
+    async fn serve_once(response: String) -> String {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut request = [0; 4096];
+            let _ = stream.read(&mut request).await.unwrap();
+            stream.write_all(response.as_bytes()).await.unwrap();
+        });
+        url
+    }
+
+    #[cfg(feature = "reqwest")]
+    fn http_response(status: &str, headers: &str, body: &str) -> String {
+        format!(
+            "HTTP/1.1 {status}\r\n{headers}content-length: {}\r\n\r\n{body}",
+            body.len()
+        )
+    }
+
+    #[cfg(feature = "reqwest")]
+    #[tokio::test]
+    async fn test_non_json_responses() {
+        // A 200 that is not JSON-RPC fails to decode
+        let body = "<html>".to_string() + &"x".repeat(2000);
+        let url = serve_once(http_response("200 OK", "", &body)).await;
+        let Err(Error::Decode(error)) = NearRpcClient::new(&url).health().await else {
+            panic!("expected a decode error");
+        };
+        assert_eq!((error.request_id, error.status), (Some(1), Some(200)));
+        assert_eq!(error.body.len(), DecodeError::MAX_BODY + "...".len());
+        assert!(error
+            .to_string()
+            .starts_with("failed to parse response to `health` from http://"));
+
+        // A gateway error page
+        let body = "<html><head><title>502 Bad Gateway</title></head>\n<body>nginx</body></html>";
+        let url = serve_once(http_response("502 Bad Gateway", "", body)).await;
+        let error = NearRpcClient::new(&url).health().await.unwrap_err();
+        assert_eq!(error.to_string(), "HTTP status 502: 502 Bad Gateway");
+        assert_eq!(error.http_error_kind(), Some(HttpErrorKind::Server));
+
+        // A plain-text rate limit
+        let url = serve_once(http_response(
+            "429 Too Many Requests",
+            "retry-after: 3\r\n",
+            "Too many requests,\n  slow down",
+        ))
+        .await;
+        let error = NearRpcClient::new(&url).health().await.unwrap_err();
+        let Error::HttpStatus {
+            status,
+            ref body_snippet,
+            retry_after,
+        } = error
+        else {
+            panic!("expected an HTTP status error, got {error:?}");
+        };
+        assert_eq!(status, 429);
+        assert_eq!(body_snippet, "Too many requests, slow down");
+        assert_eq!(retry_after, Some(std::time::Duration::from_secs(3)));
+        assert_eq!(error.http_error_kind(), Some(HttpErrorKind::RateLimited));
+
+        // An empty body falls back to the status reason
+        let url = serve_once(http_response("503 Service Unavailable", "", "")).await;
+        let error = NearRpcClient::new(&url).health().await.unwrap_err();
+        assert_eq!(error.to_string(), "HTTP status 503: Service Unavailable");
+
+        // nearcore's JSON-RPC errors come with error statuses too
+        let body = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "error": RpcError::parse_error("missing field `finality`"),
+        });
+        let url = serve_once(http_response("400 Bad Request", "", &body.to_string())).await;
+        let error = NearRpcClient::new(&url).health().await.unwrap_err();
+        assert!(matches!(error, Error::Rpc(error) if error.code == -32700));
+    }
 }
@@ -0,0 +1,97 @@
+//! Single-flight deduplication of identical concurrent requests.
+
+use async_lock::OnceCell;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+
+type Flight<T> = Arc<OnceCell<T>>;
+
+/// Tracks in-flight requests so that concurrent identical calls share one response.
+///
+/// The first caller for a key runs the request; callers arriving while it is in flight wait
+/// for the same result. If the caller running the request is cancelled, one of the waiting
+/// callers takes over. Once a request completes, the next call with the same key starts a
+/// new one.
+pub(crate) struct SingleFlight<T> {
+    flights: Mutex<HashMap<String, Flight<T>>>,
+}
+
+impl<T> Default for SingleFlight<T> {
+    fn default() -> Self {
+        Self {
+            flights: Mutex::default(),
+        }
+    }
+}
+
+impl<T: Clone> SingleFlight<T> {
+    pub async fn run<F>(&self, key: String, request: F) -> T
+    where
+        F: Future<Output = T>,
+    {
+        let flight = self
+            .flights
+            .lock()
+            .unwrap()
+            .entry(key.clone())
+            .or_default()
+            .clone();
+        let result = flight.get_or_init(|| request).await.clone();
+
+        let mut flights = self.flights.lock().unwrap();
+        if flights
+            .get(&key)
+            .is_some_and(|current| Arc::ptr_eq(current, &flight))
+        {
+            flights.remove(&key);
+        }
+        result
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+    use crate::client::{Error, Result, RpcError};
+    use serde_json::Value;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_concurrent_calls_share_one_request() {
+        let flights = Arc::new(SingleFlight::<Result<Value>>::default());
+        let requests = Arc::new(AtomicUsize::new(0));
+
+        let calls = (0..10).map(|_| {
+            let flights = flights.clone();
+            let requests = requests.clone();
+            tokio::spawn(async move {
+                flights
+                    .run("block:{}".to_string(), async {
+                        requests.fetch_add(1, Ordering::SeqCst);
+                        tokio::time::sleep(Duration::from_millis(50)).await;
+                        Err(Error::Rpc(RpcError {
+                            code: -32000,
+                            message: "Server error".to_string(),
+                            data: None,
+                            name: None,
+                            cause: None,
+                        }))
+                    })
+                    .await
+            })
+        });
+        for call in calls.collect::<Vec<_>>() {
+            assert!(matches!(call.await.unwrap(), Err(Error::Rpc(e)) if e.code == -32000));
+        }
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+        // Completed flights are not reused.
+        let result = flights
+            .run("block:{}".to_string(), async { Ok(Value::Bool(true)) })
+            .await;
+        assert_eq!(result.unwrap(), Value::Bool(true));
+        assert!(flights.flights.lock().unwrap().is_empty());
+    }
+}
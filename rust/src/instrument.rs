@@ -0,0 +1,250 @@
+//! `tracing` instrumentation of RPC calls.
+//!
+//! Every call runs in an `rpc_call` span, a child of the caller's current span, with the
+//! fields `method`, `id`, `endpoint`, `latency_ms`, `response_size` and `error_code`.
+//! Fields that do not apply, like `id` for a response served from the cache, stay empty.
+//! Callers sharing a coalesced request record its `id` and `endpoint` as well.
+
+use crate::client::{Error, Result};
+use serde_json::Value;
+use std::future::Future;
+use tracing::{field, Instrument, Span};
+use web_time::Instant;
+
+/// Params that carry a signed transaction
+const SIGNED_TRANSACTION_FIELDS: &[&str] = &["signed_tx_base64"];
+
+/// Run `call` inside a span for `method`, recording its latency and outcome.
+pub(crate) async fn call<F>(method: &str, call: F) -> Result<Value>
+where
+    F: Future<Output = Result<Value>>,
+{
+    let span = tracing::info_span!(
+        "rpc_call",
+        method,
+        id = field::Empty,
+        endpoint = field::Empty,
+        latency_ms = field::Empty,
+        response_size = field::Empty,
+        error_code = field::Empty,
+    );
+    async move {
+        let start = Instant::now();
+        let result = call.await;
+        let span = Span::current();
+        span.record("latency_ms", start.elapsed().as_secs_f64() * 1000.0);
+        match &result {
+            Ok(_) => tracing::debug!("rpc call succeeded"),
+            Err(error) => {
+                if let Error::Rpc(rpc) = error {
+                    span.record("error_code", rpc.code);
+                }
+                tracing::warn!(%error, "rpc call failed");
+            }
+        }
+        result
+    }
+    .instrument(span)
+    .await
+}
+
+/// Log the params of a request, hiding signed transactions if `redact` is set.
+pub(crate) fn request(params: &Value, redact: bool) {
+    if !tracing::enabled!(tracing::Level::TRACE) {
+        return;
+    }
+    if redact {
+        tracing::trace!(params = %redacted(params), "rpc request");
+    } else {
+        tracing::trace!(%params, "rpc request");
+    }
+}
+
+/// Record the JSON-RPC request id, if known, and the endpoint it is sent to.
+pub(crate) fn sending(id: impl Into<Option<u64>>, endpoint: &str) {
+    let span = Span::current();
+    if let Some(id) = id.into() {
+        span.record("id", id);
+    }
+    span.record("endpoint", endpoint);
+}
+
+/// Record the size of the response body in bytes.
+pub(crate) fn response_size(size: usize) {
+    Span::current().record("response_size", size);
+}
+
+pub(crate) fn cache_hit() {
+    tracing::debug!("rpc response served from cache");
+}
+
+#[cfg(feature = "tokio")]
+pub(crate) fn throttled(retry_after: Option<std::time::Duration>) {
+    tracing::warn!(?retry_after, "rpc endpoint throttled request, backing off");
+}
+
+#[cfg(feature = "proxy")]
+pub(crate) fn failover(endpoint: &str, method: &str, error: &Error) {
+    tracing::warn!(endpoint, method, %error, "upstream failed, failing over");
+}
+
+fn redacted(params: &Value) -> Value {
+    let mut params = params.clone();
+    if let Value::Object(map) = &mut params {
+        for field in SIGNED_TRANSACTION_FIELDS {
+            if let Some(value) = map.get_mut(*field) {
+                *value = Value::String("<redacted>".to_string());
+            }
+        }
+    }
+    params
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+    use crate::transport::{BoxError, Transport};
+    use crate::types::RpcGasPriceRequest;
+    use crate::NearRpcClient;
+    use serde_json::json;
+    use std::collections::BTreeMap;
+    use std::sync::{Arc, Mutex};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing_subscriber::layer::{Context, SubscriberExt};
+    use tracing_subscriber::Layer;
+
+    #[derive(Default)]
+    struct SpanFields(BTreeMap<&'static str, String>);
+
+    /// Collects the fields recorded on `rpc_call` spans, in order of creation
+    #[derive(Clone, Default)]
+    struct Spans(Arc<Mutex<Vec<(Id, SpanFields)>>>);
+
+    impl field::Visit for SpanFields {
+        fn record_debug(&mut self, field: &field::Field, value: &dyn std::fmt::Debug) {
+            self.0.insert(field.name(), format!("{value:?}"));
+        }
+
+        fn record_str(&mut self, field: &field::Field, value: &str) {
+            self.0.insert(field.name(), value.to_string());
+        }
+    }
+
+    impl<S: tracing::Subscriber> Layer<S> for Spans {
+        fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, _: Context<'_, S>) {
+            if attrs.metadata().name() == "rpc_call" {
+                let mut fields = SpanFields::default();
+                attrs.record(&mut fields);
+                self.0.lock().unwrap().push((id.clone(), fields));
+            }
+        }
+
+        fn on_record(&self, id: &Id, values: &Record<'_>, _: Context<'_, S>) {
+            let mut spans = self.0.lock().unwrap();
+            if let Some((_, fields)) = spans.iter_mut().find(|(span, _)| span == id) {
+                values.record(fields);
+            }
+        }
+    }
+
+    /// Answers every request after a short delay, so that concurrent calls overlap
+    #[derive(Default)]
+    struct Slow(Mutex<usize>);
+
+    impl Transport for Slow {
+        fn send(
+            &self,
+            request: http::Request<Vec<u8>>,
+        ) -> crate::transport::BoxFuture<'_, std::result::Result<http::Response<Vec<u8>>, BoxError>>
+        {
+            *self.0.lock().unwrap() += 1;
+            Box::pin(async move {
+                tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+                let call: Value = serde_json::from_slice(request.body())?;
+                let body = json!({
+                    "jsonrpc": "2.0",
+                    "id": call["id"],
+                    "result": { "gas_price": "100" },
+                });
+                Ok(http::Response::new(serde_json::to_vec(&body)?))
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_coalesced_span_fields() {
+        let spans = Spans::default();
+        let _guard =
+            tracing::subscriber::set_default(tracing_subscriber::registry().with(spans.clone()));
+        let transport = Arc::new(Slow::default());
+        let client =
+            NearRpcClient::from_transport("http://node.test", transport.clone()).with_coalescing();
+
+        let request = || RpcGasPriceRequest { block_id: None };
+        let (first, second) =
+            tokio::join!(client.gas_price(request()), client.gas_price(request()));
+        first.unwrap();
+        second.unwrap();
+        assert_eq!(*transport.0.lock().unwrap(), 1);
+
+        // Both callers' spans describe the single request that was sent.
+        let spans = spans.0.lock().unwrap();
+        assert_eq!(spans.len(), 2);
+        for (_, SpanFields(fields)) in spans.iter() {
+            assert_eq!(fields["method"], "gas_price");
+            assert_eq!(fields["id"], "1");
+            assert_eq!(fields["endpoint"], "http://node.test");
+            assert!(fields.contains_key("latency_ms"));
+        }
+        assert!(spans[0].1 .0.contains_key("response_size"));
+    }
+
+    /// Collects the fields of events
+    #[cfg(feature = "proxy")]
+    #[derive(Clone, Default)]
+    struct Events(Arc<Mutex<Vec<SpanFields>>>);
+
+    #[cfg(feature = "proxy")]
+    impl<S: tracing::Subscriber> Layer<S> for Events {
+        fn on_event(&self, event: &tracing::Event<'_>, _: Context<'_, S>) {
+            let mut fields = SpanFields::default();
+            event.record(&mut fields);
+            self.0.lock().unwrap().push(fields);
+        }
+    }
+
+    #[cfg(feature = "proxy")]
+    #[tokio::test]
+    async fn test_failover_event() {
+        use crate::server::NearRpcHandler;
+
+        let events = Events::default();
+        let _guard =
+            tracing::subscriber::set_default(tracing_subscriber::registry().with(events.clone()));
+        // Nothing listens on port 1
+        let proxy = crate::proxy::Proxy::new([NearRpcClient::new("http://127.0.0.1:1")]);
+        assert!(proxy.dispatch("gas_price", json!({})).await.is_err());
+
+        let events = events.0.lock().unwrap();
+        let failover = events
+            .iter()
+            .find(|SpanFields(fields)| fields.contains_key("endpoint"))
+            .expect("a failover event");
+        assert_eq!(failover.0["endpoint"], "http://127.0.0.1:1");
+        assert_eq!(failover.0["method"], "gas_price");
+        assert!(failover.0.contains_key("error"));
+    }
+
+    #[test]
+    fn test_redacted() {
+        let params = json!({
+            "signed_tx_base64": "DgAAAHNlbmRlci50ZXN0bmV0",
+            "wait_until": "FINAL",
+        });
+        assert_eq!(
+            redacted(&params),
+            json!({ "signed_tx_base64": "<redacted>", "wait_until": "FINAL" })
+        );
+        assert_eq!(redacted(&json!({})), json!({}));
+    }
+}
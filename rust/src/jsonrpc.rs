@@ -0,0 +1,504 @@
+//! JSON-RPC envelope, errors and response decoding shared by the async and blocking
+//! clients.
+
+use crate::validation::{self, ValidationError};
+use http::{HeaderMap, StatusCode};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::sync::Arc;
+
+/// JSON-RPC request wrapper
+#[derive(Debug, Serialize)]
+pub(crate) struct RpcRequest<'a, T> {
+    jsonrpc: &'static str,
+    id: u64,
+    method: &'a str,
+    params: T,
+}
+
+impl<'a, T> RpcRequest<'a, T> {
+    pub(crate) fn new(id: u64, method: &'a str, params: T) -> Self {
+        Self {
+            jsonrpc: "2.0",
+            id,
+            method,
+            params,
+        }
+    }
+}
+
+/// JSON-RPC response wrapper
+#[derive(Debug, Deserialize)]
+struct RpcResponse<T> {
+    #[allow(dead_code)]
+    jsonrpc: String,
+    #[allow(dead_code)]
+    id: u64,
+    #[serde(flatten)]
+    result: RpcResult<T>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum RpcResult<T> {
+    Ok { result: T },
+    Err { error: RpcError },
+}
+
+/// JSON-RPC error
+#[derive(Clone, Debug, Deserialize, Serialize, thiserror::Error)]
+#[error("RPC error {code}: {message}")]
+pub struct RpcError {
+    pub code: i64,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<serde_json::Value>,
+    /// Error category reported by nearcore, e.g. `HANDLER_ERROR`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Specific error reported by nearcore, e.g. `UNKNOWN_BLOCK`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cause: Option<Box<RpcErrorCause>>,
+}
+
+/// Cause of a nearcore JSON-RPC error
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RpcErrorCause {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub info: Option<serde_json::Value>,
+}
+
+impl RpcError {
+    fn nearcore(
+        code: i64,
+        message: &str,
+        name: &str,
+        cause: &str,
+        info: serde_json::Value,
+    ) -> Self {
+        RpcError {
+            code,
+            message: message.to_string(),
+            data: None,
+            name: Some(name.to_string()),
+            cause: Some(Box::new(RpcErrorCause {
+                name: cause.to_string(),
+                info: Some(info),
+            })),
+        }
+    }
+
+    /// The error nearcore returns for an unknown method
+    pub fn method_not_found(method: &str) -> Self {
+        let mut error = Self::nearcore(
+            -32601,
+            "Method not found",
+            "REQUEST_VALIDATION_ERROR",
+            "METHOD_NOT_FOUND",
+            serde_json::json!({ "method_name": method }),
+        );
+        error.data = Some(serde_json::Value::String(method.to_string()));
+        error
+    }
+
+    /// The error nearcore returns for a malformed request or invalid params
+    pub fn parse_error(message: impl Into<String>) -> Self {
+        let message = message.into();
+        let mut error = Self::nearcore(
+            -32700,
+            "Parse error",
+            "REQUEST_VALIDATION_ERROR",
+            "PARSE_ERROR",
+            serde_json::json!({ "error_message": message }),
+        );
+        error.data = Some(serde_json::Value::String(message));
+        error
+    }
+
+    /// A `HANDLER_ERROR` with the given cause, e.g. `UNKNOWN_BLOCK`
+    pub fn handler_error(cause: impl Into<String>, info: serde_json::Value) -> Self {
+        Self::nearcore(-32000, "Server error", "HANDLER_ERROR", &cause.into(), info)
+    }
+
+    /// An `INTERNAL_ERROR`, for failures unrelated to the request
+    pub fn internal_error(message: impl Into<String>) -> Self {
+        let message = message.into();
+        let mut error = Self::nearcore(
+            -32000,
+            "Server error",
+            "INTERNAL_ERROR",
+            "INTERNAL_ERROR",
+            serde_json::json!({ "error_message": message }),
+        );
+        error.data = Some(serde_json::Value::String(message));
+        error
+    }
+}
+
+/// Client error type
+///
+/// Errors are cheap to clone so that coalesced requests can share them.
+#[derive(Clone, Debug, thiserror::Error)]
+pub enum Error {
+    #[error("HTTP error: {0}")]
+    Http(#[source] Arc<dyn std::error::Error + Send + Sync>),
+    #[error("RPC error: {0}")]
+    Rpc(#[from] RpcError),
+    #[error("JSON error: {0}")]
+    Json(#[source] Arc<serde_json::Error>),
+    /// Response that could not be parsed, with the context to find out why
+    #[error("{0}")]
+    Decode(#[source] Arc<DecodeError>),
+    /// Response that does not match the schema, see [`NearRpcClient::with_validation`](crate::NearRpcClient::with_validation)
+    #[error(transparent)]
+    Validation(#[from] ValidationError),
+    /// Non-success HTTP status without a JSON-RPC response, e.g. a gateway error page or a
+    /// provider's quota message. See [`Error::http_error_kind`].
+    #[error("HTTP status {status}: {body_snippet}")]
+    HttpStatus {
+        status: u16,
+        /// Start of the response body, or the status reason if the body is empty
+        body_snippet: String,
+        /// Delay requested by the `Retry-After` header
+        retry_after: Option<std::time::Duration>,
+    },
+    /// Error raised by a middleware layer of a custom `tower` service, see
+    /// `NearRpcClient::from_service`
+    #[error("service error: {0}")]
+    Service(#[source] Arc<dyn std::error::Error + Send + Sync>),
+}
+
+impl Error {
+    /// Classify an [`Error::HttpStatus`] by its status and body
+    pub fn http_error_kind(&self) -> Option<HttpErrorKind> {
+        match self {
+            Error::HttpStatus {
+                status,
+                body_snippet,
+                ..
+            } => HttpErrorKind::classify(*status, body_snippet),
+            _ => None,
+        }
+    }
+}
+
+/// Why an endpoint answered with an HTTP error status
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HttpErrorKind {
+    /// The API key is missing or rejected
+    Unauthorized,
+    /// The provider plan's quota is used up; retrying will not help until it resets
+    QuotaExceeded,
+    /// Too many requests for now (429), retry after `retry_after`
+    RateLimited,
+    /// Any other 4xx: the request was rejected
+    Client,
+    /// 5xx: the node or a gateway in front of it failed, retrying may help
+    Server,
+}
+
+impl HttpErrorKind {
+    /// Body fragments providers use for quota errors, which come with 402, 403 or 429
+    const QUOTA: &'static [&'static str] = &[
+        "quota",
+        "credits",
+        "capacity limit",
+        "daily limit",
+        "monthly limit",
+        "usage limit",
+        "plan limit",
+        "upgrade your plan",
+        "payment required",
+    ];
+    /// Body fragments providers use for a missing or invalid API key
+    const AUTH: &'static [&'static str] = &[
+        "api key",
+        "api-key",
+        "api_key",
+        "apikey",
+        "unauthorized",
+        "unauthenticated",
+        "authentication",
+        "access token",
+        "invalid token",
+        "access denied",
+    ];
+
+    /// Classify `status`, looking at the body to tell quota and auth errors apart
+    pub fn classify(status: u16, body: &str) -> Option<Self> {
+        let body = body.to_lowercase();
+        let mentions = |fragments: &[&str]| fragments.iter().any(|f| body.contains(f));
+        match status {
+            402 => Some(Self::QuotaExceeded),
+            400..=499 if mentions(Self::QUOTA) => Some(Self::QuotaExceeded),
+            401 | 403 => Some(Self::Unauthorized),
+            429 => Some(Self::RateLimited),
+            400..=499 if mentions(Self::AUTH) => Some(Self::Unauthorized),
+            400..=499 => Some(Self::Client),
+            500..=599 => Some(Self::Server),
+            _ => None,
+        }
+    }
+
+    /// Whether the same request may succeed later
+    pub fn is_transient(self) -> bool {
+        matches!(self, Self::RateLimited | Self::Server)
+    }
+}
+
+#[cfg(any(feature = "reqwest", feature = "blocking"))]
+impl From<reqwest::Error> for Error {
+    fn from(error: reqwest::Error) -> Self {
+        Error::Http(Arc::new(error))
+    }
+}
+
+impl From<DecodeError> for Error {
+    fn from(error: DecodeError) -> Self {
+        Error::Decode(Arc::new(error))
+    }
+}
+
+impl From<Arc<DecodeError>> for Error {
+    fn from(error: Arc<DecodeError>) -> Self {
+        Error::Decode(error)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(error: serde_json::Error) -> Self {
+        Error::Json(Arc::new(error))
+    }
+}
+
+/// Result type alias for client operations
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// A response that could not be parsed.
+///
+/// Besides the serde error, it locates the failure in the response: the `path` where
+/// deserialization stopped and, since untagged enums like `RpcQueryResponse` only report
+/// that no variant matched, the mismatches with the schema of the method's response.
+#[derive(Debug, thiserror::Error)]
+pub struct DecodeError {
+    pub method: String,
+    /// URL of the endpoint, empty for clients built from a custom service
+    pub endpoint: String,
+    /// JSON-RPC request id, known when the HTTP body itself could not be parsed
+    pub request_id: Option<u64>,
+    /// HTTP status, known when the HTTP body itself could not be parsed
+    pub status: Option<u16>,
+    /// Path of the value that failed to parse, e.g. `header.gas_price`, `.` for the root
+    pub path: String,
+    /// Mismatches with the schema of the method's response
+    pub issues: Vec<validation::Issue>,
+    /// The response body or `result`, truncated to [`DecodeError::MAX_BODY`] bytes
+    pub body: String,
+    #[source]
+    pub source: serde_json::Error,
+}
+
+impl DecodeError {
+    /// Bytes of the response kept in [`body`](Self::body)
+    pub const MAX_BODY: usize = 1024;
+
+    /// Parse the `result` of `method` as `T`
+    pub(crate) fn decode<T: DeserializeOwned>(
+        method: &str,
+        endpoint: &str,
+        result: &Value,
+    ) -> std::result::Result<T, Arc<Self>> {
+        serde_path_to_error::deserialize(result).map_err(|error| {
+            let issues = validation::validate_response(method, result)
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|issue| issue.kind == validation::IssueKind::Mismatch)
+                .collect();
+            Arc::new(DecodeError {
+                method: method.to_string(),
+                endpoint: endpoint.to_string(),
+                request_id: None,
+                status: None,
+                path: error.path().to_string(),
+                issues,
+                body: truncate(&result.to_string(), DecodeError::MAX_BODY),
+                source: error.into_inner(),
+            })
+        })
+    }
+}
+
+impl std::fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "failed to parse response to `{}`", self.method)?;
+        if !self.endpoint.is_empty() {
+            write!(f, " from {}", self.endpoint)?;
+        }
+        if let Some(id) = self.request_id {
+            write!(f, " (request id {id})")?;
+        }
+        if let Some(status) = self.status {
+            write!(f, " (HTTP {status})")?;
+        }
+        write!(f, " at `{}`: {}", self.path, self.source)?;
+        // The deepest mismatch is the most specific one
+        let deepest = self
+            .issues
+            .iter()
+            .rev()
+            .max_by_key(|issue| issue.pointer.matches('/').count());
+        if let Some(issue) = deepest {
+            write!(f, "; schema mismatch at {issue}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The first `max` bytes of `body`, cut at a character boundary
+fn truncate(body: &str, max: usize) -> String {
+    if body.len() <= max {
+        return body.to_string();
+    }
+    let mut end = max;
+    while !body.is_char_boundary(end) {
+        end -= 1;
+    }
+    format!("{}...", &body[..end])
+}
+
+/// A typed response with the JSON it was parsed from, see
+/// [`NearRpcClient::request_with_raw`](crate::NearRpcClient::request_with_raw)
+#[derive(Clone, Debug)]
+pub struct WithRaw<T> {
+    pub value: T,
+    /// The `result` as sent by the node, including fields `T` does not know
+    pub raw: Value,
+}
+
+/// A response that may not match its type, see [`NearRpcClient::request_lenient`](crate::NearRpcClient::request_lenient)
+#[derive(Clone, Debug)]
+pub struct Lenient<T> {
+    /// The `result` as sent by the node
+    pub raw: Value,
+    /// `raw` parsed as `T`, or why it could not be
+    pub parsed: std::result::Result<T, Arc<DecodeError>>,
+}
+
+impl<T> Lenient<T> {
+    /// The typed response, failing like a typed call if it could not be parsed
+    pub fn into_parsed(self) -> Result<T> {
+        self.parsed.map_err(Error::Decode)
+    }
+}
+
+/// Extract the `result` of the JSON-RPC response to request `id` for `method` from the
+/// HTTP response of `endpoint`
+pub(crate) fn read_body(
+    method: &str,
+    id: u64,
+    status: StatusCode,
+    endpoint: String,
+    headers: &HeaderMap,
+    body: &[u8],
+) -> Result<Value> {
+    let mut deserializer = serde_json::Deserializer::from_slice(body);
+    let response: RpcResponse<Value> = match serde_path_to_error::deserialize(&mut deserializer) {
+        Ok(response) => response,
+        // nearcore sends JSON-RPC errors with 4xx and 5xx statuses, so the status only
+        // matters when the body is something else
+        Err(_) if !status.is_success() => {
+            return Err(Error::HttpStatus {
+                status: status.as_u16(),
+                body_snippet: body_snippet(status, body),
+                retry_after: retry_after(headers),
+            })
+        }
+        Err(error) => {
+            return Err(DecodeError {
+                method: method.to_string(),
+                endpoint,
+                request_id: Some(id),
+                status: Some(status.as_u16()),
+                path: error.path().to_string(),
+                issues: Vec::new(),
+                body: truncate(&String::from_utf8_lossy(body), DecodeError::MAX_BODY),
+                source: error.into_inner(),
+            }
+            .into())
+        }
+    };
+
+    match response.result {
+        RpcResult::Ok { result } => Ok(result),
+        RpcResult::Err { error } => Err(Error::Rpc(error)),
+    }
+}
+
+/// Bytes of an error body kept in [`Error::HttpStatus`]
+const MAX_SNIPPET: usize = 256;
+
+/// A one-line excerpt of an error `body`: the title of an HTML page, or the start of the text
+fn body_snippet(status: StatusCode, body: &[u8]) -> String {
+    let body = String::from_utf8_lossy(body);
+    let text = html_title(&body).unwrap_or(&body);
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if text.is_empty() {
+        return status.canonical_reason().unwrap_or_default().to_string();
+    }
+    truncate(&text, MAX_SNIPPET)
+}
+
+/// The content of the `<title>` element of an HTML page
+fn html_title(body: &str) -> Option<&str> {
+    // ASCII lowercasing keeps byte offsets
+    let lower = body.to_ascii_lowercase();
+    let start = lower.find("<title")?;
+    let start = start + lower[start..].find('>')? + 1;
+    let end = start + lower[start..].find("</title")?;
+    Some(&body[start..end])
+}
+
+/// Parse a `Retry-After` header given in seconds
+pub(crate) fn retry_after(headers: &HeaderMap) -> Option<std::time::Duration> {
+    let value = headers.get(http::header::RETRY_AFTER)?;
+    let seconds = value.to_str().ok()?.trim().parse().ok()?;
+    Some(std::time::Duration::from_secs(seconds))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_http_error_kind() {
+        use HttpErrorKind::*;
+        let cases = [
+            (401, "Unauthorized", Unauthorized),
+            (403, "Forbidden", Unauthorized),
+            (400, "Invalid API key provided", Unauthorized),
+            (402, "", QuotaExceeded),
+            (
+                429,
+                "Monthly capacity limit exceeded. Upgrade your plan.",
+                QuotaExceeded,
+            ),
+            (403, "Daily request quota exceeded", QuotaExceeded),
+            (429, "Rate limit exceeded", RateLimited),
+            (404, "Not Found", Client),
+            (502, "Bad Gateway", Server),
+            (504, "upstream request timeout", Server),
+        ];
+        for (status, body, kind) in cases {
+            assert_eq!(
+                HttpErrorKind::classify(status, body),
+                Some(kind),
+                "{status} {body}"
+            );
+        }
+        assert_eq!(HttpErrorKind::classify(304, ""), None);
+        assert!(RateLimited.is_transient() && Server.is_transient());
+        assert!(!QuotaExceeded.is_transient() && !Unauthorized.is_transient());
+    }
+}
@@ -1,8 +1,26 @@
 //! Generated Rust types and client for NEAR Protocol JSON-RPC API.
 //!
 //! This crate provides:
-//! - `types` module: All RPC request/response types generated from the OpenRPC schema
-//! - `client` module (with `client` feature): A simple async RPC client
+//! - `types` module: All RPC request/response types generated from the OpenRPC schema, from
+//!   the `near-rpc-types` crate
+//! - `methods` module: Table of the RPC methods, and a marker type per method for typed calls
+//! - `validation` module: Checks of raw responses against the OpenRPC JSON Schema
+//! - `client` module (with `client` feature): A simple async RPC client, for any executor
+//! - `transport` module (with `client` feature): The HTTP transport of the client, using
+//!   `reqwest` with the `reqwest` feature
+//! - `blocking` module (with `blocking` feature): The same client for synchronous code
+//! - `cache` module (with `client` feature): Opt-in caching of immutable responses
+//! - `rate_limit` module (with `tokio` feature): Client-side rate limiting and concurrency caps
+//! - `metrics` module (with `client` feature): Hooks for request counts, errors and latency
+//! - `snapshot` module (with `client` feature): Consistent reads pinned to a single block
+//! - `service` module (with `tower` feature): The call path as a `tower::Service` for middleware
+//! - `tracing` feature: An `rpc_call` span with method, request id, endpoint and latency per call
+//! - `server` module (with `client` feature): `NearRpcHandler` trait and dispatcher for serving
+//!   JSON-RPC, with an HTTP adapter behind the `server` feature
+//! - `proxy` module (with `proxy` feature): Caching, failing-over proxy, run by the
+//!   `near-rpc-proxy` binary
+//! - `testing` module (with `testing` feature): Mock JSON-RPC server for offline tests
+//! - `light_client` module (with `light-client` feature): Local verification of light client proofs
 //!
 //! # Example
 //!
@@ -16,13 +34,71 @@
 //!     println!("Chain ID: {:?}", status);
 //! }
 //! ```
+//!
+//! # WebAssembly
+//!
+//! The crate builds for `wasm32-unknown-unknown` with `default-features = false` and the
+//! `client` feature. The client then sends requests with the host's `fetch`, in browsers,
+//! Node and Cloudflare Workers; futures are not `Send` there.
 
 pub mod types;
 
+pub mod methods;
+
+pub mod validation;
+
+#[cfg(any(feature = "client", feature = "blocking"))]
+mod jsonrpc;
+
 #[cfg(feature = "client")]
 pub mod client;
 
+#[cfg(feature = "blocking")]
+pub mod blocking;
+
+#[cfg(feature = "client")]
+pub mod cache;
+
+#[cfg(feature = "client")]
+mod coalesce;
+
+#[cfg(feature = "client")]
+pub mod metrics;
+
+#[cfg(feature = "tokio")]
+pub mod rate_limit;
+
+#[cfg(feature = "client")]
+pub mod snapshot;
+
+#[cfg(feature = "client")]
+pub mod transport;
+
+#[cfg(feature = "client")]
+pub mod server;
+
+#[cfg(feature = "proxy")]
+pub mod proxy;
+
+#[cfg(feature = "tower")]
+pub mod service;
+
+#[cfg(feature = "tracing")]
+mod instrument;
+
+#[cfg(feature = "testing")]
+pub mod testing;
+
 #[cfg(feature = "client")]
 pub use client::NearRpcClient;
 
+#[cfg(feature = "blocking")]
+pub use blocking::NearRpcBlockingClient;
+
+#[cfg(feature = "client")]
+pub use snapshot::{BlockSnapshot, Pinned};
+
+#[cfg(feature = "light-client")]
+pub mod light_client;
+
 pub use types::*;
@@ -0,0 +1,163 @@
+//! Minimal Borsh encoding for the structures that feed into NEAR hashes.
+
+use super::{Error, Result};
+use crate::types::CryptoHash;
+use sha2::{Digest, Sha256};
+
+/// Raw 32-byte hash
+pub(crate) type Hash = [u8; 32];
+
+pub(crate) fn sha256(data: &[u8]) -> Hash {
+    Sha256::digest(data).into()
+}
+
+pub(crate) fn decode_hash(hash: &CryptoHash) -> Result<Hash> {
+    let mut out = [0u8; 32];
+    match bs58::decode(&hash.0).onto(&mut out) {
+        Ok(32) => Ok(out),
+        _ => Err(Error::InvalidHash(hash.0.clone())),
+    }
+}
+
+pub(crate) fn encode_hash(hash: &Hash) -> CryptoHash {
+    CryptoHash(bs58::encode(hash).into_string())
+}
+
+/// Flips one byte of `hash`, for tamper tests
+#[cfg(test)]
+pub(crate) fn flip(hash: &mut CryptoHash) {
+    let mut bytes = decode_hash(hash).unwrap();
+    bytes[7] ^= 1;
+    *hash = encode_hash(&bytes);
+}
+
+/// Append-only Borsh writer
+#[derive(Default)]
+pub(crate) struct Writer {
+    buf: Vec<u8>,
+}
+
+impl Writer {
+    pub fn u8(&mut self, value: u8) -> &mut Self {
+        self.buf.push(value);
+        self
+    }
+
+    pub fn u32(&mut self, value: u32) -> &mut Self {
+        self.buf.extend_from_slice(&value.to_le_bytes());
+        self
+    }
+
+    pub fn u64(&mut self, value: u64) -> &mut Self {
+        self.buf.extend_from_slice(&value.to_le_bytes());
+        self
+    }
+
+    pub fn u128(&mut self, value: u128) -> &mut Self {
+        self.buf.extend_from_slice(&value.to_le_bytes());
+        self
+    }
+
+    pub fn hash(&mut self, value: &Hash) -> &mut Self {
+        self.raw(value)
+    }
+
+    /// Fixed-size bytes, written without a length prefix
+    pub fn raw(&mut self, value: &[u8]) -> &mut Self {
+        self.buf.extend_from_slice(value);
+        self
+    }
+
+    /// Length-prefixed byte string (`Vec<u8>` and `String`)
+    pub fn bytes(&mut self, value: &[u8]) -> &mut Self {
+        self.u32(value.len() as u32);
+        self.buf.extend_from_slice(value);
+        self
+    }
+
+    pub fn finish(&self) -> &[u8] {
+        &self.buf
+    }
+}
+
+/// Borsh reader over a byte slice; every method returns `None` past the end of the input.
+///
+/// Only the fake chain of [`testing`](crate::testing) decodes Borsh.
+#[cfg(feature = "testing")]
+pub(crate) struct Reader<'a> {
+    buf: &'a [u8],
+}
+
+#[cfg(feature = "testing")]
+impl<'a> Reader<'a> {
+    pub fn new(buf: &'a [u8]) -> Self {
+        Self { buf }
+    }
+
+    /// Number of bytes left
+    pub fn len(&self) -> usize {
+        self.buf.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.buf.is_empty()
+    }
+
+    /// Fixed-size bytes without a length prefix
+    pub fn raw(&mut self, len: usize) -> Option<&'a [u8]> {
+        if self.buf.len() < len {
+            return None;
+        }
+        let (value, rest) = self.buf.split_at(len);
+        self.buf = rest;
+        Some(value)
+    }
+
+    fn array<const N: usize>(&mut self) -> Option<[u8; N]> {
+        self.raw(N)?.try_into().ok()
+    }
+
+    pub fn u8(&mut self) -> Option<u8> {
+        self.array::<1>().map(|[value]| value)
+    }
+
+    pub fn u32(&mut self) -> Option<u32> {
+        self.array().map(u32::from_le_bytes)
+    }
+
+    pub fn u64(&mut self) -> Option<u64> {
+        self.array().map(u64::from_le_bytes)
+    }
+
+    pub fn u128(&mut self) -> Option<u128> {
+        self.array().map(u128::from_le_bytes)
+    }
+
+    pub fn hash(&mut self) -> Option<Hash> {
+        self.array()
+    }
+
+    /// Length-prefixed byte string
+    pub fn bytes(&mut self) -> Option<&'a [u8]> {
+        let len = self.u32()?;
+        self.raw(len as usize)
+    }
+
+    pub fn string(&mut self) -> Option<String> {
+        String::from_utf8(self.bytes()?.to_vec()).ok()
+    }
+}
+
+pub(crate) fn parse_u64(field: &'static str, value: &str) -> Result<u64> {
+    value.parse().map_err(|_| Error::InvalidField {
+        field,
+        value: value.to_string(),
+    })
+}
+
+pub(crate) fn parse_u128(field: &'static str, value: &str) -> Result<u128> {
+    value.parse().map_err(|_| Error::InvalidField {
+        field,
+        value: value.to_string(),
+    })
+}
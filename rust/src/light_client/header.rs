@@ -0,0 +1,196 @@
+//! Block header hashing.
+
+use super::borsh::{decode_hash, encode_hash, parse_u64, sha256, Hash, Writer};
+use super::merkle::combine_hash;
+use super::{Error, Result};
+use crate::types::{
+    BlockHeaderInnerLiteView, BlockHeaderView, CryptoHash, LightClientBlockLiteView,
+};
+
+/// Borsh encoding of `BlockHeaderInnerLite`, in nearcore field order.
+pub(crate) fn inner_lite_bytes(inner: &BlockHeaderInnerLiteView) -> Result<Vec<u8>> {
+    let mut w = Writer::default();
+    w.u64(inner.height)
+        .hash(&decode_hash(&inner.epoch_id)?)
+        .hash(&decode_hash(&inner.next_epoch_id)?)
+        .hash(&decode_hash(&inner.prev_state_root)?)
+        .hash(&decode_hash(&inner.outcome_root)?)
+        .u64(parse_u64("timestamp_nanosec", &inner.timestamp_nanosec)?)
+        .hash(&decode_hash(&inner.next_bp_hash)?)
+        .hash(&decode_hash(&inner.block_merkle_root)?);
+    Ok(w.finish().to_vec())
+}
+
+/// Computes the hash of the Borsh-serialized `BlockHeaderInnerLite`.
+pub fn inner_lite_hash(inner: &BlockHeaderInnerLiteView) -> Result<CryptoHash> {
+    Ok(encode_hash(&sha256(&inner_lite_bytes(inner)?)))
+}
+
+pub(crate) fn compute_block_hash(
+    inner_lite: &BlockHeaderInnerLiteView,
+    inner_rest_hash: &CryptoHash,
+    prev_block_hash: &CryptoHash,
+) -> Result<Hash> {
+    let inner_hash = combine_hash(
+        &sha256(&inner_lite_bytes(inner_lite)?),
+        &decode_hash(inner_rest_hash)?,
+    );
+    Ok(combine_hash(&inner_hash, &decode_hash(prev_block_hash)?))
+}
+
+/// Computes the hash of the block described by a light client header.
+pub fn block_hash(header: &LightClientBlockLiteView) -> Result<CryptoHash> {
+    compute_block_hash(
+        &header.inner_lite,
+        &header.inner_rest_hash,
+        &header.prev_block_hash,
+    )
+    .map(|hash| encode_hash(&hash))
+}
+
+/// Checks that `header` hashes to `expected`.
+pub fn verify_block_hash(header: &LightClientBlockLiteView, expected: &CryptoHash) -> Result<()> {
+    check_hash(
+        compute_block_hash(
+            &header.inner_lite,
+            &header.inner_rest_hash,
+            &header.prev_block_hash,
+        )?,
+        expected,
+    )
+}
+
+/// Builds the light client view of a full block header.
+///
+/// `BlockHeaderView` does not carry the hash of its inner rest part; it can be taken from
+/// `block_header_lite` of a `light_client_block_proof` response for the same block.
+pub fn light_client_block(
+    header: &BlockHeaderView,
+    inner_rest_hash: CryptoHash,
+) -> LightClientBlockLiteView {
+    LightClientBlockLiteView {
+        inner_lite: BlockHeaderInnerLiteView {
+            block_merkle_root: header.block_merkle_root.clone(),
+            epoch_id: header.epoch_id.clone(),
+            height: header.height,
+            next_bp_hash: header.next_bp_hash.clone(),
+            next_epoch_id: header.next_epoch_id.clone(),
+            outcome_root: header.outcome_root.clone(),
+            prev_state_root: header.prev_state_root.clone(),
+            timestamp: header.timestamp,
+            timestamp_nanosec: header.timestamp_nanosec.clone(),
+        },
+        inner_rest_hash,
+        prev_block_hash: header.prev_hash.clone(),
+    }
+}
+
+/// Checks that `header.hash` matches the hash recomputed from the header fields.
+pub fn verify_header_hash(header: &BlockHeaderView, inner_rest_hash: &CryptoHash) -> Result<()> {
+    verify_block_hash(
+        &light_client_block(header, inner_rest_hash.clone()),
+        &header.hash,
+    )
+}
+
+fn check_hash(actual: Hash, expected: &CryptoHash) -> Result<()> {
+    if actual != decode_hash(expected)? {
+        return Err(Error::BlockHashMismatch {
+            expected: expected.clone(),
+            actual: encode_hash(&actual),
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::light_client::borsh::flip;
+    use serde::Deserialize;
+
+    /// The last block of an epoch and the first block of the next one, with `hash` as
+    /// computed by nearcore. Synthetic, not chain data: `examples/capture_light_client_fixtures.rs`
+    /// writes the same shape from a mainnet archival node and should replace it.
+    #[derive(Deserialize)]
+    struct Fixture {
+        headers: Vec<Header>,
+    }
+
+    #[derive(Deserialize)]
+    struct Header {
+        header: BlockHeaderView,
+        lite: LightClientBlockLiteView,
+    }
+
+    fn headers() -> Vec<Header> {
+        let fixture: Fixture =
+            serde_json::from_str(include_str!("../../tests/fixtures/block_headers.json")).unwrap();
+        fixture.headers
+    }
+
+    #[test]
+    fn test_header_hash() {
+        let headers = headers();
+        for Header { header, lite } in &headers {
+            assert_eq!(block_hash(lite).unwrap(), header.hash);
+            verify_block_hash(lite, &header.hash).unwrap();
+            verify_header_hash(header, &lite.inner_rest_hash).unwrap();
+            assert_eq!(
+                inner_lite_hash(
+                    &light_client_block(header, lite.inner_rest_hash.clone()).inner_lite
+                )
+                .unwrap(),
+                inner_lite_hash(&lite.inner_lite).unwrap()
+            );
+        }
+        assert_eq!(headers[1].header.prev_hash, headers[0].header.hash);
+        assert_eq!(headers[1].header.epoch_id, headers[0].header.next_epoch_id);
+    }
+
+    #[test]
+    fn test_header_hash_mismatch() {
+        let headers = headers();
+        let Header { header, lite } = &headers[1];
+
+        let mismatch = |header: &BlockHeaderView, inner_rest_hash: &CryptoHash| {
+            matches!(
+                verify_header_hash(header, inner_rest_hash),
+                Err(Error::BlockHashMismatch { .. })
+            )
+        };
+
+        let mut wrong = header.clone();
+        wrong.hash = headers[0].header.hash.clone();
+        assert!(mismatch(&wrong, &lite.inner_rest_hash));
+        assert!(mismatch(header, &headers[0].lite.inner_rest_hash));
+
+        // A flipped byte in any hashed field changes the block hash.
+        let tampered: [fn(&mut BlockHeaderView); 7] = [
+            |header| flip(&mut header.epoch_id),
+            |header| flip(&mut header.next_epoch_id),
+            |header| flip(&mut header.prev_state_root),
+            |header| flip(&mut header.outcome_root),
+            |header| flip(&mut header.next_bp_hash),
+            |header| flip(&mut header.block_merkle_root),
+            |header| flip(&mut header.prev_hash),
+        ];
+        for tamper in tampered {
+            let mut header = header.clone();
+            tamper(&mut header);
+            assert!(mismatch(&header, &lite.inner_rest_hash));
+        }
+        let mut inner_rest_hash = lite.inner_rest_hash.clone();
+        flip(&mut inner_rest_hash);
+        assert!(mismatch(header, &inner_rest_hash));
+
+        let mut wrong = header.clone();
+        wrong.height += 1;
+        assert!(mismatch(&wrong, &lite.inner_rest_hash));
+
+        let mut wrong = header.clone();
+        let timestamp: u64 = wrong.timestamp_nanosec.parse().unwrap();
+        wrong.timestamp_nanosec = (timestamp + 1).to_string();
+        assert!(mismatch(&wrong, &lite.inner_rest_hash));
+    }
+}
@@ -0,0 +1,30 @@
+//! Merkle path folding.
+
+use super::borsh::{decode_hash, encode_hash, sha256, Hash};
+use super::Result;
+use crate::types::{CryptoHash, Direction, MerklePathItem};
+
+pub(crate) fn combine_hash(left: &Hash, right: &Hash) -> Hash {
+    let mut buf = [0u8; 64];
+    buf[..32].copy_from_slice(left);
+    buf[32..].copy_from_slice(right);
+    sha256(&buf)
+}
+
+pub(crate) fn fold_path(path: &[MerklePathItem], item_hash: Hash) -> Result<Hash> {
+    path.iter().try_fold(item_hash, |acc, item| {
+        let sibling = decode_hash(&item.hash)?;
+        Ok(match item.direction {
+            Direction::Left => combine_hash(&sibling, &acc),
+            Direction::Right => combine_hash(&acc, &sibling),
+        })
+    })
+}
+
+/// Computes the merkle root reached by folding `path` upwards from `item_hash`.
+pub fn compute_root_from_path(
+    path: &[MerklePathItem],
+    item_hash: &CryptoHash,
+) -> Result<CryptoHash> {
+    fold_path(path, decode_hash(item_hash)?).map(|root| encode_hash(&root))
+}
@@ -0,0 +1,93 @@
+//! Local verification of NEAR light client data.
+//!
+//! RPC providers are not trusted: everything returned by `light_client_proof` and
+//! `light_client_block_proof` can be checked here against a block merkle root taken
+//! from a header the caller already trusts. [`LightClient`] keeps such a header up to
+//! date by validating `next_light_client_block` responses against block producer
+//! signatures, and [`verify_header_hash`] detects headers whose claimed hash does not
+//! match their contents.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use near_rpc_client::{light_client, types::*, NearRpcClient};
+//!
+//! # async fn example(request: RpcLightClientExecutionProofRequest, trusted_head: LightClientBlockLiteView) -> Result<(), Box<dyn std::error::Error>> {
+//! let client = NearRpcClient::mainnet();
+//! let proof = client.light_client_proof(request).await?;
+//! light_client::verify_execution_proof(&proof, &trusted_head.inner_lite.block_merkle_root)?;
+//! # Ok(())
+//! # }
+//! ```
+
+pub(crate) mod borsh;
+mod header;
+mod merkle;
+mod proof;
+pub(crate) mod sync;
+
+pub use header::{
+    block_hash, inner_lite_hash, light_client_block, verify_block_hash, verify_header_hash,
+};
+pub use merkle::compute_root_from_path;
+pub use proof::{outcome_hash, verify_block_proof, verify_execution_proof};
+pub use sync::{LightClient, LightClientState};
+
+use crate::types::{AccountId, CryptoHash};
+
+/// Light client verification error
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("invalid hash {0:?}")]
+    InvalidHash(String),
+    #[error("invalid {field}: {value:?}")]
+    InvalidField { field: &'static str, value: String },
+    #[error("outcome root mismatch: header has {expected}, proof computes {actual}")]
+    OutcomeRootMismatch {
+        expected: CryptoHash,
+        actual: CryptoHash,
+    },
+    #[error("outcome block mismatch: header follows {expected}, outcome is in {actual}")]
+    OutcomeBlockMismatch {
+        expected: CryptoHash,
+        actual: CryptoHash,
+    },
+    #[error("block hash mismatch: expected {expected}, header hashes to {actual}")]
+    BlockHashMismatch {
+        expected: CryptoHash,
+        actual: CryptoHash,
+    },
+    #[error("block merkle root mismatch: expected {expected}, proof computes {actual}")]
+    BlockMerkleRootMismatch {
+        expected: CryptoHash,
+        actual: CryptoHash,
+    },
+    #[error("missing field {0}")]
+    MissingField(&'static str),
+    #[error("block height {height} is not newer than head height {head_height}")]
+    NotNewer { height: u64, head_height: u64 },
+    #[error("block epoch {0} is neither the current nor the next epoch of the head")]
+    UnexpectedEpoch(CryptoHash),
+    #[error("block producers of epoch {0} are unknown")]
+    UnknownBlockProducers(CryptoHash),
+    #[error("unsupported key type: {0}")]
+    UnsupportedKeyType(String),
+    #[error("invalid approval signature from {0}")]
+    InvalidSignature(AccountId),
+    #[error("approved stake {approved_stake} is not more than 2/3 of {total_stake}")]
+    InsufficientApprovals {
+        approved_stake: u128,
+        total_stake: u128,
+    },
+    #[error("next_bp_hash mismatch: header has {expected}, next_bps hash to {actual}")]
+    NextBpHashMismatch {
+        expected: CryptoHash,
+        actual: CryptoHash,
+    },
+    #[cfg(feature = "client")]
+    #[error("RPC client error: {0}")]
+    Client(#[from] crate::client::Error),
+}
+
+/// Result type alias for light client operations
+pub type Result<T> = std::result::Result<T, Error>;
@@ -0,0 +1,260 @@
+//! Execution outcome and block inclusion proofs.
+
+use super::borsh::{decode_hash, encode_hash, parse_u128, sha256, Hash, Writer};
+use super::header::compute_block_hash;
+use super::merkle::fold_path;
+use super::{Error, Result};
+use crate::types::{
+    CryptoHash, ExecutionOutcomeWithIdView, ExecutionStatusView, MerklePathItem,
+    RpcLightClientBlockProofResponse, RpcLightClientExecutionProofResponse,
+};
+use base64::Engine;
+
+/// Borsh encoding of `PartialExecutionOutcome` (the outcome without logs and metadata).
+fn partial_outcome_bytes(outcome: &ExecutionOutcomeWithIdView) -> Result<Vec<u8>> {
+    let outcome = &outcome.outcome;
+    let mut w = Writer::default();
+    w.u32(outcome.receipt_ids.len() as u32);
+    for receipt_id in &outcome.receipt_ids {
+        w.hash(&decode_hash(receipt_id)?);
+    }
+    w.u64(outcome.gas_burnt.0)
+        .u128(parse_u128("tokens_burnt", &outcome.tokens_burnt.0)?)
+        .bytes(outcome.executor_id.0.as_bytes());
+    match &outcome.status {
+        ExecutionStatusView::Unknown => w.u8(0),
+        ExecutionStatusView::Failure(_) => w.u8(1),
+        ExecutionStatusView::SuccessValue(value) => {
+            let value = base64::engine::general_purpose::STANDARD
+                .decode(value)
+                .map_err(|_| Error::InvalidField {
+                    field: "SuccessValue",
+                    value: value.clone(),
+                })?;
+            w.u8(2).bytes(&value)
+        }
+        ExecutionStatusView::SuccessReceiptId(id) => w.u8(3).hash(&decode_hash(id)?),
+    };
+    Ok(w.finish().to_vec())
+}
+
+fn compute_outcome_hash(outcome: &ExecutionOutcomeWithIdView) -> Result<Hash> {
+    // Borsh `Vec<CryptoHash>` of [id, hash(partial outcome), hash(log) for each log].
+    let mut w = Writer::default();
+    w.u32(outcome.outcome.logs.len() as u32 + 2)
+        .hash(&decode_hash(&outcome.id)?)
+        .hash(&sha256(&partial_outcome_bytes(outcome)?));
+    for log in &outcome.outcome.logs {
+        w.hash(&sha256(log.as_bytes()));
+    }
+    Ok(sha256(w.finish()))
+}
+
+/// Computes the hash of an execution outcome as it is committed to the shard outcome root.
+pub fn outcome_hash(outcome: &ExecutionOutcomeWithIdView) -> Result<CryptoHash> {
+    compute_outcome_hash(outcome).map(|hash| encode_hash(&hash))
+}
+
+fn check_block_merkle_root(
+    block_hash: Hash,
+    block_proof: &[MerklePathItem],
+    block_merkle_root: &CryptoHash,
+) -> Result<()> {
+    let actual = fold_path(block_proof, block_hash)?;
+    if actual != decode_hash(block_merkle_root)? {
+        return Err(Error::BlockMerkleRootMismatch {
+            expected: block_merkle_root.clone(),
+            actual: encode_hash(&actual),
+        });
+    }
+    Ok(())
+}
+
+/// Verifies a `light_client_proof` response.
+///
+/// Checks that the outcome is included in the `outcome_root` of `block_header_lite`, that
+/// the block of the outcome is the one `block_header_lite` follows, and that
+/// `block_header_lite` is included in `block_merkle_root`, which must come from a trusted
+/// light client head.
+pub fn verify_execution_proof(
+    proof: &RpcLightClientExecutionProofResponse,
+    block_merkle_root: &CryptoHash,
+) -> Result<()> {
+    // The outcome root of a block commits to the outcomes of its previous block.
+    let header = &proof.block_header_lite;
+    if proof.outcome_proof.block_hash != header.prev_block_hash {
+        return Err(Error::OutcomeBlockMismatch {
+            expected: header.prev_block_hash.clone(),
+            actual: proof.outcome_proof.block_hash.clone(),
+        });
+    }
+
+    let outcome_hash = compute_outcome_hash(&proof.outcome_proof)?;
+    let shard_outcome_root = fold_path(&proof.outcome_proof.proof, outcome_hash)?;
+    // Shard roots are merklized as Borsh items, so the leaf is hashed once more.
+    let outcome_root = fold_path(&proof.outcome_root_proof, sha256(&shard_outcome_root))?;
+    if outcome_root != decode_hash(&header.inner_lite.outcome_root)? {
+        return Err(Error::OutcomeRootMismatch {
+            expected: header.inner_lite.outcome_root.clone(),
+            actual: encode_hash(&outcome_root),
+        });
+    }
+
+    let block_hash = compute_block_hash(
+        &header.inner_lite,
+        &header.inner_rest_hash,
+        &header.prev_block_hash,
+    )?;
+    check_block_merkle_root(block_hash, &proof.block_proof, block_merkle_root)
+}
+
+/// Verifies a `light_client_block_proof` response against a trusted `block_merkle_root`.
+pub fn verify_block_proof(
+    proof: &RpcLightClientBlockProofResponse,
+    block_merkle_root: &CryptoHash,
+) -> Result<()> {
+    let header = &proof.block_header_lite;
+    let block_hash = compute_block_hash(
+        &header.inner_lite,
+        &header.inner_rest_hash,
+        &header.prev_block_hash,
+    )?;
+    check_block_merkle_root(block_hash, &proof.block_proof, block_merkle_root)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::light_client::borsh::flip;
+    use crate::types::BlockHeaderView;
+    use serde::Deserialize;
+
+    /// Synthetic, not chain data: hashes, merkle paths and signatures computed by nearcore
+    /// with deterministic keys. `examples/capture_light_client_fixtures.rs` writes the same
+    /// shape from a mainnet archival node and should replace it.
+    #[derive(Deserialize)]
+    struct Fixture {
+        light_client_head: BlockHeaderView,
+        proof: RpcLightClientExecutionProofResponse,
+    }
+
+    fn fixture() -> Fixture {
+        serde_json::from_str(include_str!("../../tests/fixtures/light_client_proof.json")).unwrap()
+    }
+
+    #[test]
+    fn test_outcome_and_block_hash() {
+        let Fixture {
+            light_client_head: head,
+            proof,
+        } = fixture();
+        let root = &head.block_merkle_root;
+        assert!(matches!(
+            verify_tampered(&proof, root, |p| flip(&mut p.outcome_proof.block_hash)),
+            Err(Error::OutcomeBlockMismatch { .. })
+        ));
+        assert!(matches!(
+            verify_tampered(&proof, root, |p| flip(
+                &mut p.block_header_lite.prev_block_hash
+            )),
+            Err(Error::OutcomeBlockMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_verify_execution_proof() {
+        let Fixture {
+            light_client_head: head,
+            proof,
+        } = fixture();
+        let root = &head.block_merkle_root;
+        verify_execution_proof(&proof, root).unwrap();
+
+        let mut tampered = proof.clone();
+        tampered
+            .outcome_proof
+            .outcome
+            .logs
+            .push("forged".to_string());
+        assert!(matches!(
+            verify_execution_proof(&tampered, root),
+            Err(Error::OutcomeRootMismatch { .. })
+        ));
+
+        let mut tampered = proof.clone();
+        tampered.block_header_lite.inner_lite.height += 1;
+        assert!(matches!(
+            verify_execution_proof(&tampered, root),
+            Err(Error::BlockMerkleRootMismatch { .. })
+        ));
+    }
+
+    fn verify_tampered(
+        proof: &RpcLightClientExecutionProofResponse,
+        root: &CryptoHash,
+        tamper: impl FnOnce(&mut RpcLightClientExecutionProofResponse),
+    ) -> Result<()> {
+        let mut proof = proof.clone();
+        tamper(&mut proof);
+        verify_execution_proof(&proof, root)
+    }
+
+    #[test]
+    fn test_flipped_byte() {
+        let Fixture {
+            light_client_head: head,
+            proof,
+        } = fixture();
+        let root = &head.block_merkle_root;
+
+        assert!(matches!(
+            verify_tampered(&proof, root, |p| flip(&mut p.outcome_proof.id)),
+            Err(Error::OutcomeRootMismatch { .. })
+        ));
+        for i in 0..proof.outcome_proof.proof.len() {
+            assert!(matches!(
+                verify_tampered(&proof, root, |p| flip(&mut p.outcome_proof.proof[i].hash)),
+                Err(Error::OutcomeRootMismatch { .. })
+            ));
+        }
+        for i in 0..proof.outcome_root_proof.len() {
+            assert!(matches!(
+                verify_tampered(&proof, root, |p| flip(&mut p.outcome_root_proof[i].hash)),
+                Err(Error::OutcomeRootMismatch { .. })
+            ));
+        }
+        for i in 0..proof.block_proof.len() {
+            assert!(matches!(
+                verify_tampered(&proof, root, |p| flip(&mut p.block_proof[i].hash)),
+                Err(Error::BlockMerkleRootMismatch { .. })
+            ));
+        }
+        assert!(matches!(
+            verify_tampered(&proof, root, |p| flip(
+                &mut p.block_header_lite.inner_rest_hash
+            )),
+            Err(Error::BlockMerkleRootMismatch { .. })
+        ));
+
+        let mut root = root.clone();
+        flip(&mut root);
+        assert!(matches!(
+            verify_execution_proof(&proof, &root),
+            Err(Error::BlockMerkleRootMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_verify_block_proof() {
+        let Fixture {
+            light_client_head: head,
+            proof,
+        } = fixture();
+        let proof = RpcLightClientBlockProofResponse {
+            block_header_lite: proof.block_header_lite,
+            block_proof: proof.block_proof,
+        };
+        verify_block_proof(&proof, &head.block_merkle_root).unwrap();
+        assert!(verify_block_proof(&proof, &head.hash).is_err());
+    }
+}
@@ -0,0 +1,400 @@
+//! Light client header sync.
+
+use super::borsh::{decode_hash, encode_hash, parse_u128, sha256, Hash, Writer};
+use super::header::compute_block_hash;
+use super::merkle::combine_hash;
+use super::{Error, Result};
+use crate::types::{
+    CryptoHash, LightClientBlockLiteView, PublicKey, RpcLightClientNextBlockResponse, Signature,
+    ValidatorStakeView,
+};
+use ed25519_dalek::{Verifier, VerifyingKey};
+use serde::{Deserialize, Serialize};
+
+/// Trusted light client state, suitable for persisting between runs.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LightClientState {
+    /// Latest verified header
+    pub head: LightClientBlockLiteView,
+    /// Block producers of `head.inner_lite.epoch_id`
+    pub epoch_block_producers: Vec<ValidatorStakeView>,
+    /// Block producers of `head.inner_lite.next_epoch_id`, once known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_epoch_block_producers: Option<Vec<ValidatorStakeView>>,
+}
+
+/// Light client that follows the chain from a trusted head using `next_light_client_block`.
+///
+/// Every new header must be approved by block producers holding more than 2/3 of the stake
+/// of its epoch. Block producer sets are rotated at epoch boundaries using `next_bps`, which
+/// is checked against `next_bp_hash` of the header that announces it.
+#[derive(Clone, Debug)]
+pub struct LightClient {
+    state: LightClientState,
+}
+
+impl LightClient {
+    /// Create a light client from a trusted state
+    pub fn new(state: LightClientState) -> Self {
+        Self { state }
+    }
+
+    /// Current trusted head
+    pub fn head(&self) -> &LightClientBlockLiteView {
+        &self.state.head
+    }
+
+    /// Hash of the current trusted head
+    pub fn head_hash(&self) -> Result<CryptoHash> {
+        super::block_hash(&self.state.head)
+    }
+
+    /// Current trusted state
+    pub fn state(&self) -> &LightClientState {
+        &self.state
+    }
+
+    /// Consume the client, returning its state
+    pub fn into_state(self) -> LightClientState {
+        self.state
+    }
+
+    /// Validate `block` against the current head and advance the head to it.
+    pub fn update(&mut self, block: &RpcLightClientNextBlockResponse) -> Result<()> {
+        let (head, next_bps) = self.validate(block)?;
+        let new_epoch = head.inner_lite.epoch_id != self.state.head.inner_lite.epoch_id;
+        if new_epoch {
+            self.state.epoch_block_producers = self
+                .state
+                .next_epoch_block_producers
+                .take()
+                .expect("validated block producers");
+        }
+        if next_bps.is_some() {
+            self.state.next_epoch_block_producers = next_bps;
+        }
+        self.state.head = head;
+        Ok(())
+    }
+
+    /// Validate `block` against the current head without modifying the state.
+    ///
+    /// Returns the validated header and its `next_bps`, if any.
+    pub fn validate(
+        &self,
+        block: &RpcLightClientNextBlockResponse,
+    ) -> Result<(LightClientBlockLiteView, Option<Vec<ValidatorStakeView>>)> {
+        let head = &self.state.head;
+        let header = LightClientBlockLiteView {
+            inner_lite: required(&block.inner_lite, "inner_lite")?,
+            inner_rest_hash: required(&block.inner_rest_hash, "inner_rest_hash")?,
+            prev_block_hash: required(&block.prev_block_hash, "prev_block_hash")?,
+        };
+        let next_block_inner_hash = decode_hash(&required(
+            &block.next_block_inner_hash,
+            "next_block_inner_hash",
+        )?)?;
+
+        if header.inner_lite.height <= head.inner_lite.height {
+            return Err(Error::NotNewer {
+                height: header.inner_lite.height,
+                head_height: head.inner_lite.height,
+            });
+        }
+
+        let block_producers =
+            if header.inner_lite.epoch_id == head.inner_lite.epoch_id {
+                &self.state.epoch_block_producers
+            } else if header.inner_lite.epoch_id == head.inner_lite.next_epoch_id {
+                if block.next_bps.is_none() {
+                    return Err(Error::MissingField("next_bps"));
+                }
+                self.state.next_epoch_block_producers.as_ref().ok_or(
+                    Error::UnknownBlockProducers(header.inner_lite.epoch_id.clone()),
+                )?
+            } else {
+                return Err(Error::UnexpectedEpoch(header.inner_lite.epoch_id.clone()));
+            };
+
+        let block_hash = compute_block_hash(
+            &header.inner_lite,
+            &header.inner_rest_hash,
+            &header.prev_block_hash,
+        )?;
+        let next_block_hash = combine_hash(&next_block_inner_hash, &block_hash);
+        let message = approval_message(&next_block_hash, header.inner_lite.height + 2);
+
+        let mut total_stake = 0u128;
+        let mut approved_stake = 0u128;
+        for (index, producer) in block_producers.iter().enumerate() {
+            let stake = parse_u128("stake", &producer.stake.0)?;
+            total_stake += stake;
+            let Some(Some(signature)) = block.approvals_after_next.get(index) else {
+                continue;
+            };
+            if !verify_signature(&producer.public_key, signature, &message)? {
+                return Err(Error::InvalidSignature(producer.account_id.clone()));
+            }
+            approved_stake += stake;
+        }
+        if approved_stake * 3 <= total_stake * 2 {
+            return Err(Error::InsufficientApprovals {
+                approved_stake,
+                total_stake,
+            });
+        }
+
+        if let Some(next_bps) = &block.next_bps {
+            let actual = sha256(&block_producers_bytes(next_bps)?);
+            if actual != decode_hash(&header.inner_lite.next_bp_hash)? {
+                return Err(Error::NextBpHashMismatch {
+                    expected: header.inner_lite.next_bp_hash.clone(),
+                    actual: encode_hash(&actual),
+                });
+            }
+        }
+
+        Ok((header, block.next_bps.clone()))
+    }
+
+    /// Follow the chain via `next_light_client_block` until no newer header is available.
+    ///
+    /// Returns `true` if the head moved.
+    #[cfg(feature = "client")]
+    pub async fn sync(&mut self, client: &crate::NearRpcClient) -> Result<bool> {
+        let mut moved = false;
+        loop {
+            let block = client
+                .next_light_client_block(crate::types::RpcLightClientNextBlockRequest {
+                    last_block_hash: self.head_hash()?,
+                })
+                .await?;
+            match &block.inner_lite {
+                Some(inner) if inner.height > self.state.head.inner_lite.height => {
+                    self.update(&block)?;
+                    moved = true;
+                }
+                _ => return Ok(moved),
+            }
+        }
+    }
+}
+
+fn required<T: Clone>(value: &Option<T>, field: &'static str) -> Result<T> {
+    value.clone().ok_or(Error::MissingField(field))
+}
+
+/// Borsh `ApprovalInner::Endorsement(next_block_hash)` followed by the target height.
+fn approval_message(next_block_hash: &Hash, target_height: u64) -> Vec<u8> {
+    let mut w = Writer::default();
+    w.u8(0).hash(next_block_hash).u64(target_height);
+    w.finish().to_vec()
+}
+
+/// Borsh `Vec<ValidatorStake>`, the preimage of `next_bp_hash`.
+fn block_producers_bytes(producers: &[ValidatorStakeView]) -> Result<Vec<u8>> {
+    let mut w = Writer::default();
+    w.u32(producers.len() as u32);
+    for producer in producers {
+        if producer.validator_stake_struct_version != "V1" {
+            return Err(Error::InvalidField {
+                field: "validator_stake_struct_version",
+                value: producer.validator_stake_struct_version.clone(),
+            });
+        }
+        let (key_type, key) = decode_key(&producer.public_key.0)?;
+        w.u8(0)
+            .bytes(producer.account_id.0.as_bytes())
+            .u8(key_type)
+            .raw(&key)
+            .u128(parse_u128("stake", &producer.stake.0)?);
+    }
+    Ok(w.finish().to_vec())
+}
+
+/// Decode a `<curve>:<base58>` key or signature into its Borsh key type and bytes.
+pub(crate) fn decode_key(value: &str) -> Result<(u8, Vec<u8>)> {
+    let invalid = || Error::InvalidField {
+        field: "key",
+        value: value.to_string(),
+    };
+    let (key_type, data) = match value.split_once(':') {
+        Some(("ed25519", data)) => (0, data),
+        Some(("secp256k1", data)) => (1, data),
+        _ => return Err(invalid()),
+    };
+    bs58::decode(data)
+        .into_vec()
+        .map(|bytes| (key_type, bytes))
+        .map_err(|_| invalid())
+}
+
+pub(crate) fn verify_signature(
+    public_key: &PublicKey,
+    signature: &Signature,
+    message: &[u8],
+) -> Result<bool> {
+    let (key_type, key) = decode_key(&public_key.0)?;
+    let signature_str = &signature.0;
+    let (signature_type, signature) = decode_key(signature_str)?;
+    if key_type != 0 || signature_type != 0 {
+        return Err(Error::UnsupportedKeyType(public_key.0.clone()));
+    }
+    let key: [u8; 32] = key.try_into().map_err(|_| Error::InvalidField {
+        field: "public_key",
+        value: public_key.0.clone(),
+    })?;
+    let signature: [u8; 64] = signature.try_into().map_err(|_| Error::InvalidField {
+        field: "signature",
+        value: signature_str.clone(),
+    })?;
+    let Ok(key) = VerifyingKey::from_bytes(&key) else {
+        return Ok(false);
+    };
+    Ok(key
+        .verify(message, &ed25519_dalek::Signature::from_bytes(&signature))
+        .is_ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::light_client::block_hash;
+    use crate::light_client::borsh::flip;
+
+    /// A head about two epochs back, then the `next_light_client_block` responses for the
+    /// last blocks of its epoch and of the next one. Synthetic, not chain data: a handful of
+    /// nearcore-signed producers stand in for the mainnet set, which
+    /// `examples/capture_light_client_fixtures.rs` captures and should replace it with.
+    #[derive(Deserialize)]
+    struct Fixture {
+        head: LightClientBlockLiteView,
+        head_hash: CryptoHash,
+        epoch_block_producers: Vec<ValidatorStakeView>,
+        blocks: Vec<RpcLightClientNextBlockResponse>,
+        block_hashes: Vec<CryptoHash>,
+    }
+
+    fn fixture() -> (
+        LightClient,
+        Vec<RpcLightClientNextBlockResponse>,
+        Vec<CryptoHash>,
+    ) {
+        let fixture: Fixture = serde_json::from_str(include_str!(
+            "../../tests/fixtures/light_client_blocks.json"
+        ))
+        .unwrap();
+        assert_eq!(block_hash(&fixture.head).unwrap(), fixture.head_hash);
+        let client = LightClient::new(LightClientState {
+            head: fixture.head,
+            epoch_block_producers: fixture.epoch_block_producers,
+            next_epoch_block_producers: None,
+        });
+        (client, fixture.blocks, fixture.block_hashes)
+    }
+
+    fn to_json<T: Serialize>(value: &T) -> serde_json::Value {
+        serde_json::to_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_sync_across_epoch_boundary() {
+        let (mut client, blocks, hashes) = fixture();
+        let epoch = client.head().inner_lite.epoch_id.clone();
+        let next_epoch = client.head().inner_lite.next_epoch_id.clone();
+
+        // The last block of the head's epoch announces the block producers of the next one.
+        client.update(&blocks[0]).unwrap();
+        assert_eq!(client.head_hash().unwrap(), hashes[0]);
+        assert_eq!(client.head().inner_lite.epoch_id, epoch);
+        assert_eq!(
+            to_json(&client.state().next_epoch_block_producers),
+            to_json(&blocks[0].next_bps)
+        );
+
+        client.update(&blocks[1]).unwrap();
+        assert_eq!(client.head_hash().unwrap(), hashes[1]);
+        assert_eq!(client.head().inner_lite.epoch_id, next_epoch);
+        let state = client.state();
+        assert_eq!(
+            to_json(&state.epoch_block_producers),
+            to_json(&blocks[0].next_bps)
+        );
+        assert_eq!(
+            to_json(&state.next_epoch_block_producers),
+            to_json(&blocks[1].next_bps)
+        );
+
+        // State survives a serialization round trip.
+        let restored: LightClientState =
+            serde_json::from_str(&serde_json::to_string(state).unwrap()).unwrap();
+        assert_eq!(LightClient::new(restored).head_hash().unwrap(), hashes[1]);
+    }
+
+    #[test]
+    fn test_rejects_invalid_blocks() {
+        let (mut client, blocks, _) = fixture();
+
+        // The next epoch is approved by block producers the client does not know yet.
+        assert!(matches!(
+            client.validate(&blocks[1]),
+            Err(Error::UnknownBlockProducers(_))
+        ));
+
+        // Skipping ahead to the next epoch without `next_bps`.
+        let mut block = blocks[1].clone();
+        block.next_bps = None;
+        assert!(matches!(
+            client.validate(&block),
+            Err(Error::MissingField("next_bps"))
+        ));
+
+        let mut block = blocks[0].clone();
+        block.approvals_after_next.fill(None);
+        assert!(matches!(
+            client.validate(&block),
+            Err(Error::InsufficientApprovals { .. })
+        ));
+
+        // Signatures must be by their own block producer.
+        let mut block = blocks[0].clone();
+        let signed: Vec<usize> = (block.approvals_after_next.iter().enumerate())
+            .filter_map(|(i, approval)| approval.as_ref().map(|_| i))
+            .collect();
+        block.approvals_after_next.swap(signed[0], signed[1]);
+        assert!(matches!(
+            client.validate(&block),
+            Err(Error::InvalidSignature(_))
+        ));
+
+        // A flipped byte anywhere in the header changes the approved hash.
+        let tampered: [fn(&mut RpcLightClientNextBlockResponse); 4] = [
+            |block| flip(&mut block.inner_lite.as_mut().unwrap().outcome_root),
+            |block| flip(block.inner_rest_hash.as_mut().unwrap()),
+            |block| flip(block.prev_block_hash.as_mut().unwrap()),
+            |block| flip(block.next_block_inner_hash.as_mut().unwrap()),
+        ];
+        for tamper in tampered {
+            let mut block = blocks[0].clone();
+            tamper(&mut block);
+            assert!(matches!(
+                client.validate(&block),
+                Err(Error::InvalidSignature(_))
+            ));
+        }
+
+        client.update(&blocks[0]).unwrap();
+        assert!(matches!(
+            client.validate(&blocks[0]),
+            Err(Error::NotNewer { .. })
+        ));
+
+        // `next_bps` must match `next_bp_hash`.
+        let mut block = blocks[1].clone();
+        block.next_bps.as_mut().unwrap().pop();
+        assert!(matches!(
+            client.validate(&block),
+            Err(Error::NextBpHashMismatch { .. })
+        ));
+    }
+}
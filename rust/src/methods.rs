@@ -0,0 +1,591 @@
+//!Table of the JSON-RPC methods, generated by `build.rs` from `openrpc.json`.
+///A JSON-RPC method
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Method {
+    ///Method name sent in requests, e.g. `EXPERIMENTAL_changes`
+    pub name: &'static str,
+    pub summary: &'static str,
+    ///Name of the request type in [`types`](crate::types)
+    pub request: &'static str,
+    ///Name of the response type in [`types`](crate::types)
+    pub response: &'static str,
+    pub deprecated: bool,
+}
+///Every method of the NEAR JSON-RPC API, in `openrpc.json` order
+pub const METHODS: &[Method] = &[
+    Method {
+        name: "block",
+        summary: "Returns block details for given height or hash",
+        request: "RpcBlockRequest",
+        response: "RpcBlockResponse",
+        deprecated: false,
+    },
+    Method {
+        name: "chunk",
+        summary: "Returns details of a specific chunk",
+        request: "RpcChunkRequest",
+        response: "RpcChunkResponse",
+        deprecated: false,
+    },
+    Method {
+        name: "gas_price",
+        summary: "Returns gas price for a specific block_height or block_hash",
+        request: "RpcGasPriceRequest",
+        response: "RpcGasPriceResponse",
+        deprecated: false,
+    },
+    Method {
+        name: "query",
+        summary: "Query the blockchain state (view account, call function, etc.)",
+        request: "RpcQueryRequest",
+        response: "RpcQueryResponse",
+        deprecated: false,
+    },
+    Method {
+        name: "send_tx",
+        summary: "Sends a transaction and optionally waits for execution",
+        request: "RpcSendTransactionRequest",
+        response: "RpcTransactionResponse",
+        deprecated: false,
+    },
+    Method {
+        name: "tx",
+        summary: "Queries status of a transaction by hash",
+        request: "RpcTransactionStatusRequest",
+        response: "RpcTransactionResponse",
+        deprecated: false,
+    },
+    Method {
+        name: "status",
+        summary: "Returns the status of the RPC node",
+        request: "RpcStatusRequest",
+        response: "RpcStatusResponse",
+        deprecated: false,
+    },
+    Method {
+        name: "validators",
+        summary: "Queries active validators on the network",
+        request: "RpcValidatorRequest",
+        response: "RpcValidatorResponse",
+        deprecated: false,
+    },
+    Method {
+        name: "network_info",
+        summary: "Queries the current state of node network connections",
+        request: "RpcNetworkInfoRequest",
+        response: "RpcNetworkInfoResponse",
+        deprecated: false,
+    },
+    Method {
+        name: "health",
+        summary: "Returns health status of the node",
+        request: "RpcHealthRequest",
+        response: "RpcHealthResponse",
+        deprecated: false,
+    },
+    Method {
+        name: "light_client_proof",
+        summary: "Returns execution proof for light clients",
+        request: "RpcLightClientExecutionProofRequest",
+        response: "RpcLightClientExecutionProofResponse",
+        deprecated: false,
+    },
+    Method {
+        name: "next_light_client_block",
+        summary: "Returns the next light client block",
+        request: "RpcLightClientNextBlockRequest",
+        response: "RpcLightClientNextBlockResponse",
+        deprecated: false,
+    },
+    Method {
+        name: "light_client_block_proof",
+        summary: "Returns block proof for light clients",
+        request: "RpcLightClientBlockProofRequest",
+        response: "RpcLightClientBlockProofResponse",
+        deprecated: false,
+    },
+    Method {
+        name: "EXPERIMENTAL_changes_in_block",
+        summary: "Returns changes in block for given block height or hash",
+        request: "RpcStateChangesInBlockRequest",
+        response: "RpcStateChangesInBlockByTypeResponse",
+        deprecated: false,
+    },
+    Method {
+        name: "EXPERIMENTAL_changes",
+        summary: "Returns state changes for specific state change kinds",
+        request: "RpcStateChangesInBlockByTypeRequest",
+        response: "RpcStateChangesInBlockResponse",
+        deprecated: false,
+    },
+    Method {
+        name: "EXPERIMENTAL_protocol_config",
+        summary: "Returns protocol configuration for given block",
+        request: "RpcProtocolConfigRequest",
+        response: "RpcProtocolConfigResponse",
+        deprecated: false,
+    },
+    Method {
+        name: "EXPERIMENTAL_genesis_config",
+        summary: "Returns genesis configuration of the network",
+        request: "GenesisConfigRequest",
+        response: "GenesisConfig",
+        deprecated: false,
+    },
+    Method {
+        name: "EXPERIMENTAL_receipt",
+        summary: "Returns receipt by receipt_id",
+        request: "RpcReceiptRequest",
+        response: "RpcReceiptResponse",
+        deprecated: false,
+    },
+    Method {
+        name: "EXPERIMENTAL_maintenance_windows",
+        summary: "Returns maintenance windows for validators",
+        request: "RpcMaintenanceWindowsRequest",
+        response: "BlockHeightRanges",
+        deprecated: false,
+    },
+    Method {
+        name: "EXPERIMENTAL_split_storage_info",
+        summary: "Returns split storage information",
+        request: "RpcSplitStorageInfoRequest",
+        response: "RpcSplitStorageInfoResponse",
+        deprecated: false,
+    },
+    Method {
+        name: "EXPERIMENTAL_congestion_level",
+        summary: "Returns congestion level for a chunk",
+        request: "RpcCongestionLevelRequest",
+        response: "RpcCongestionLevelResponse",
+        deprecated: false,
+    },
+    Method {
+        name: "EXPERIMENTAL_validators_ordered",
+        summary: "Returns validators ordered by stake for given epoch",
+        request: "RpcValidatorsOrderedRequest",
+        response: "ValidatorStakeViews",
+        deprecated: false,
+    },
+    Method {
+        name: "EXPERIMENTAL_client_config",
+        summary: "Returns client configuration",
+        request: "RpcClientConfigRequest",
+        response: "RpcClientConfigResponse",
+        deprecated: false,
+    },
+    Method {
+        name: "EXPERIMENTAL_tx_status",
+        summary: "Queries status of a transaction by hash (alias for tx)",
+        request: "RpcTransactionStatusRequest",
+        response: "RpcTransactionResponse",
+        deprecated: false,
+    },
+    Method {
+        name: "EXPERIMENTAL_light_client_proof",
+        summary: "Returns execution proof for light clients",
+        request: "RpcLightClientExecutionProofRequest",
+        response: "RpcLightClientExecutionProofResponse",
+        deprecated: false,
+    },
+    Method {
+        name: "EXPERIMENTAL_light_client_block_proof",
+        summary: "Returns block proof for light clients",
+        request: "RpcLightClientBlockProofRequest",
+        response: "RpcLightClientBlockProofResponse",
+        deprecated: false,
+    },
+    Method {
+        name: "block_effects",
+        summary: "Returns changes in block (alias for EXPERIMENTAL_changes_in_block)",
+        request: "RpcStateChangesInBlockRequest",
+        response: "RpcStateChangesInBlockByTypeResponse",
+        deprecated: false,
+    },
+    Method {
+        name: "changes",
+        summary: "Returns state changes (alias for EXPERIMENTAL_changes)",
+        request: "RpcStateChangesInBlockByTypeRequest",
+        response: "RpcStateChangesInBlockResponse",
+        deprecated: false,
+    },
+    Method {
+        name: "genesis_config",
+        summary: "Returns genesis configuration (alias for EXPERIMENTAL_genesis_config)",
+        request: "GenesisConfigRequest",
+        response: "GenesisConfig",
+        deprecated: false,
+    },
+    Method {
+        name: "client_config",
+        summary: "Returns client configuration (alias for EXPERIMENTAL_client_config)",
+        request: "RpcClientConfigRequest",
+        response: "RpcClientConfigResponse",
+        deprecated: false,
+    },
+    Method {
+        name: "maintenance_windows",
+        summary: "Returns maintenance windows (alias for EXPERIMENTAL_maintenance_windows)",
+        request: "RpcMaintenanceWindowsRequest",
+        response: "BlockHeightRanges",
+        deprecated: false,
+    },
+    Method {
+        name: "broadcast_tx_async",
+        summary: "Sends a transaction and immediately returns hash (deprecated: use send_tx)",
+        request: "RpcSendTransactionRequest",
+        response: "CryptoHash",
+        deprecated: true,
+    },
+    Method {
+        name: "broadcast_tx_commit",
+        summary: "Sends a transaction and waits for completion (deprecated: use send_tx)",
+        request: "RpcSendTransactionRequest",
+        response: "RpcTransactionResponse",
+        deprecated: true,
+    },
+];
+///Look up a method by name
+pub fn find(name: &str) -> Option<&'static Method> {
+    METHODS.iter().find(|method| method.name == name)
+}
+///A JSON-RPC method with its request and response types.
+///
+///Every method has a marker type implementing this trait, named after the method, e.g.
+///[`ExperimentalChanges`] for `EXPERIMENTAL_changes`.
+pub trait RpcMethod {
+    ///Method name sent in requests
+    const NAME: &'static str;
+    type Request: serde::Serialize;
+    type Response: serde::de::DeserializeOwned;
+}
+///Returns block details for given height or hash
+///
+///JSON-RPC method `block`.
+#[derive(Clone, Copy, Debug)]
+pub struct Block;
+impl RpcMethod for Block {
+    const NAME: &'static str = "block";
+    type Request = crate::types::RpcBlockRequest;
+    type Response = crate::types::RpcBlockResponse;
+}
+///Returns details of a specific chunk
+///
+///JSON-RPC method `chunk`.
+#[derive(Clone, Copy, Debug)]
+pub struct Chunk;
+impl RpcMethod for Chunk {
+    const NAME: &'static str = "chunk";
+    type Request = crate::types::RpcChunkRequest;
+    type Response = crate::types::RpcChunkResponse;
+}
+///Returns gas price for a specific block_height or block_hash
+///
+///JSON-RPC method `gas_price`.
+#[derive(Clone, Copy, Debug)]
+pub struct GasPrice;
+impl RpcMethod for GasPrice {
+    const NAME: &'static str = "gas_price";
+    type Request = crate::types::RpcGasPriceRequest;
+    type Response = crate::types::RpcGasPriceResponse;
+}
+///Query the blockchain state (view account, call function, etc.)
+///
+///JSON-RPC method `query`.
+#[derive(Clone, Copy, Debug)]
+pub struct Query;
+impl RpcMethod for Query {
+    const NAME: &'static str = "query";
+    type Request = crate::types::RpcQueryRequest;
+    type Response = crate::types::RpcQueryResponse;
+}
+///Sends a transaction and optionally waits for execution
+///
+///JSON-RPC method `send_tx`.
+#[derive(Clone, Copy, Debug)]
+pub struct SendTx;
+impl RpcMethod for SendTx {
+    const NAME: &'static str = "send_tx";
+    type Request = crate::types::RpcSendTransactionRequest;
+    type Response = crate::types::RpcTransactionResponse;
+}
+///Queries status of a transaction by hash
+///
+///JSON-RPC method `tx`.
+#[derive(Clone, Copy, Debug)]
+pub struct Tx;
+impl RpcMethod for Tx {
+    const NAME: &'static str = "tx";
+    type Request = crate::types::RpcTransactionStatusRequest;
+    type Response = crate::types::RpcTransactionResponse;
+}
+///Returns the status of the RPC node
+///
+///JSON-RPC method `status`.
+#[derive(Clone, Copy, Debug)]
+pub struct Status;
+impl RpcMethod for Status {
+    const NAME: &'static str = "status";
+    type Request = crate::types::RpcStatusRequest;
+    type Response = crate::types::RpcStatusResponse;
+}
+///Queries active validators on the network
+///
+///JSON-RPC method `validators`.
+#[derive(Clone, Copy, Debug)]
+pub struct Validators;
+impl RpcMethod for Validators {
+    const NAME: &'static str = "validators";
+    type Request = crate::types::RpcValidatorRequest;
+    type Response = crate::types::RpcValidatorResponse;
+}
+///Queries the current state of node network connections
+///
+///JSON-RPC method `network_info`.
+#[derive(Clone, Copy, Debug)]
+pub struct NetworkInfo;
+impl RpcMethod for NetworkInfo {
+    const NAME: &'static str = "network_info";
+    type Request = crate::types::RpcNetworkInfoRequest;
+    type Response = crate::types::RpcNetworkInfoResponse;
+}
+///Returns health status of the node
+///
+///JSON-RPC method `health`.
+#[derive(Clone, Copy, Debug)]
+pub struct Health;
+impl RpcMethod for Health {
+    const NAME: &'static str = "health";
+    type Request = crate::types::RpcHealthRequest;
+    type Response = crate::types::RpcHealthResponse;
+}
+///Returns execution proof for light clients
+///
+///JSON-RPC method `light_client_proof`.
+#[derive(Clone, Copy, Debug)]
+pub struct LightClientProof;
+impl RpcMethod for LightClientProof {
+    const NAME: &'static str = "light_client_proof";
+    type Request = crate::types::RpcLightClientExecutionProofRequest;
+    type Response = crate::types::RpcLightClientExecutionProofResponse;
+}
+///Returns the next light client block
+///
+///JSON-RPC method `next_light_client_block`.
+#[derive(Clone, Copy, Debug)]
+pub struct NextLightClientBlock;
+impl RpcMethod for NextLightClientBlock {
+    const NAME: &'static str = "next_light_client_block";
+    type Request = crate::types::RpcLightClientNextBlockRequest;
+    type Response = crate::types::RpcLightClientNextBlockResponse;
+}
+///Returns block proof for light clients
+///
+///JSON-RPC method `light_client_block_proof`.
+#[derive(Clone, Copy, Debug)]
+pub struct LightClientBlockProof;
+impl RpcMethod for LightClientBlockProof {
+    const NAME: &'static str = "light_client_block_proof";
+    type Request = crate::types::RpcLightClientBlockProofRequest;
+    type Response = crate::types::RpcLightClientBlockProofResponse;
+}
+///Returns changes in block for given block height or hash
+///
+///JSON-RPC method `EXPERIMENTAL_changes_in_block`.
+#[derive(Clone, Copy, Debug)]
+pub struct ExperimentalChangesInBlock;
+impl RpcMethod for ExperimentalChangesInBlock {
+    const NAME: &'static str = "EXPERIMENTAL_changes_in_block";
+    type Request = crate::types::RpcStateChangesInBlockRequest;
+    type Response = crate::types::RpcStateChangesInBlockByTypeResponse;
+}
+///Returns state changes for specific state change kinds
+///
+///JSON-RPC method `EXPERIMENTAL_changes`.
+#[derive(Clone, Copy, Debug)]
+pub struct ExperimentalChanges;
+impl RpcMethod for ExperimentalChanges {
+    const NAME: &'static str = "EXPERIMENTAL_changes";
+    type Request = crate::types::RpcStateChangesInBlockByTypeRequest;
+    type Response = crate::types::RpcStateChangesInBlockResponse;
+}
+///Returns protocol configuration for given block
+///
+///JSON-RPC method `EXPERIMENTAL_protocol_config`.
+#[derive(Clone, Copy, Debug)]
+pub struct ExperimentalProtocolConfig;
+impl RpcMethod for ExperimentalProtocolConfig {
+    const NAME: &'static str = "EXPERIMENTAL_protocol_config";
+    type Request = crate::types::RpcProtocolConfigRequest;
+    type Response = crate::types::RpcProtocolConfigResponse;
+}
+///Returns genesis configuration of the network
+///
+///JSON-RPC method `EXPERIMENTAL_genesis_config`.
+#[derive(Clone, Copy, Debug)]
+pub struct ExperimentalGenesisConfig;
+impl RpcMethod for ExperimentalGenesisConfig {
+    const NAME: &'static str = "EXPERIMENTAL_genesis_config";
+    type Request = crate::types::GenesisConfigRequest;
+    type Response = crate::types::GenesisConfig;
+}
+///Returns receipt by receipt_id
+///
+///JSON-RPC method `EXPERIMENTAL_receipt`.
+#[derive(Clone, Copy, Debug)]
+pub struct ExperimentalReceipt;
+impl RpcMethod for ExperimentalReceipt {
+    const NAME: &'static str = "EXPERIMENTAL_receipt";
+    type Request = crate::types::RpcReceiptRequest;
+    type Response = crate::types::RpcReceiptResponse;
+}
+///Returns maintenance windows for validators
+///
+///JSON-RPC method `EXPERIMENTAL_maintenance_windows`.
+#[derive(Clone, Copy, Debug)]
+pub struct ExperimentalMaintenanceWindows;
+impl RpcMethod for ExperimentalMaintenanceWindows {
+    const NAME: &'static str = "EXPERIMENTAL_maintenance_windows";
+    type Request = crate::types::RpcMaintenanceWindowsRequest;
+    type Response = crate::types::BlockHeightRanges;
+}
+///Returns split storage information
+///
+///JSON-RPC method `EXPERIMENTAL_split_storage_info`.
+#[derive(Clone, Copy, Debug)]
+pub struct ExperimentalSplitStorageInfo;
+impl RpcMethod for ExperimentalSplitStorageInfo {
+    const NAME: &'static str = "EXPERIMENTAL_split_storage_info";
+    type Request = crate::types::RpcSplitStorageInfoRequest;
+    type Response = crate::types::RpcSplitStorageInfoResponse;
+}
+///Returns congestion level for a chunk
+///
+///JSON-RPC method `EXPERIMENTAL_congestion_level`.
+#[derive(Clone, Copy, Debug)]
+pub struct ExperimentalCongestionLevel;
+impl RpcMethod for ExperimentalCongestionLevel {
+    const NAME: &'static str = "EXPERIMENTAL_congestion_level";
+    type Request = crate::types::RpcCongestionLevelRequest;
+    type Response = crate::types::RpcCongestionLevelResponse;
+}
+///Returns validators ordered by stake for given epoch
+///
+///JSON-RPC method `EXPERIMENTAL_validators_ordered`.
+#[derive(Clone, Copy, Debug)]
+pub struct ExperimentalValidatorsOrdered;
+impl RpcMethod for ExperimentalValidatorsOrdered {
+    const NAME: &'static str = "EXPERIMENTAL_validators_ordered";
+    type Request = crate::types::RpcValidatorsOrderedRequest;
+    type Response = crate::types::ValidatorStakeViews;
+}
+///Returns client configuration
+///
+///JSON-RPC method `EXPERIMENTAL_client_config`.
+#[derive(Clone, Copy, Debug)]
+pub struct ExperimentalClientConfig;
+impl RpcMethod for ExperimentalClientConfig {
+    const NAME: &'static str = "EXPERIMENTAL_client_config";
+    type Request = crate::types::RpcClientConfigRequest;
+    type Response = crate::types::RpcClientConfigResponse;
+}
+///Queries status of a transaction by hash (alias for tx)
+///
+///JSON-RPC method `EXPERIMENTAL_tx_status`.
+#[derive(Clone, Copy, Debug)]
+pub struct ExperimentalTxStatus;
+impl RpcMethod for ExperimentalTxStatus {
+    const NAME: &'static str = "EXPERIMENTAL_tx_status";
+    type Request = crate::types::RpcTransactionStatusRequest;
+    type Response = crate::types::RpcTransactionResponse;
+}
+///Returns execution proof for light clients
+///
+///JSON-RPC method `EXPERIMENTAL_light_client_proof`.
+#[derive(Clone, Copy, Debug)]
+pub struct ExperimentalLightClientProof;
+impl RpcMethod for ExperimentalLightClientProof {
+    const NAME: &'static str = "EXPERIMENTAL_light_client_proof";
+    type Request = crate::types::RpcLightClientExecutionProofRequest;
+    type Response = crate::types::RpcLightClientExecutionProofResponse;
+}
+///Returns block proof for light clients
+///
+///JSON-RPC method `EXPERIMENTAL_light_client_block_proof`.
+#[derive(Clone, Copy, Debug)]
+pub struct ExperimentalLightClientBlockProof;
+impl RpcMethod for ExperimentalLightClientBlockProof {
+    const NAME: &'static str = "EXPERIMENTAL_light_client_block_proof";
+    type Request = crate::types::RpcLightClientBlockProofRequest;
+    type Response = crate::types::RpcLightClientBlockProofResponse;
+}
+///Returns changes in block (alias for EXPERIMENTAL_changes_in_block)
+///
+///JSON-RPC method `block_effects`.
+#[derive(Clone, Copy, Debug)]
+pub struct BlockEffects;
+impl RpcMethod for BlockEffects {
+    const NAME: &'static str = "block_effects";
+    type Request = crate::types::RpcStateChangesInBlockRequest;
+    type Response = crate::types::RpcStateChangesInBlockByTypeResponse;
+}
+///Returns state changes (alias for EXPERIMENTAL_changes)
+///
+///JSON-RPC method `changes`.
+#[derive(Clone, Copy, Debug)]
+pub struct Changes;
+impl RpcMethod for Changes {
+    const NAME: &'static str = "changes";
+    type Request = crate::types::RpcStateChangesInBlockByTypeRequest;
+    type Response = crate::types::RpcStateChangesInBlockResponse;
+}
+///Returns genesis configuration (alias for EXPERIMENTAL_genesis_config)
+///
+///JSON-RPC method `genesis_config`.
+#[derive(Clone, Copy, Debug)]
+pub struct GenesisConfig;
+impl RpcMethod for GenesisConfig {
+    const NAME: &'static str = "genesis_config";
+    type Request = crate::types::GenesisConfigRequest;
+    type Response = crate::types::GenesisConfig;
+}
+///Returns client configuration (alias for EXPERIMENTAL_client_config)
+///
+///JSON-RPC method `client_config`.
+#[derive(Clone, Copy, Debug)]
+pub struct ClientConfig;
+impl RpcMethod for ClientConfig {
+    const NAME: &'static str = "client_config";
+    type Request = crate::types::RpcClientConfigRequest;
+    type Response = crate::types::RpcClientConfigResponse;
+}
+///Returns maintenance windows (alias for EXPERIMENTAL_maintenance_windows)
+///
+///JSON-RPC method `maintenance_windows`.
+#[derive(Clone, Copy, Debug)]
+pub struct MaintenanceWindows;
+impl RpcMethod for MaintenanceWindows {
+    const NAME: &'static str = "maintenance_windows";
+    type Request = crate::types::RpcMaintenanceWindowsRequest;
+    type Response = crate::types::BlockHeightRanges;
+}
+///Sends a transaction and immediately returns hash (deprecated: use send_tx)
+///
+///JSON-RPC method `broadcast_tx_async`. Deprecated.
+#[derive(Clone, Copy, Debug)]
+pub struct BroadcastTxAsync;
+impl RpcMethod for BroadcastTxAsync {
+    const NAME: &'static str = "broadcast_tx_async";
+    type Request = crate::types::RpcSendTransactionRequest;
+    type Response = crate::types::CryptoHash;
+}
+///Sends a transaction and waits for completion (deprecated: use send_tx)
+///
+///JSON-RPC method `broadcast_tx_commit`. Deprecated.
+#[derive(Clone, Copy, Debug)]
+pub struct BroadcastTxCommit;
+impl RpcMethod for BroadcastTxCommit {
+    const NAME: &'static str = "broadcast_tx_commit";
+    type Request = crate::types::RpcSendTransactionRequest;
+    type Response = crate::types::RpcTransactionResponse;
+}
@@ -0,0 +1,324 @@
+//! Metrics hooks for RPC client usage.
+//!
+//! A [`MetricsHook`] set with [`NearRpcClient::with_metrics`](crate::NearRpcClient::with_metrics)
+//! is told about every request sent to the endpoint. Responses served from the cache or shared
+//! with a coalesced request are not counted.
+//!
+//! Ready-made hooks are available behind features:
+//! - `metrics`: [`MetricsFacade`] reports to the [`metrics`](::metrics) crate facade, e.g. for
+//!   a Prometheus exporter
+//! - `opentelemetry`: [`OpenTelemetryMetrics`] reports to an OpenTelemetry meter
+//!
+//! Both record the following instruments, labeled by `method` and `endpoint`:
+//! - `near_rpc_requests_total`: requests sent
+//! - `near_rpc_errors_total`: failed requests, also labeled by error `name` and `cause`
+//! - `near_rpc_request_duration_seconds`: latency histogram
+//! - `near_rpc_requests_in_flight`: requests awaiting a response
+
+use crate::client::{Error, HttpErrorKind};
+use std::time::Duration;
+use web_time::Instant;
+
+/// A request sent to the endpoint
+#[derive(Clone, Copy, Debug)]
+pub struct Request<'a> {
+    pub method: &'a str,
+    pub endpoint: &'a str,
+}
+
+/// How a request ended
+#[derive(Clone, Copy, Debug)]
+pub enum Outcome<'a> {
+    Success,
+    Error(&'a Error),
+    /// The caller dropped the request before it completed
+    Cancelled,
+}
+
+/// Receiver of client usage metrics.
+pub trait MetricsHook: Send + Sync {
+    /// A request is about to be sent
+    fn request_started(&self, request: Request<'_>);
+
+    /// A request finished after `latency`
+    fn request_finished(&self, request: Request<'_>, latency: Duration, outcome: Outcome<'_>);
+}
+
+impl<T: MetricsHook + ?Sized> MetricsHook for std::sync::Arc<T> {
+    fn request_started(&self, request: Request<'_>) {
+        (**self).request_started(request)
+    }
+
+    fn request_finished(&self, request: Request<'_>, latency: Duration, outcome: Outcome<'_>) {
+        (**self).request_finished(request, latency, outcome)
+    }
+}
+
+/// Error `name` and `cause` labels for `error`.
+///
+/// JSON-RPC errors use the `name` and `cause.name` reported by nearcore. Other errors are
+/// named `TRANSPORT_ERROR` with the failing layer as cause.
+pub fn error_labels(error: &Error) -> (&str, &str) {
+    match error {
+        Error::Rpc(error) => (
+            error.name.as_deref().unwrap_or("RPC_ERROR"),
+            error
+                .cause
+                .as_ref()
+                .map_or("UNKNOWN", |cause| cause.name.as_str()),
+        ),
+        Error::Http(_) => ("TRANSPORT_ERROR", "HTTP_ERROR"),
+        Error::Json(_) => ("TRANSPORT_ERROR", "JSON_ERROR"),
+        Error::Decode(_) => ("TRANSPORT_ERROR", "DECODE_ERROR"),
+        Error::Validation(_) => ("TRANSPORT_ERROR", "VALIDATION_ERROR"),
+        Error::HttpStatus { .. } => (
+            "TRANSPORT_ERROR",
+            match error.http_error_kind() {
+                Some(HttpErrorKind::Unauthorized) => "HTTP_UNAUTHORIZED",
+                Some(HttpErrorKind::QuotaExceeded) => "HTTP_QUOTA_EXCEEDED",
+                Some(HttpErrorKind::RateLimited) => "HTTP_RATE_LIMITED",
+                Some(HttpErrorKind::Client) => "HTTP_CLIENT_ERROR",
+                Some(HttpErrorKind::Server) => "HTTP_SERVER_ERROR",
+                None => "HTTP_STATUS",
+            },
+        ),
+        Error::Service(_) => ("TRANSPORT_ERROR", "SERVICE_ERROR"),
+    }
+}
+
+/// Reports a request as cancelled unless it is finished explicitly.
+pub(crate) struct InFlight<'a> {
+    hook: &'a dyn MetricsHook,
+    request: Request<'a>,
+    start: Instant,
+    finished: bool,
+}
+
+impl<'a> InFlight<'a> {
+    pub fn start(hook: &'a dyn MetricsHook, request: Request<'a>) -> Self {
+        hook.request_started(request);
+        Self {
+            hook,
+            request,
+            start: Instant::now(),
+            finished: false,
+        }
+    }
+
+    pub fn finish<T>(mut self, result: &Result<T, Error>) {
+        self.finished = true;
+        let outcome = match result {
+            Ok(_) => Outcome::Success,
+            Err(error) => Outcome::Error(error),
+        };
+        self.hook
+            .request_finished(self.request, self.start.elapsed(), outcome);
+    }
+}
+
+impl Drop for InFlight<'_> {
+    fn drop(&mut self) {
+        if !self.finished {
+            self.hook
+                .request_finished(self.request, self.start.elapsed(), Outcome::Cancelled);
+        }
+    }
+}
+
+/// Reports to the [`metrics`](::metrics) crate facade.
+#[cfg(feature = "metrics")]
+#[derive(Clone, Debug, Default)]
+pub struct MetricsFacade;
+
+#[cfg(feature = "metrics")]
+impl MetricsHook for MetricsFacade {
+    fn request_started(&self, request: Request<'_>) {
+        let labels = [
+            ("method", request.method.to_string()),
+            ("endpoint", request.endpoint.to_string()),
+        ];
+        ::metrics::counter!("near_rpc_requests_total", &labels).increment(1);
+        ::metrics::gauge!("near_rpc_requests_in_flight", &labels).increment(1.0);
+    }
+
+    fn request_finished(&self, request: Request<'_>, latency: Duration, outcome: Outcome<'_>) {
+        let labels = [
+            ("method", request.method.to_string()),
+            ("endpoint", request.endpoint.to_string()),
+        ];
+        ::metrics::gauge!("near_rpc_requests_in_flight", &labels).decrement(1.0);
+        ::metrics::histogram!("near_rpc_request_duration_seconds", &labels)
+            .record(latency.as_secs_f64());
+        if let Outcome::Error(error) = outcome {
+            let (name, cause) = error_labels(error);
+            ::metrics::counter!(
+                "near_rpc_errors_total",
+                "method" => request.method.to_string(),
+                "endpoint" => request.endpoint.to_string(),
+                "name" => name.to_string(),
+                "cause" => cause.to_string(),
+            )
+            .increment(1);
+        }
+    }
+}
+
+/// Reports to an OpenTelemetry meter.
+#[cfg(feature = "opentelemetry")]
+pub struct OpenTelemetryMetrics {
+    requests: opentelemetry::metrics::Counter<u64>,
+    errors: opentelemetry::metrics::Counter<u64>,
+    duration: opentelemetry::metrics::Histogram<f64>,
+    in_flight: opentelemetry::metrics::UpDownCounter<i64>,
+}
+
+#[cfg(feature = "opentelemetry")]
+impl OpenTelemetryMetrics {
+    /// Create the instruments on `meter`
+    pub fn new(meter: &opentelemetry::metrics::Meter) -> Self {
+        Self {
+            requests: meter
+                .u64_counter("near_rpc_requests_total")
+                .with_description("JSON-RPC requests sent")
+                .build(),
+            errors: meter
+                .u64_counter("near_rpc_errors_total")
+                .with_description("JSON-RPC requests that failed")
+                .build(),
+            duration: meter
+                .f64_histogram("near_rpc_request_duration_seconds")
+                .with_description("JSON-RPC request latency")
+                .with_unit("s")
+                .build(),
+            in_flight: meter
+                .i64_up_down_counter("near_rpc_requests_in_flight")
+                .with_description("JSON-RPC requests awaiting a response")
+                .build(),
+        }
+    }
+}
+
+#[cfg(feature = "opentelemetry")]
+fn otel_labels(request: Request<'_>) -> [opentelemetry::KeyValue; 2] {
+    [
+        opentelemetry::KeyValue::new("method", request.method.to_string()),
+        opentelemetry::KeyValue::new("endpoint", request.endpoint.to_string()),
+    ]
+}
+
+#[cfg(feature = "opentelemetry")]
+impl MetricsHook for OpenTelemetryMetrics {
+    fn request_started(&self, request: Request<'_>) {
+        let labels = otel_labels(request);
+        self.requests.add(1, &labels);
+        self.in_flight.add(1, &labels);
+    }
+
+    fn request_finished(&self, request: Request<'_>, latency: Duration, outcome: Outcome<'_>) {
+        let labels = otel_labels(request);
+        self.in_flight.add(-1, &labels);
+        self.duration.record(latency.as_secs_f64(), &labels);
+        if let Outcome::Error(error) = outcome {
+            let (name, cause) = error_labels(error);
+            let [method, endpoint] = labels;
+            self.errors.add(
+                1,
+                &[
+                    method,
+                    endpoint,
+                    opentelemetry::KeyValue::new("name", name.to_string()),
+                    opentelemetry::KeyValue::new("cause", cause.to_string()),
+                ],
+            );
+        }
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+    use crate::client::RpcError;
+    use crate::transport::{BoxError, Transport};
+    use crate::NearRpcClient;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Default)]
+    struct Recorder {
+        events: Mutex<Vec<String>>,
+    }
+
+    impl MetricsHook for Recorder {
+        fn request_started(&self, request: Request<'_>) {
+            self.events
+                .lock()
+                .unwrap()
+                .push(format!("start {} {}", request.method, request.endpoint));
+        }
+
+        fn request_finished(&self, request: Request<'_>, _: Duration, outcome: Outcome<'_>) {
+            let outcome = match outcome {
+                Outcome::Success => "ok".to_string(),
+                Outcome::Error(error) => format!("{:?}", error_labels(error)),
+                Outcome::Cancelled => "cancelled".to_string(),
+            };
+            self.events
+                .lock()
+                .unwrap()
+                .push(format!("finish {} {outcome}", request.method));
+        }
+    }
+
+    #[test]
+    fn test_error_labels() {
+        let error: RpcError = serde_json::from_value(serde_json::json!({
+            "name": "HANDLER_ERROR",
+            "cause": { "name": "UNKNOWN_BLOCK", "info": {} },
+            "code": -32000,
+            "message": "Server error",
+            "data": "DB Not Found Error: BLOCK HEIGHT: 1",
+        }))
+        .unwrap();
+        assert_eq!(
+            error_labels(&Error::Rpc(error)),
+            ("HANDLER_ERROR", "UNKNOWN_BLOCK")
+        );
+
+        let error = Error::HttpStatus {
+            status: 429,
+            body_snippet: "Monthly quota exceeded".to_string(),
+            retry_after: None,
+        };
+        assert_eq!(
+            error_labels(&error),
+            ("TRANSPORT_ERROR", "HTTP_QUOTA_EXCEEDED")
+        );
+    }
+
+    /// Transport whose requests never connect
+    struct Unreachable;
+
+    impl Transport for Unreachable {
+        fn send(
+            &self,
+            _request: http::Request<Vec<u8>>,
+        ) -> crate::transport::BoxFuture<'_, std::result::Result<http::Response<Vec<u8>>, BoxError>>
+        {
+            Box::pin(async { Err("connection refused".into()) })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_hook_reports_requests() {
+        let recorder = Arc::new(Recorder::default());
+        let client = NearRpcClient::from_transport("http://127.0.0.1:1", Unreachable)
+            .with_metrics(recorder.clone());
+        assert!(client.health().await.is_err());
+        assert_eq!(
+            *recorder.events.lock().unwrap(),
+            [
+                "start health http://127.0.0.1:1",
+                "finish health (\"TRANSPORT_ERROR\", \"HTTP_ERROR\")",
+            ]
+        );
+    }
+}
@@ -0,0 +1,404 @@
+//! Caching, failing-over JSON-RPC proxy for upstream NEAR RPC nodes.
+//!
+//! [`Proxy`] is a [`NearRpcHandler`] that forwards every request for a known method as raw
+//! JSON to the first healthy upstream client, so responses pass through unchanged even when
+//! they contain fields unknown to the generated types. Methods missing from
+//! [`methods`](crate::methods) are answered with `METHOD_NOT_FOUND` without reaching an
+//! upstream. Caching, coalescing and upstream metrics come
+//! from the upstream [`NearRpcClient`]s; give them a shared cache so that failing over
+//! keeps cached responses.
+//!
+//! An upstream is considered failed when the request does not get a JSON-RPC response, or
+//! the node answers with an `INTERNAL_ERROR`. Failed upstreams are tried last until their
+//! cooldown has passed. Other JSON-RPC errors, like `UNKNOWN_BLOCK`, are returned to the
+//! caller as-is.
+//!
+//! With the `tracing` feature, every failover is logged as a warning with the failed
+//! `endpoint`, the `method` and the error.
+//!
+//! Besides the `near_rpc_*` metrics of the upstream clients, the proxy reports
+//! `near_rpc_proxy_requests_total` by `method` (`unknown` for rejected methods), `near_rpc_proxy_upstream_failures_total`
+//! by the failed `endpoint` and `near_rpc_proxy_rate_limited_total` through the
+//! [`metrics`](::metrics) facade. The `near-rpc-proxy` binary exports them for Prometheus.
+//!
+//! ```no_run
+//! use near_rpc_client::cache::MemoryCache;
+//! use near_rpc_client::proxy::Proxy;
+//! use near_rpc_client::NearRpcClient;
+//! use std::sync::Arc;
+//!
+//! # async fn example() -> std::io::Result<()> {
+//! let cache = Arc::new(MemoryCache::new(100_000));
+//! let upstreams = ["https://rpc.mainnet.near.org", "https://free.rpc.fastnear.com"]
+//!     .map(|url| NearRpcClient::new(url).with_cache(cache.clone()).with_coalescing());
+//! let proxy = Proxy::new(upstreams).with_client_rate_limit(20.0, 40);
+//! let listener = tokio::net::TcpListener::bind("127.0.0.1:3030").await?;
+//! proxy.serve(listener).await;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::client::{Error, RpcError};
+use crate::server::{accept, NearRpcHandler, RpcHttpService};
+use crate::NearRpcClient;
+use http_body_util::Full;
+use hyper::body::Bytes;
+use hyper::header::{HeaderValue, RETRY_AFTER};
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Response, StatusCode};
+use hyper_util::rt::TokioIo;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::net::TcpListener;
+use tower::Service;
+
+/// Time a failed upstream is tried after the healthy ones by default
+const DEFAULT_COOLDOWN: Duration = Duration::from_secs(30);
+/// Number of tracked client addresses above which idle ones are forgotten
+const MAX_TRACKED_CLIENTS: usize = 10_000;
+
+/// Forwards requests to upstream nodes, failing over between them.
+pub struct Proxy {
+    upstreams: Vec<Upstream>,
+    cooldown: Duration,
+    client_limit: Option<Arc<ClientLimiter>>,
+    max_body_size: Option<usize>,
+}
+
+struct Upstream {
+    client: NearRpcClient,
+    failed_at: Mutex<Option<Instant>>,
+}
+
+impl Proxy {
+    /// Forward to `upstreams`, preferring them in the given order
+    pub fn new(upstreams: impl IntoIterator<Item = NearRpcClient>) -> Self {
+        Self {
+            upstreams: upstreams
+                .into_iter()
+                .map(|client| Upstream {
+                    client,
+                    failed_at: Mutex::new(None),
+                })
+                .collect(),
+            cooldown: DEFAULT_COOLDOWN,
+            client_limit: None,
+            max_body_size: None,
+        }
+    }
+
+    /// Try a failed upstream after the healthy ones for `cooldown`. Defaults to 30 seconds.
+    pub fn with_cooldown(mut self, cooldown: Duration) -> Self {
+        self.cooldown = cooldown;
+        self
+    }
+
+    /// Limit each client IP address to `requests_per_second` on average, with bursts of
+    /// up to `burst` requests, when served with [`serve`](Self::serve).
+    ///
+    /// Requests over the limit get `429 Too Many Requests` with a `Retry-After` header.
+    ///
+    /// # Panics
+    ///
+    /// If `requests_per_second` is not a positive, finite number.
+    pub fn with_client_rate_limit(mut self, requests_per_second: f64, burst: u32) -> Self {
+        assert!(
+            requests_per_second.is_finite() && requests_per_second > 0.0,
+            "requests per second must be positive and finite, got {requests_per_second}"
+        );
+        self.client_limit = Some(Arc::new(ClientLimiter::new(requests_per_second, burst)));
+        self
+    }
+
+    /// Reject request bodies larger than `bytes` when served with [`serve`](Self::serve).
+    /// Defaults to the limit of [`RpcHttpService`].
+    pub fn with_max_body_size(mut self, bytes: usize) -> Self {
+        self.max_body_size = Some(bytes);
+        self
+    }
+
+    /// Upstreams in the order to try them: healthy ones first, then those cooling down
+    fn candidates(&self) -> Vec<&Upstream> {
+        let (healthy, failed): (Vec<_>, Vec<_>) = self
+            .upstreams
+            .iter()
+            .partition(|upstream| !upstream.is_cooling_down(self.cooldown));
+        healthy.into_iter().chain(failed).collect()
+    }
+
+    async fn forward(&self, method: &str, params: Value) -> Result<Value, RpcError> {
+        // Only known names become labels, so clients cannot create unbounded series.
+        let Some(known) = crate::methods::find(method) else {
+            ::metrics::counter!("near_rpc_proxy_requests_total", "method" => "unknown")
+                .increment(1);
+            return Err(RpcError::method_not_found(method));
+        };
+        let method = known.name;
+        ::metrics::counter!("near_rpc_proxy_requests_total", "method" => method).increment(1);
+        let mut last_error = None;
+        for upstream in self.candidates() {
+            match upstream.client.call_raw(method, params.clone()).await {
+                Ok(result) => {
+                    *upstream.failed_at.lock().unwrap() = None;
+                    return Ok(result);
+                }
+                Err(Error::Rpc(error)) if !is_upstream_failure(&error) => return Err(error),
+                Err(error) => {
+                    #[cfg(feature = "tracing")]
+                    crate::instrument::failover(upstream.client.url(), method, &error);
+                    ::metrics::counter!(
+                        "near_rpc_proxy_upstream_failures_total",
+                        "endpoint" => upstream.client.url().to_string(),
+                    )
+                    .increment(1);
+                    *upstream.failed_at.lock().unwrap() = Some(Instant::now());
+                    last_error = Some(error);
+                }
+            }
+        }
+        Err(match last_error {
+            Some(Error::Rpc(error)) => error,
+            Some(error) => RpcError::internal_error(format!("all upstreams failed: {error}")),
+            None => RpcError::internal_error("no upstreams configured"),
+        })
+    }
+
+    /// Serve the proxy over HTTP/1 on `listener` until the future is dropped.
+    pub async fn serve(mut self, listener: TcpListener) {
+        let limiter = self.client_limit.take();
+        let max_body_size = self.max_body_size;
+        let mut service = RpcHttpService::new(self);
+        if let Some(bytes) = max_body_size {
+            service = service.with_max_body_size(bytes);
+        }
+        loop {
+            let (stream, peer) = accept(&listener).await;
+            let service = service.clone();
+            let limiter = limiter.clone();
+            tokio::spawn(async move {
+                let service = service_fn(move |request| {
+                    let mut service = service.clone();
+                    let limited = limiter.as_ref().and_then(|l| l.check(peer.ip()).err());
+                    async move {
+                        match limited {
+                            Some(retry_after) => Ok(too_many_requests(retry_after)),
+                            None => service.call(request).await,
+                        }
+                    }
+                });
+                let _ = http1::Builder::new()
+                    .serve_connection(TokioIo::new(stream), service)
+                    .await;
+            });
+        }
+    }
+}
+
+impl NearRpcHandler for Proxy {
+    async fn dispatch(&self, method: &str, params: Value) -> Result<Value, RpcError> {
+        self.forward(method, params).await
+    }
+}
+
+impl Upstream {
+    fn is_cooling_down(&self, cooldown: Duration) -> bool {
+        self.failed_at
+            .lock()
+            .unwrap()
+            .is_some_and(|failed_at| failed_at.elapsed() < cooldown)
+    }
+}
+
+/// Whether `error` says the upstream node failed rather than the request
+fn is_upstream_failure(error: &RpcError) -> bool {
+    error.name.as_deref() == Some("INTERNAL_ERROR")
+}
+
+fn too_many_requests(retry_after: Duration) -> Response<Full<Bytes>> {
+    ::metrics::counter!("near_rpc_proxy_rate_limited_total").increment(1);
+    let mut response = Response::new(Full::default());
+    *response.status_mut() = StatusCode::TOO_MANY_REQUESTS;
+    let seconds = retry_after.as_secs_f64().ceil().max(1.0) as u64;
+    response
+        .headers_mut()
+        .insert(RETRY_AFTER, HeaderValue::from(seconds));
+    response
+}
+
+/// Token buckets per client IP address
+struct ClientLimiter {
+    rate: f64,
+    burst: f64,
+    buckets: Mutex<HashMap<IpAddr, Bucket>>,
+}
+
+struct Bucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+impl ClientLimiter {
+    fn new(requests_per_second: f64, burst: u32) -> Self {
+        Self {
+            rate: requests_per_second,
+            burst: f64::from(burst.max(1)),
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Take a token for `ip`, or return how long until one is available.
+    fn check(&self, ip: IpAddr) -> Result<(), Duration> {
+        let now = Instant::now();
+        let mut buckets = self.buckets.lock().unwrap();
+        if buckets.len() >= MAX_TRACKED_CLIENTS {
+            // Clients whose bucket has refilled are indistinguishable from new ones.
+            let (rate, burst) = (self.rate, self.burst);
+            buckets.retain(|_, bucket| bucket.refilled(now, rate) < burst);
+        }
+        let bucket = buckets.entry(ip).or_insert(Bucket {
+            tokens: self.burst,
+            updated: now,
+        });
+        bucket.tokens = bucket.refilled(now, self.rate).min(self.burst);
+        bucket.updated = now;
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64((1.0 - bucket.tokens) / self.rate))
+        }
+    }
+}
+
+impl Bucket {
+    fn refilled(&self, now: Instant, rate: f64) -> f64 {
+        self.tokens + now.duration_since(self.updated).as_secs_f64() * rate
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::MemoryCache;
+    use crate::server::serve;
+    use crate::types::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(Clone, Default)]
+    struct Node {
+        calls: Arc<AtomicUsize>,
+    }
+
+    impl NearRpcHandler for Node {
+        async fn gas_price(
+            &self,
+            request: RpcGasPriceRequest,
+        ) -> Result<RpcGasPriceResponse, RpcError> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            match request.block_id {
+                Some(BlockId::BlockHeight(0)) => Err(RpcError::handler_error(
+                    "UNKNOWN_BLOCK",
+                    serde_json::json!({}),
+                )),
+                _ => Ok(RpcGasPriceResponse {
+                    gas_price: NearToken("100000000".to_string()),
+                }),
+            }
+        }
+    }
+
+    async fn start(node: Node) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(serve(listener, node));
+        url
+    }
+
+    #[tokio::test]
+    async fn test_failover_and_cache() {
+        let node = Node::default();
+        let url = start(node.clone()).await;
+        let cache = Arc::new(MemoryCache::new(100));
+        // Nothing listens on port 1, so the first upstream always fails.
+        let proxy = Proxy::new([
+            NearRpcClient::new("http://127.0.0.1:1").with_cache(cache.clone()),
+            NearRpcClient::new(url).with_cache(cache),
+        ]);
+
+        let hash = "6RWmTYhXCzjMjoY3Mz1rfFcnBm8E6XeDDbFEPUA4sv1w";
+        for _ in 0..2 {
+            let result = proxy
+                .dispatch("gas_price", serde_json::json!({ "block_id": hash }))
+                .await
+                .unwrap();
+            assert_eq!(result["gas_price"], "100000000");
+        }
+        assert_eq!(node.calls.load(Ordering::SeqCst), 1);
+        assert!(proxy.upstreams[0].is_cooling_down(proxy.cooldown));
+        assert_eq!(
+            proxy.candidates()[0].client.url(),
+            proxy.upstreams[1].client.url()
+        );
+
+        let error = proxy
+            .dispatch("gas_price", serde_json::json!({ "block_id": 0 }))
+            .await
+            .unwrap_err();
+        assert_eq!(error.cause.unwrap().name, "UNKNOWN_BLOCK");
+    }
+
+    #[tokio::test]
+    async fn test_unknown_method() {
+        // Forwarding would fail over and mark the unreachable upstream as failed.
+        let proxy = Proxy::new([NearRpcClient::new("http://127.0.0.1:1")]);
+        let error = proxy
+            .dispatch("no_such_method", serde_json::json!({}))
+            .await
+            .unwrap_err();
+        assert_eq!(error.cause.unwrap().name, "METHOD_NOT_FOUND");
+        assert!(!proxy.upstreams[0].is_cooling_down(proxy.cooldown));
+    }
+
+    #[tokio::test]
+    async fn test_serve_with_client_rate_limit() {
+        let url = start(Node::default()).await;
+        let proxy = Proxy::new([NearRpcClient::new(url)]).with_client_rate_limit(0.1, 2);
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let proxy_url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(proxy.serve(listener));
+
+        let client = NearRpcClient::new(proxy_url);
+        for _ in 0..2 {
+            client
+                .gas_price(RpcGasPriceRequest { block_id: None })
+                .await
+                .unwrap();
+        }
+        assert!(client
+            .gas_price(RpcGasPriceRequest { block_id: None })
+            .await
+            .is_err());
+    }
+
+    #[test]
+    fn test_client_limiter() {
+        let limiter = ClientLimiter::new(1.0, 2);
+        let alice = IpAddr::from([10, 0, 0, 1]);
+        let bob = IpAddr::from([10, 0, 0, 2]);
+        assert!(limiter.check(alice).is_ok());
+        assert!(limiter.check(alice).is_ok());
+        let retry_after = limiter.check(alice).unwrap_err();
+        assert!(retry_after > Duration::ZERO && retry_after <= Duration::from_secs(1));
+        assert!(limiter.check(bob).is_ok());
+    }
+
+    #[test]
+    #[should_panic(expected = "positive and finite")]
+    fn test_zero_client_rate() {
+        Proxy::new([NearRpcClient::new("http://127.0.0.1:1")]).with_client_rate_limit(0.0, 1);
+    }
+}
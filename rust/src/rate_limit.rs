@@ -0,0 +1,285 @@
+//! Client-side rate limiting and concurrency caps.
+//!
+//! Each [`RateLimit`] is a token bucket plus an optional cap on in-flight requests. A client
+//! has one default limit for its endpoint and may give individual methods their own budget,
+//! so that heavy methods like `EXPERIMENTAL_changes` cannot starve cheap ones.
+//!
+//! When the endpoint answers `429 Too Many Requests`, the bucket stops issuing tokens until
+//! `Retry-After` has passed and halves its rate; the rate recovers gradually as requests
+//! succeed again. For clients built with `NearRpcClient::from_service`, only a
+//! `429` returned by the service as [`Error::HttpStatus`](crate::client::Error::HttpStatus)
+//! is seen, so errors mapped by its layers, e.g. into `Error::Service`, do not slow the
+//! bucket down.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+use tokio::sync::{Semaphore, SemaphorePermit};
+use tokio::time::Instant;
+
+/// Lowest fraction of the configured rate that adaptive slowdown goes down to
+const MIN_RATE_FACTOR: f64 = 1.0 / 16.0;
+/// Fraction of the configured rate regained per successful request
+const RECOVERY_STEP: f64 = 1.0 / 32.0;
+
+/// Limits for one budget. Unlimited by default.
+#[derive(Clone, Debug, Default)]
+pub struct RateLimit {
+    requests_per_second: Option<f64>,
+    burst: Option<u32>,
+    max_concurrent: Option<usize>,
+}
+
+impl RateLimit {
+    /// Create an unlimited budget
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allow at most `rate` requests per second on average
+    ///
+    /// # Panics
+    ///
+    /// If `rate` is not a positive, finite number.
+    pub fn requests_per_second(mut self, rate: f64) -> Self {
+        assert!(
+            rate.is_finite() && rate > 0.0,
+            "requests per second must be positive and finite, got {rate}"
+        );
+        self.requests_per_second = Some(rate);
+        self
+    }
+
+    /// Allow bursts of up to `burst` requests. Defaults to one second worth of requests.
+    ///
+    /// # Panics
+    ///
+    /// If `burst` is zero, since no request could ever be sent.
+    pub fn burst(mut self, burst: u32) -> Self {
+        assert!(burst > 0, "burst must allow at least one request");
+        self.burst = Some(burst);
+        self
+    }
+
+    /// Allow at most `max` requests in flight at once
+    ///
+    /// # Panics
+    ///
+    /// If `max` is zero, since no request could ever be sent.
+    pub fn max_concurrent(mut self, max: usize) -> Self {
+        assert!(max > 0, "max concurrent must allow at least one request");
+        self.max_concurrent = Some(max);
+        self
+    }
+}
+
+/// Rate limits for an endpoint, with optional per-method budgets.
+///
+/// ```no_run
+/// use near_rpc_client::{rate_limit::{RateLimit, RateLimiter}, NearRpcClient};
+///
+/// let limiter = RateLimiter::new(RateLimit::new().requests_per_second(10.0).max_concurrent(4))
+///     .method("EXPERIMENTAL_changes", RateLimit::new().requests_per_second(1.0).max_concurrent(1));
+/// let client = NearRpcClient::mainnet().with_rate_limiter(limiter);
+/// ```
+pub struct RateLimiter {
+    default: Budget,
+    methods: HashMap<String, Budget>,
+}
+
+impl RateLimiter {
+    /// Create a limiter applying `limit` to every method
+    pub fn new(limit: RateLimit) -> Self {
+        Self {
+            default: Budget::new(&limit),
+            methods: HashMap::new(),
+        }
+    }
+
+    /// Give `method` its own budget instead of the default one
+    pub fn method(mut self, method: impl Into<String>, limit: RateLimit) -> Self {
+        self.methods.insert(method.into(), Budget::new(&limit));
+        self
+    }
+
+    fn budget(&self, method: &str) -> &Budget {
+        self.methods.get(method).unwrap_or(&self.default)
+    }
+
+    /// Wait until a request for `method` may be sent.
+    ///
+    /// The returned permit counts towards the concurrency cap until dropped.
+    pub(crate) async fn acquire(&self, method: &str) -> Option<SemaphorePermit<'_>> {
+        let budget = self.budget(method);
+        let permit = match &budget.concurrency {
+            Some(semaphore) => Some(
+                semaphore
+                    .acquire()
+                    .await
+                    .expect("semaphore is never closed"),
+            ),
+            None => None,
+        };
+        if let Some(bucket) = &budget.bucket {
+            bucket.acquire().await;
+        }
+        permit
+    }
+
+    /// Record a `429 Too Many Requests` response for `method`
+    pub(crate) fn on_throttled(&self, method: &str, retry_after: Option<Duration>) {
+        if let Some(bucket) = &self.budget(method).bucket {
+            bucket.on_throttled(retry_after);
+        }
+    }
+
+    /// Record a response that was not throttled
+    pub(crate) fn on_success(&self, method: &str) {
+        if let Some(bucket) = &self.budget(method).bucket {
+            bucket.on_success();
+        }
+    }
+}
+
+struct Budget {
+    bucket: Option<TokenBucket>,
+    concurrency: Option<Semaphore>,
+}
+
+impl Budget {
+    fn new(limit: &RateLimit) -> Self {
+        Self {
+            bucket: limit.requests_per_second.map(|rate| {
+                let burst = limit.burst.map_or(rate.ceil().max(1.0), f64::from);
+                TokenBucket::new(rate, burst)
+            }),
+            concurrency: limit.max_concurrent.map(Semaphore::new),
+        }
+    }
+}
+
+struct TokenBucket {
+    rate: f64,
+    burst: f64,
+    state: Mutex<BucketState>,
+}
+
+struct BucketState {
+    tokens: f64,
+    updated: Instant,
+    rate_factor: f64,
+    paused_until: Option<Instant>,
+}
+
+impl TokenBucket {
+    fn new(rate: f64, burst: f64) -> Self {
+        Self {
+            rate,
+            burst,
+            state: Mutex::new(BucketState {
+                tokens: burst,
+                updated: Instant::now(),
+                rate_factor: 1.0,
+                paused_until: None,
+            }),
+        }
+    }
+
+    async fn acquire(&self) {
+        loop {
+            let wait = {
+                let mut state = self.state.lock().unwrap();
+                let now = Instant::now();
+                match state.paused_until {
+                    Some(until) if until > now => until - now,
+                    _ => {
+                        let rate = self.rate * state.rate_factor;
+                        let elapsed = now.saturating_duration_since(state.updated).as_secs_f64();
+                        state.tokens = (state.tokens + elapsed * rate).min(self.burst);
+                        state.updated = now;
+                        if state.tokens >= 1.0 {
+                            state.tokens -= 1.0;
+                            return;
+                        }
+                        Duration::from_secs_f64((1.0 - state.tokens) / rate)
+                    }
+                }
+            };
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    fn on_throttled(&self, retry_after: Option<Duration>) {
+        let mut state = self.state.lock().unwrap();
+        state.rate_factor = (state.rate_factor / 2.0).max(MIN_RATE_FACTOR);
+        let pause = retry_after.unwrap_or(Duration::from_secs_f64(1.0 / self.rate));
+        let until = Instant::now() + pause;
+        // Resume with a single request, then refill at the reduced rate.
+        state.tokens = 1.0;
+        state.updated = until;
+        state.paused_until = Some(until);
+    }
+
+    fn on_success(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.rate_factor = (state.rate_factor + RECOVERY_STEP).min(1.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn test_token_bucket() {
+        let limiter = RateLimiter::new(RateLimit::new().requests_per_second(10.0).burst(2));
+        let start = Instant::now();
+        for _ in 0..4 {
+            limiter.acquire("block").await;
+        }
+        // Two requests from the burst, then one every 100ms.
+        assert_eq!(start.elapsed(), Duration::from_millis(200));
+
+        limiter.on_throttled("block", Some(Duration::from_secs(3)));
+        let start = Instant::now();
+        limiter.acquire("block").await;
+        limiter.acquire("block").await;
+        // Paused for Retry-After, then at half rate.
+        assert_eq!(start.elapsed(), Duration::from_millis(3200));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_method_budget_and_concurrency() {
+        let limiter = RateLimiter::new(RateLimit::new().requests_per_second(1.0))
+            .method("EXPERIMENTAL_changes", RateLimit::new().max_concurrent(1));
+        let start = Instant::now();
+        let permit = limiter.acquire("EXPERIMENTAL_changes").await;
+        assert!(permit.is_some());
+        limiter.acquire("block").await;
+        assert_eq!(start.elapsed(), Duration::ZERO);
+
+        let second = limiter.acquire("EXPERIMENTAL_changes");
+        tokio::pin!(second);
+        assert!(poll_once(&mut second).await.is_none());
+        drop(permit);
+        assert!(second.await.is_some());
+    }
+
+    #[test]
+    fn test_invalid_limits() {
+        for rate in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            let result = std::panic::catch_unwind(|| RateLimit::new().requests_per_second(rate));
+            assert!(result.is_err(), "rate {rate} accepted");
+        }
+        assert!(std::panic::catch_unwind(|| RateLimit::new().burst(0)).is_err());
+        assert!(std::panic::catch_unwind(|| RateLimit::new().max_concurrent(0)).is_err());
+    }
+
+    async fn poll_once<F: std::future::Future + Unpin>(future: &mut F) -> Option<F::Output> {
+        tokio::select! {
+            biased;
+            output = future => Some(output),
+            _ = tokio::task::yield_now() => None,
+        }
+    }
+}
@@ -0,0 +1,483 @@
+//!Server-side handler trait, generated by `build.rs` from `openrpc.json`.
+use crate::client::RpcError;
+use crate::types::*;
+use serde_json::Value;
+use std::future::Future;
+///Server-side implementation of the NEAR JSON-RPC methods.
+///
+///Every method defaults to a `METHOD_NOT_FOUND` error, so implementations only
+///override the methods they serve. Serve a handler with a [`Dispatcher`](super::Dispatcher).
+pub trait NearRpcHandler: Send + Sync {
+    ///Answer a request by method name with its params as sent.
+    ///
+    ///Defaults to parsing `params` into the method's request type and calling the
+    ///typed method below. Override to handle requests without parsing them, e.g. to
+    ///forward them in a proxy.
+    fn dispatch(
+        &self,
+        method: &str,
+        params: Value,
+    ) -> impl Future<Output = Result<Value, RpcError>> + Send
+    where
+        Self: Sized,
+    {
+        route(self, method, params)
+    }
+    ///Returns block details for given height or hash
+    ///
+    ///JSON-RPC method `block`
+    fn block(
+        &self,
+        request: RpcBlockRequest,
+    ) -> impl Future<Output = Result<RpcBlockResponse, RpcError>> + Send {
+        let _ = request;
+        async { Err(RpcError::method_not_found("block")) }
+    }
+    ///Returns details of a specific chunk
+    ///
+    ///JSON-RPC method `chunk`
+    fn chunk(
+        &self,
+        request: RpcChunkRequest,
+    ) -> impl Future<Output = Result<RpcChunkResponse, RpcError>> + Send {
+        let _ = request;
+        async { Err(RpcError::method_not_found("chunk")) }
+    }
+    ///Returns gas price for a specific block_height or block_hash
+    ///
+    ///JSON-RPC method `gas_price`
+    fn gas_price(
+        &self,
+        request: RpcGasPriceRequest,
+    ) -> impl Future<Output = Result<RpcGasPriceResponse, RpcError>> + Send {
+        let _ = request;
+        async { Err(RpcError::method_not_found("gas_price")) }
+    }
+    ///Query the blockchain state (view account, call function, etc.)
+    ///
+    ///JSON-RPC method `query`
+    fn query(
+        &self,
+        request: RpcQueryRequest,
+    ) -> impl Future<Output = Result<RpcQueryResponse, RpcError>> + Send {
+        let _ = request;
+        async { Err(RpcError::method_not_found("query")) }
+    }
+    ///Sends a transaction and optionally waits for execution
+    ///
+    ///JSON-RPC method `send_tx`
+    fn send_tx(
+        &self,
+        request: RpcSendTransactionRequest,
+    ) -> impl Future<Output = Result<RpcTransactionResponse, RpcError>> + Send {
+        let _ = request;
+        async { Err(RpcError::method_not_found("send_tx")) }
+    }
+    ///Queries status of a transaction by hash
+    ///
+    ///JSON-RPC method `tx`
+    fn tx(
+        &self,
+        request: RpcTransactionStatusRequest,
+    ) -> impl Future<Output = Result<RpcTransactionResponse, RpcError>> + Send {
+        let _ = request;
+        async { Err(RpcError::method_not_found("tx")) }
+    }
+    ///Returns the status of the RPC node
+    ///
+    ///JSON-RPC method `status`
+    fn status(
+        &self,
+        request: RpcStatusRequest,
+    ) -> impl Future<Output = Result<RpcStatusResponse, RpcError>> + Send {
+        let _ = request;
+        async { Err(RpcError::method_not_found("status")) }
+    }
+    ///Queries active validators on the network
+    ///
+    ///JSON-RPC method `validators`
+    fn validators(
+        &self,
+        request: RpcValidatorRequest,
+    ) -> impl Future<Output = Result<RpcValidatorResponse, RpcError>> + Send {
+        let _ = request;
+        async { Err(RpcError::method_not_found("validators")) }
+    }
+    ///Queries the current state of node network connections
+    ///
+    ///JSON-RPC method `network_info`
+    fn network_info(
+        &self,
+        request: RpcNetworkInfoRequest,
+    ) -> impl Future<Output = Result<RpcNetworkInfoResponse, RpcError>> + Send {
+        let _ = request;
+        async { Err(RpcError::method_not_found("network_info")) }
+    }
+    ///Returns health status of the node
+    ///
+    ///JSON-RPC method `health`
+    fn health(
+        &self,
+        request: RpcHealthRequest,
+    ) -> impl Future<Output = Result<RpcHealthResponse, RpcError>> + Send {
+        let _ = request;
+        async { Err(RpcError::method_not_found("health")) }
+    }
+    ///Returns execution proof for light clients
+    ///
+    ///JSON-RPC method `light_client_proof`
+    fn light_client_proof(
+        &self,
+        request: RpcLightClientExecutionProofRequest,
+    ) -> impl Future<
+        Output = Result<RpcLightClientExecutionProofResponse, RpcError>,
+    > + Send {
+        let _ = request;
+        async { Err(RpcError::method_not_found("light_client_proof")) }
+    }
+    ///Returns the next light client block
+    ///
+    ///JSON-RPC method `next_light_client_block`
+    fn next_light_client_block(
+        &self,
+        request: RpcLightClientNextBlockRequest,
+    ) -> impl Future<Output = Result<RpcLightClientNextBlockResponse, RpcError>> + Send {
+        let _ = request;
+        async { Err(RpcError::method_not_found("next_light_client_block")) }
+    }
+    ///Returns block proof for light clients
+    ///
+    ///JSON-RPC method `light_client_block_proof`
+    fn light_client_block_proof(
+        &self,
+        request: RpcLightClientBlockProofRequest,
+    ) -> impl Future<
+        Output = Result<RpcLightClientBlockProofResponse, RpcError>,
+    > + Send {
+        let _ = request;
+        async { Err(RpcError::method_not_found("light_client_block_proof")) }
+    }
+    ///Returns changes in block for given block height or hash
+    ///
+    ///JSON-RPC method `EXPERIMENTAL_changes_in_block`
+    fn experimental_changes_in_block(
+        &self,
+        request: RpcStateChangesInBlockRequest,
+    ) -> impl Future<
+        Output = Result<RpcStateChangesInBlockByTypeResponse, RpcError>,
+    > + Send {
+        let _ = request;
+        async { Err(RpcError::method_not_found("EXPERIMENTAL_changes_in_block")) }
+    }
+    ///Returns state changes for specific state change kinds
+    ///
+    ///JSON-RPC method `EXPERIMENTAL_changes`
+    fn experimental_changes(
+        &self,
+        request: RpcStateChangesInBlockByTypeRequest,
+    ) -> impl Future<Output = Result<RpcStateChangesInBlockResponse, RpcError>> + Send {
+        let _ = request;
+        async { Err(RpcError::method_not_found("EXPERIMENTAL_changes")) }
+    }
+    ///Returns protocol configuration for given block
+    ///
+    ///JSON-RPC method `EXPERIMENTAL_protocol_config`
+    fn experimental_protocol_config(
+        &self,
+        request: RpcProtocolConfigRequest,
+    ) -> impl Future<Output = Result<RpcProtocolConfigResponse, RpcError>> + Send {
+        let _ = request;
+        async { Err(RpcError::method_not_found("EXPERIMENTAL_protocol_config")) }
+    }
+    ///Returns genesis configuration of the network
+    ///
+    ///JSON-RPC method `EXPERIMENTAL_genesis_config`
+    fn experimental_genesis_config(
+        &self,
+        request: GenesisConfigRequest,
+    ) -> impl Future<Output = Result<GenesisConfig, RpcError>> + Send {
+        let _ = request;
+        async { Err(RpcError::method_not_found("EXPERIMENTAL_genesis_config")) }
+    }
+    ///Returns receipt by receipt_id
+    ///
+    ///JSON-RPC method `EXPERIMENTAL_receipt`
+    fn experimental_receipt(
+        &self,
+        request: RpcReceiptRequest,
+    ) -> impl Future<Output = Result<RpcReceiptResponse, RpcError>> + Send {
+        let _ = request;
+        async { Err(RpcError::method_not_found("EXPERIMENTAL_receipt")) }
+    }
+    ///Returns maintenance windows for validators
+    ///
+    ///JSON-RPC method `EXPERIMENTAL_maintenance_windows`
+    fn experimental_maintenance_windows(
+        &self,
+        request: RpcMaintenanceWindowsRequest,
+    ) -> impl Future<Output = Result<BlockHeightRanges, RpcError>> + Send {
+        let _ = request;
+        async { Err(RpcError::method_not_found("EXPERIMENTAL_maintenance_windows")) }
+    }
+    ///Returns split storage information
+    ///
+    ///JSON-RPC method `EXPERIMENTAL_split_storage_info`
+    fn experimental_split_storage_info(
+        &self,
+        request: RpcSplitStorageInfoRequest,
+    ) -> impl Future<Output = Result<RpcSplitStorageInfoResponse, RpcError>> + Send {
+        let _ = request;
+        async { Err(RpcError::method_not_found("EXPERIMENTAL_split_storage_info")) }
+    }
+    ///Returns congestion level for a chunk
+    ///
+    ///JSON-RPC method `EXPERIMENTAL_congestion_level`
+    fn experimental_congestion_level(
+        &self,
+        request: RpcCongestionLevelRequest,
+    ) -> impl Future<Output = Result<RpcCongestionLevelResponse, RpcError>> + Send {
+        let _ = request;
+        async { Err(RpcError::method_not_found("EXPERIMENTAL_congestion_level")) }
+    }
+    ///Returns validators ordered by stake for given epoch
+    ///
+    ///JSON-RPC method `EXPERIMENTAL_validators_ordered`
+    fn experimental_validators_ordered(
+        &self,
+        request: RpcValidatorsOrderedRequest,
+    ) -> impl Future<Output = Result<ValidatorStakeViews, RpcError>> + Send {
+        let _ = request;
+        async { Err(RpcError::method_not_found("EXPERIMENTAL_validators_ordered")) }
+    }
+    ///Returns client configuration
+    ///
+    ///JSON-RPC method `EXPERIMENTAL_client_config`
+    fn experimental_client_config(
+        &self,
+        request: RpcClientConfigRequest,
+    ) -> impl Future<Output = Result<RpcClientConfigResponse, RpcError>> + Send {
+        let _ = request;
+        async { Err(RpcError::method_not_found("EXPERIMENTAL_client_config")) }
+    }
+    ///Queries status of a transaction by hash (alias for tx)
+    ///
+    ///JSON-RPC method `EXPERIMENTAL_tx_status`
+    fn experimental_tx_status(
+        &self,
+        request: RpcTransactionStatusRequest,
+    ) -> impl Future<Output = Result<RpcTransactionResponse, RpcError>> + Send {
+        let _ = request;
+        async { Err(RpcError::method_not_found("EXPERIMENTAL_tx_status")) }
+    }
+    ///Returns execution proof for light clients
+    ///
+    ///JSON-RPC method `EXPERIMENTAL_light_client_proof`
+    fn experimental_light_client_proof(
+        &self,
+        request: RpcLightClientExecutionProofRequest,
+    ) -> impl Future<
+        Output = Result<RpcLightClientExecutionProofResponse, RpcError>,
+    > + Send {
+        let _ = request;
+        async { Err(RpcError::method_not_found("EXPERIMENTAL_light_client_proof")) }
+    }
+    ///Returns block proof for light clients
+    ///
+    ///JSON-RPC method `EXPERIMENTAL_light_client_block_proof`
+    fn experimental_light_client_block_proof(
+        &self,
+        request: RpcLightClientBlockProofRequest,
+    ) -> impl Future<
+        Output = Result<RpcLightClientBlockProofResponse, RpcError>,
+    > + Send {
+        let _ = request;
+        async {
+            Err(RpcError::method_not_found("EXPERIMENTAL_light_client_block_proof"))
+        }
+    }
+    ///Returns changes in block (alias for EXPERIMENTAL_changes_in_block)
+    ///
+    ///JSON-RPC method `block_effects`
+    fn block_effects(
+        &self,
+        request: RpcStateChangesInBlockRequest,
+    ) -> impl Future<
+        Output = Result<RpcStateChangesInBlockByTypeResponse, RpcError>,
+    > + Send {
+        let _ = request;
+        async { Err(RpcError::method_not_found("block_effects")) }
+    }
+    ///Returns state changes (alias for EXPERIMENTAL_changes)
+    ///
+    ///JSON-RPC method `changes`
+    fn changes(
+        &self,
+        request: RpcStateChangesInBlockByTypeRequest,
+    ) -> impl Future<Output = Result<RpcStateChangesInBlockResponse, RpcError>> + Send {
+        let _ = request;
+        async { Err(RpcError::method_not_found("changes")) }
+    }
+    ///Returns genesis configuration (alias for EXPERIMENTAL_genesis_config)
+    ///
+    ///JSON-RPC method `genesis_config`
+    fn genesis_config(
+        &self,
+        request: GenesisConfigRequest,
+    ) -> impl Future<Output = Result<GenesisConfig, RpcError>> + Send {
+        let _ = request;
+        async { Err(RpcError::method_not_found("genesis_config")) }
+    }
+    ///Returns client configuration (alias for EXPERIMENTAL_client_config)
+    ///
+    ///JSON-RPC method `client_config`
+    fn client_config(
+        &self,
+        request: RpcClientConfigRequest,
+    ) -> impl Future<Output = Result<RpcClientConfigResponse, RpcError>> + Send {
+        let _ = request;
+        async { Err(RpcError::method_not_found("client_config")) }
+    }
+    ///Returns maintenance windows (alias for EXPERIMENTAL_maintenance_windows)
+    ///
+    ///JSON-RPC method `maintenance_windows`
+    fn maintenance_windows(
+        &self,
+        request: RpcMaintenanceWindowsRequest,
+    ) -> impl Future<Output = Result<BlockHeightRanges, RpcError>> + Send {
+        let _ = request;
+        async { Err(RpcError::method_not_found("maintenance_windows")) }
+    }
+    ///Sends a transaction and immediately returns hash (deprecated: use send_tx)
+    ///
+    ///JSON-RPC method `broadcast_tx_async`
+    fn broadcast_tx_async(
+        &self,
+        request: RpcSendTransactionRequest,
+    ) -> impl Future<Output = Result<CryptoHash, RpcError>> + Send {
+        let _ = request;
+        async { Err(RpcError::method_not_found("broadcast_tx_async")) }
+    }
+    ///Sends a transaction and waits for completion (deprecated: use send_tx)
+    ///
+    ///JSON-RPC method `broadcast_tx_commit`
+    fn broadcast_tx_commit(
+        &self,
+        request: RpcSendTransactionRequest,
+    ) -> impl Future<Output = Result<RpcTransactionResponse, RpcError>> + Send {
+        let _ = request;
+        async { Err(RpcError::method_not_found("broadcast_tx_commit")) }
+    }
+}
+///Call the `handler` method for the JSON-RPC `method` with JSON `params`.
+pub(crate) async fn route<H: NearRpcHandler>(
+    handler: &H,
+    method: &str,
+    params: Value,
+) -> Result<Value, RpcError> {
+    match method {
+        "block" => super::respond(handler.block(super::params(params)?).await),
+        "chunk" => super::respond(handler.chunk(super::params(params)?).await),
+        "gas_price" => super::respond(handler.gas_price(super::params(params)?).await),
+        "query" => super::respond(handler.query(super::params(params)?).await),
+        "send_tx" => super::respond(handler.send_tx(super::params(params)?).await),
+        "tx" => super::respond(handler.tx(super::params(params)?).await),
+        "status" => super::respond(handler.status(super::params(params)?).await),
+        "validators" => super::respond(handler.validators(super::params(params)?).await),
+        "network_info" => {
+            super::respond(handler.network_info(super::params(params)?).await)
+        }
+        "health" => super::respond(handler.health(super::params(params)?).await),
+        "light_client_proof" => {
+            super::respond(handler.light_client_proof(super::params(params)?).await)
+        }
+        "next_light_client_block" => {
+            super::respond(handler.next_light_client_block(super::params(params)?).await)
+        }
+        "light_client_block_proof" => {
+            super::respond(
+                handler.light_client_block_proof(super::params(params)?).await,
+            )
+        }
+        "EXPERIMENTAL_changes_in_block" => {
+            super::respond(
+                handler.experimental_changes_in_block(super::params(params)?).await,
+            )
+        }
+        "EXPERIMENTAL_changes" => {
+            super::respond(handler.experimental_changes(super::params(params)?).await)
+        }
+        "EXPERIMENTAL_protocol_config" => {
+            super::respond(
+                handler.experimental_protocol_config(super::params(params)?).await,
+            )
+        }
+        "EXPERIMENTAL_genesis_config" => {
+            super::respond(
+                handler.experimental_genesis_config(super::params(params)?).await,
+            )
+        }
+        "EXPERIMENTAL_receipt" => {
+            super::respond(handler.experimental_receipt(super::params(params)?).await)
+        }
+        "EXPERIMENTAL_maintenance_windows" => {
+            super::respond(
+                handler.experimental_maintenance_windows(super::params(params)?).await,
+            )
+        }
+        "EXPERIMENTAL_split_storage_info" => {
+            super::respond(
+                handler.experimental_split_storage_info(super::params(params)?).await,
+            )
+        }
+        "EXPERIMENTAL_congestion_level" => {
+            super::respond(
+                handler.experimental_congestion_level(super::params(params)?).await,
+            )
+        }
+        "EXPERIMENTAL_validators_ordered" => {
+            super::respond(
+                handler.experimental_validators_ordered(super::params(params)?).await,
+            )
+        }
+        "EXPERIMENTAL_client_config" => {
+            super::respond(
+                handler.experimental_client_config(super::params(params)?).await,
+            )
+        }
+        "EXPERIMENTAL_tx_status" => {
+            super::respond(handler.experimental_tx_status(super::params(params)?).await)
+        }
+        "EXPERIMENTAL_light_client_proof" => {
+            super::respond(
+                handler.experimental_light_client_proof(super::params(params)?).await,
+            )
+        }
+        "EXPERIMENTAL_light_client_block_proof" => {
+            super::respond(
+                handler
+                    .experimental_light_client_block_proof(super::params(params)?)
+                    .await,
+            )
+        }
+        "block_effects" => {
+            super::respond(handler.block_effects(super::params(params)?).await)
+        }
+        "changes" => super::respond(handler.changes(super::params(params)?).await),
+        "genesis_config" => {
+            super::respond(handler.genesis_config(super::params(params)?).await)
+        }
+        "client_config" => {
+            super::respond(handler.client_config(super::params(params)?).await)
+        }
+        "maintenance_windows" => {
+            super::respond(handler.maintenance_windows(super::params(params)?).await)
+        }
+        "broadcast_tx_async" => {
+            super::respond(handler.broadcast_tx_async(super::params(params)?).await)
+        }
+        "broadcast_tx_commit" => {
+            super::respond(handler.broadcast_tx_commit(super::params(params)?).await)
+        }
+        _ => Err(RpcError::method_not_found(method)),
+    }
+}
@@ -0,0 +1,221 @@
+//! HTTP adapter for a [`Dispatcher`].
+
+use super::{Dispatcher, NearRpcHandler};
+use http_body_util::{BodyExt, Full, LengthLimitError, Limited};
+use hyper::body::{Body, Bytes};
+use hyper::header::{HeaderValue, CONTENT_TYPE};
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Method, Request, Response, StatusCode};
+use hyper_util::rt::TokioIo;
+use std::convert::Infallible;
+use std::future::Future;
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+use tokio::net::{TcpListener, TcpStream};
+use tower::Service;
+
+/// Pause after a failed `accept`, e.g. when out of file descriptors
+const ACCEPT_ERROR_DELAY: Duration = Duration::from_millis(100);
+/// Largest request body read by default
+const DEFAULT_MAX_BODY_SIZE: usize = 4 * 1024 * 1024;
+
+/// Serves a [`NearRpcHandler`] over HTTP.
+///
+/// JSON-RPC requests are read from `POST` bodies on any path. Other methods get
+/// `405 Method Not Allowed`, requests consisting only of notifications get
+/// `204 No Content` and bodies over the [size limit](Self::with_max_body_size) get
+/// `413 Payload Too Large`.
+pub struct RpcHttpService<H> {
+    dispatcher: Dispatcher<H>,
+    max_body_size: usize,
+}
+
+impl<H> Clone for RpcHttpService<H> {
+    fn clone(&self) -> Self {
+        Self {
+            dispatcher: self.dispatcher.clone(),
+            max_body_size: self.max_body_size,
+        }
+    }
+}
+
+impl<H: NearRpcHandler> RpcHttpService<H> {
+    /// Serve `handler`
+    pub fn new(handler: H) -> Self {
+        Self::from_dispatcher(Dispatcher::new(handler))
+    }
+
+    /// Serve the handler of an existing `dispatcher`
+    pub fn from_dispatcher(dispatcher: Dispatcher<H>) -> Self {
+        Self {
+            dispatcher,
+            max_body_size: DEFAULT_MAX_BODY_SIZE,
+        }
+    }
+
+    /// Reject request bodies larger than `bytes`. Defaults to 4 MiB.
+    pub fn with_max_body_size(mut self, bytes: usize) -> Self {
+        self.max_body_size = bytes;
+        self
+    }
+}
+
+impl<H, B> Service<Request<B>> for RpcHttpService<H>
+where
+    H: NearRpcHandler + 'static,
+    B: Body + Send + 'static,
+    B::Data: Send,
+    B::Error: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    type Response = Response<Full<Bytes>>;
+    type Error = Infallible;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Infallible>> + Send>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Infallible>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: Request<B>) -> Self::Future {
+        let service = self.clone();
+        Box::pin(async move { Ok(service.respond(request).await) })
+    }
+}
+
+impl<H: NearRpcHandler> RpcHttpService<H> {
+    async fn respond<B>(&self, request: Request<B>) -> Response<Full<Bytes>>
+    where
+        B: Body,
+        B::Error: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        if request.method() != Method::POST {
+            return status(StatusCode::METHOD_NOT_ALLOWED);
+        }
+        let body = match Limited::new(request.into_body(), self.max_body_size)
+            .collect()
+            .await
+        {
+            Ok(body) => body.to_bytes(),
+            Err(error) if error.is::<LengthLimitError>() => {
+                return status(StatusCode::PAYLOAD_TOO_LARGE)
+            }
+            Err(_) => return status(StatusCode::BAD_REQUEST),
+        };
+        match self.dispatcher.handle(&body).await {
+            Some(reply) => {
+                let mut response = Response::new(Full::new(Bytes::from(reply.to_string())));
+                response
+                    .headers_mut()
+                    .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+                response
+            }
+            None => status(StatusCode::NO_CONTENT),
+        }
+    }
+}
+
+fn status(status: StatusCode) -> Response<Full<Bytes>> {
+    let mut response = Response::new(Full::default());
+    *response.status_mut() = status;
+    response
+}
+
+/// Accept the next connection, pausing after errors rather than retrying in a busy loop.
+pub(crate) async fn accept(listener: &TcpListener) -> (TcpStream, SocketAddr) {
+    loop {
+        match listener.accept().await {
+            Ok(connection) => return connection,
+            Err(_) => tokio::time::sleep(ACCEPT_ERROR_DELAY).await,
+        }
+    }
+}
+
+/// Serve `handler` over HTTP/1 on `listener` until the future is dropped.
+pub async fn serve<H: NearRpcHandler + 'static>(listener: TcpListener, handler: H) {
+    let service = RpcHttpService::new(handler);
+    loop {
+        let (stream, _) = accept(&listener).await;
+        let service = service.clone();
+        tokio::spawn(async move {
+            let service = service_fn(move |request| {
+                let service = service.clone();
+                async move { Ok::<_, Infallible>(service.respond(request).await) }
+            });
+            let _ = http1::Builder::new()
+                .serve_connection(TokioIo::new(stream), service)
+                .await;
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::RpcError;
+    use crate::types::*;
+
+    struct Handler;
+
+    impl NearRpcHandler for Handler {
+        async fn gas_price(
+            &self,
+            _request: RpcGasPriceRequest,
+        ) -> Result<RpcGasPriceResponse, RpcError> {
+            Ok(RpcGasPriceResponse {
+                gas_price: NearToken("100000000".to_string()),
+            })
+        }
+    }
+
+    #[cfg(feature = "reqwest")]
+    #[tokio::test]
+    async fn test_serve() {
+        use crate::client::Error;
+        use crate::NearRpcClient;
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let server = tokio::spawn(serve(listener, Handler));
+
+        let client = NearRpcClient::new(&url);
+        let gas_price = client
+            .gas_price(RpcGasPriceRequest { block_id: None })
+            .await
+            .unwrap();
+        assert_eq!(gas_price.gas_price.0, "100000000");
+        assert!(matches!(
+            client.health().await,
+            Err(Error::Rpc(e)) if e.code == -32601
+        ));
+        server.abort();
+    }
+
+    #[tokio::test]
+    async fn test_http_service() {
+        use tower::ServiceExt;
+
+        let service = RpcHttpService::new(Handler);
+        let request = Request::get("/").body(Full::<Bytes>::default()).unwrap();
+        let response = service.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::METHOD_NOT_ALLOWED);
+
+        let body = r#"{"jsonrpc":"2.0","method":"gas_price","params":{}}"#;
+        let request = Request::post("/")
+            .body(Full::new(Bytes::from(body)))
+            .unwrap();
+        let response = service.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+        let request = Request::post("/")
+            .body(Full::new(Bytes::from(body)))
+            .unwrap();
+        let response = service
+            .with_max_body_size(body.len() - 1)
+            .oneshot(request)
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    }
+}
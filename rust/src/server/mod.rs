@@ -0,0 +1,257 @@
+//! Server side of the NEAR JSON-RPC protocol, for proxies and services that speak it.
+//!
+//! Implement the methods you serve on [`NearRpcHandler`], then answer JSON-RPC request
+//! bodies with a [`Dispatcher`]. It parses single and batch requests, routes them to the
+//! handler with the generated request types, and formats errors in nearcore's
+//! `name`/`cause` shape, see the constructors on [`RpcError`].
+//!
+//! With the `server` feature, [`RpcHttpService`] serves a handler over HTTP as a
+//! `tower::Service`, usable with `hyper` directly through [`serve`] or mounted in an
+//! `axum` router with `Router::route_service`.
+//!
+//! ```no_run
+//! use near_rpc_client::client::RpcError;
+//! use near_rpc_client::server::{Dispatcher, NearRpcHandler};
+//! use near_rpc_client::types::*;
+//! use serde_json::json;
+//!
+//! struct GasOracle;
+//!
+//! impl NearRpcHandler for GasOracle {
+//!     async fn gas_price(
+//!         &self,
+//!         request: RpcGasPriceRequest,
+//!     ) -> Result<RpcGasPriceResponse, RpcError> {
+//!         match request.block_id {
+//!             None => Ok(RpcGasPriceResponse { gas_price: NearToken("100000000".to_string()) }),
+//!             Some(_) => Err(RpcError::handler_error("UNKNOWN_BLOCK", json!({}))),
+//!         }
+//!     }
+//! }
+//!
+//! # async fn example() {
+//! let dispatcher = Dispatcher::new(GasOracle);
+//! let response = dispatcher
+//!     .handle(br#"{"jsonrpc":"2.0","id":1,"method":"gas_price","params":[null]}"#)
+//!     .await;
+//! # let _ = response;
+//! # }
+//! ```
+
+mod handler;
+#[cfg(feature = "server")]
+mod http;
+
+pub use handler::NearRpcHandler;
+#[cfg(any(feature = "proxy", feature = "testing"))]
+pub(crate) use http::accept;
+#[cfg(feature = "server")]
+pub use http::{serve, RpcHttpService};
+
+use crate::client::RpcError;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use std::sync::Arc;
+
+/// Routes JSON-RPC requests to a [`NearRpcHandler`].
+///
+/// Clones share the same handler.
+pub struct Dispatcher<H> {
+    handler: Arc<H>,
+}
+
+impl<H> Clone for Dispatcher<H> {
+    fn clone(&self) -> Self {
+        Self {
+            handler: self.handler.clone(),
+        }
+    }
+}
+
+impl<H: NearRpcHandler> Dispatcher<H> {
+    /// Dispatch requests to `handler`
+    pub fn new(handler: H) -> Self {
+        Self {
+            handler: Arc::new(handler),
+        }
+    }
+
+    pub fn handler(&self) -> &H {
+        &self.handler
+    }
+
+    /// Answer a JSON-RPC request body.
+    ///
+    /// Returns the response to a single request, or the array of responses to a batch in
+    /// request order. Notifications, requests without an `id`, get no response, so `None`
+    /// is returned when there is nothing to answer. A body that is not JSON is answered
+    /// with a `PARSE_ERROR`.
+    pub async fn handle(&self, body: &[u8]) -> Option<Value> {
+        match serde_json::from_slice(body) {
+            Ok(message) => self.handle_message(message).await,
+            Err(error) => Some(response(
+                Value::Null,
+                Err(RpcError::parse_error(error.to_string())),
+            )),
+        }
+    }
+
+    /// Answer an already parsed JSON-RPC request or batch, like [`handle`](Self::handle)
+    pub async fn handle_message(&self, message: Value) -> Option<Value> {
+        match message {
+            Value::Array(batch) if batch.is_empty() => Some(response(
+                Value::Null,
+                Err(RpcError::parse_error("empty batch")),
+            )),
+            Value::Array(batch) => {
+                let mut responses = Vec::new();
+                for request in batch {
+                    responses.extend(self.handle_request(request).await);
+                }
+                (!responses.is_empty()).then_some(Value::Array(responses))
+            }
+            request => self.handle_request(request).await,
+        }
+    }
+
+    /// Call `method` with JSON `params`
+    pub async fn call(&self, method: &str, params: Value) -> Result<Value, RpcError> {
+        self.handler.dispatch(method, params).await
+    }
+
+    async fn handle_request(&self, request: Value) -> Option<Value> {
+        let Value::Object(mut request) = request else {
+            return Some(response(
+                Value::Null,
+                Err(RpcError::parse_error("request must be an object")),
+            ));
+        };
+        let id = request.remove("id");
+        let result = match request.remove("method") {
+            Some(Value::String(method)) => {
+                let params = request.remove("params").unwrap_or(Value::Null);
+                self.call(&method, params).await
+            }
+            _ => Err(RpcError::parse_error("request must have a string method")),
+        };
+        id.map(|id| response(id, result))
+    }
+}
+
+fn response(id: Value, result: Result<Value, RpcError>) -> Value {
+    match result {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+        Err(error) => json!({ "jsonrpc": "2.0", "id": id, "error": error }),
+    }
+}
+
+/// Deserialize the request from `params`.
+///
+/// Params are accepted by name, or as a single positional object like nearcore does for
+/// `[{...}]`. Missing or empty params stand for a request without fields.
+fn params<T: DeserializeOwned>(params: Value) -> Result<T, RpcError> {
+    let params = match params {
+        Value::Null => Value::Object(Map::new()),
+        Value::Array(mut values) if values.len() <= 1 => match values.pop() {
+            None | Some(Value::Null) => Value::Object(Map::new()),
+            Some(value) => value,
+        },
+        params => params,
+    };
+    serde_json::from_value(params)
+        .map_err(|error| RpcError::parse_error(format!("Failed parsing args: {error}")))
+}
+
+fn respond<T: Serialize>(result: Result<T, RpcError>) -> Result<Value, RpcError> {
+    serde_json::to_value(result?).map_err(|error| RpcError::internal_error(error.to_string()))
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+    use crate::types::*;
+
+    struct Handler;
+
+    impl NearRpcHandler for Handler {
+        async fn gas_price(
+            &self,
+            request: RpcGasPriceRequest,
+        ) -> Result<RpcGasPriceResponse, RpcError> {
+            match request.block_id {
+                None => Ok(RpcGasPriceResponse {
+                    gas_price: NearToken("100000000".to_string()),
+                }),
+                Some(_) => Err(RpcError::handler_error("UNKNOWN_BLOCK", json!({}))),
+            }
+        }
+    }
+
+    fn error_cause(response: &Value) -> &str {
+        response["error"]["cause"]["name"].as_str().unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_dispatch() {
+        let dispatcher = Dispatcher::new(Handler);
+
+        let response = dispatcher
+            .handle(br#"{"jsonrpc":"2.0","id":"a","method":"gas_price","params":[null]}"#)
+            .await
+            .unwrap();
+        assert_eq!(
+            response,
+            json!({ "jsonrpc": "2.0", "id": "a", "result": { "gas_price": "100000000" } })
+        );
+
+        let response = dispatcher
+            .handle(br#"{"jsonrpc":"2.0","id":1,"method":"gas_price","params":{"block_id":1}}"#)
+            .await
+            .unwrap();
+        assert_eq!(response["error"]["name"], "HANDLER_ERROR");
+        assert_eq!(error_cause(&response), "UNKNOWN_BLOCK");
+
+        let response = dispatcher
+            .handle(br#"{"jsonrpc":"2.0","id":1,"method":"block","params":{"finality":"final"}}"#)
+            .await
+            .unwrap();
+        assert_eq!(response["error"]["code"], -32601);
+        assert_eq!(error_cause(&response), "METHOD_NOT_FOUND");
+
+        let response = dispatcher
+            .handle(br#"{"jsonrpc":"2.0","id":1,"method":"gas_price","params":{"block_id":[]}}"#)
+            .await
+            .unwrap();
+        assert_eq!(error_cause(&response), "PARSE_ERROR");
+
+        let response = dispatcher.handle(b"{").await.unwrap();
+        assert_eq!(response["id"], Value::Null);
+        assert_eq!(error_cause(&response), "PARSE_ERROR");
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_batch() {
+        let dispatcher = Dispatcher::new(Handler);
+        let response = dispatcher
+            .handle_message(json!([
+                { "jsonrpc": "2.0", "id": 1, "method": "gas_price", "params": {} },
+                { "jsonrpc": "2.0", "method": "gas_price", "params": {} },
+                { "jsonrpc": "2.0", "id": 2, "method": "status" },
+                7,
+            ]))
+            .await
+            .unwrap();
+        let responses = response.as_array().unwrap();
+        assert_eq!(responses.len(), 3);
+        assert_eq!(responses[0]["result"]["gas_price"], "100000000");
+        assert_eq!(responses[1]["id"], 2);
+        assert_eq!(error_cause(&responses[1]), "METHOD_NOT_FOUND");
+        assert_eq!(error_cause(&responses[2]), "PARSE_ERROR");
+
+        let notifications = json!([{ "jsonrpc": "2.0", "method": "gas_price" }]);
+        assert_eq!(dispatcher.handle_message(notifications).await, None);
+        let empty = dispatcher.handle_message(json!([])).await.unwrap();
+        assert_eq!(error_cause(&empty), "PARSE_ERROR");
+    }
+}
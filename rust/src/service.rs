@@ -0,0 +1,246 @@
+//! [`tower`] integration.
+//!
+//! The JSON-RPC call path is exposed as a `tower::Service<RpcCall>`, so standard middleware
+//! (timeouts, retries, load shedding, ...) can be stacked around it and the result used as
+//! the transport of a [`NearRpcClient`].
+//!
+//! ```no_run
+//! use near_rpc_client::service::HttpService;
+//! use near_rpc_client::NearRpcClient;
+//! use std::time::Duration;
+//! use tower::ServiceBuilder;
+//!
+//! let service = ServiceBuilder::new()
+//!     .timeout(Duration::from_secs(10))
+//!     .concurrency_limit(16)
+//!     .service(HttpService::new("https://rpc.mainnet.near.org"));
+//! let client = NearRpcClient::from_service(service);
+//! ```
+//!
+//! Layers producing their own error types are supported: errors that are not a
+//! [`client::Error`](Error) are returned as [`Error::Service`].
+
+use crate::client::{self, Error, NearRpcClient, Result};
+use crate::transport::{BoxFuture, Transport};
+use serde_json::Value;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use tower::util::BoxCloneSyncService;
+use tower::{BoxError, Service, ServiceExt};
+
+/// A single JSON-RPC call: the method name and its params.
+///
+/// The response of a service handling it is the JSON-RPC `result`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RpcCall {
+    pub method: String,
+    pub params: Value,
+}
+
+impl RpcCall {
+    /// Create a call of `method` with `params`
+    pub fn new(method: impl Into<String>, params: Value) -> Self {
+        Self {
+            method: method.into(),
+            params,
+        }
+    }
+}
+
+/// Type-erased service a [`NearRpcClient`] sends its calls through
+pub(crate) type BoxRpcService = BoxCloneSyncService<RpcCall, Value, Error>;
+
+/// Service sending each call as a JSON-RPC request over HTTP.
+#[derive(Clone)]
+pub struct HttpService {
+    transport: Arc<dyn Transport>,
+    url: Arc<str>,
+    request_id: Arc<AtomicU64>,
+}
+
+impl HttpService {
+    /// Create a service posting to `url`
+    #[cfg(feature = "reqwest")]
+    pub fn new(url: impl Into<String>) -> Self {
+        Self::with_client(reqwest::Client::new(), url)
+    }
+
+    /// Create a service posting to `url` with a preconfigured `reqwest` client
+    #[cfg(feature = "reqwest")]
+    pub fn with_client(client: reqwest::Client, url: impl Into<String>) -> Self {
+        Self::with_transport(crate::transport::ReqwestTransport::new(client), url)
+    }
+
+    /// Create a service posting to `url` with `transport`
+    pub fn with_transport(transport: impl Transport + 'static, url: impl Into<String>) -> Self {
+        Self {
+            transport: Arc::new(transport),
+            url: url.into().into(),
+            request_id: Arc::new(AtomicU64::new(1)),
+        }
+    }
+}
+
+impl Service<RpcCall> for HttpService {
+    type Response = Value;
+    type Error = Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Value>> + Send>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, call: RpcCall) -> Self::Future {
+        let transport = self.transport.clone();
+        let url = self.url.clone();
+        let id = self.request_id.fetch_add(1, Ordering::Relaxed);
+        Box::pin(async move {
+            let response =
+                client::post(transport.as_ref(), &url, id, &call.method, call.params).await?;
+            client::read_result(response, &url, &call.method, id)
+        })
+    }
+}
+
+impl NearRpcClient {
+    /// Create a client sending every call through `service`.
+    ///
+    /// Caching, coalescing and rate limiting configured on the client still apply before
+    /// a call reaches the service. The client has no URL of its own: its
+    /// [`url`](NearRpcClient::url), reported as the `endpoint` of metrics and traces, is
+    /// [`SERVICE_ENDPOINT`].
+    pub fn from_service<S>(service: S) -> Self
+    where
+        S: Service<RpcCall, Response = Value> + Clone + Send + Sync + 'static,
+        S::Error: Into<BoxError>,
+        S::Future: Send + 'static,
+    {
+        let service = service.map_err(|error: S::Error| into_client_error(error.into()));
+        let mut client = Self::from_transport(SERVICE_ENDPOINT, Unused);
+        client.service = Some(BoxCloneSyncService::new(service));
+        client
+    }
+}
+
+/// Endpoint of clients built from a service
+pub const SERVICE_ENDPOINT: &str = "service";
+
+/// Transport of clients built from a service, which never send requests themselves
+struct Unused;
+
+impl Transport for Unused {
+    fn send(
+        &self,
+        _request: http::Request<Vec<u8>>,
+    ) -> BoxFuture<'_, std::result::Result<http::Response<Vec<u8>>, BoxError>> {
+        Box::pin(async { Err("requests are sent by the service".into()) })
+    }
+}
+
+/// Drive one call through `service`.
+pub(crate) async fn oneshot(service: &BoxRpcService, method: &str, params: Value) -> Result<Value> {
+    service.clone().oneshot(RpcCall::new(method, params)).await
+}
+
+fn into_client_error(error: BoxError) -> Error {
+    match error.downcast::<Error>() {
+        Ok(error) => *error,
+        Err(error) => Error::Service(Arc::from(error)),
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+    use crate::client::RpcError;
+    use crate::types::RpcGasPriceRequest;
+    use serde_json::json;
+    use std::sync::Mutex;
+    use std::time::Duration;
+    use tower::ServiceBuilder;
+
+    #[tokio::test]
+    async fn test_client_from_service() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let recorded = calls.clone();
+        let service = tower::service_fn(move |call: RpcCall| {
+            recorded.lock().unwrap().push(call.clone());
+            async move {
+                match call.method.as_str() {
+                    "gas_price" => Ok(json!({ "gas_price": "100000000" })),
+                    _ => Err(Error::Rpc(RpcError {
+                        code: -32601,
+                        message: "Method not found".to_string(),
+                        data: None,
+                        name: None,
+                        cause: None,
+                    })),
+                }
+            }
+        });
+        let client = NearRpcClient::from_service(ServiceBuilder::new().service(service));
+        assert_eq!(client.url(), SERVICE_ENDPOINT);
+
+        let gas_price = client
+            .gas_price(RpcGasPriceRequest { block_id: None })
+            .await
+            .unwrap();
+        assert_eq!(gas_price.gas_price.0, "100000000");
+        assert_eq!(
+            calls.lock().unwrap()[0],
+            RpcCall::new("gas_price", json!({}))
+        );
+
+        // Errors of the service are passed through unchanged.
+        assert!(matches!(
+            client.health().await,
+            Err(Error::Rpc(e)) if e.code == -32601
+        ));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_layer_errors() {
+        let service = tower::service_fn(|_: RpcCall| async {
+            tokio::time::sleep(Duration::from_secs(60)).await;
+            Ok::<_, Error>(json!({}))
+        });
+        let client = NearRpcClient::from_service(
+            ServiceBuilder::new()
+                .timeout(Duration::from_secs(1))
+                .service(service),
+        );
+        assert!(matches!(client.health().await, Err(Error::Service(_))));
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test(start_paused = true)]
+    async fn test_throttled_service() {
+        use crate::rate_limit::{RateLimit, RateLimiter};
+
+        let throttled = Arc::new(std::sync::atomic::AtomicBool::new(true));
+        let service = tower::service_fn(move |_: RpcCall| {
+            let throttled = throttled.swap(false, Ordering::Relaxed);
+            async move {
+                if throttled {
+                    return Err(Error::HttpStatus {
+                        status: 429,
+                        body_snippet: "Too Many Requests".to_string(),
+                        retry_after: Some(Duration::from_secs(3)),
+                    });
+                }
+                Ok(json!(null))
+            }
+        });
+        let client = NearRpcClient::from_service(service)
+            .with_rate_limiter(RateLimiter::new(RateLimit::new().requests_per_second(10.0)));
+
+        let start = tokio::time::Instant::now();
+        assert!(client.health().await.is_err());
+        client.health().await.unwrap();
+        // The 429 of the service pauses the bucket for Retry-After.
+        assert_eq!(start.elapsed(), Duration::from_secs(3));
+    }
+}
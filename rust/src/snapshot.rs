@@ -0,0 +1,320 @@
+//! Consistent reads pinned to a single block.
+
+use crate::client::{NearRpcClient, Result};
+use crate::types::*;
+use serde::Serialize;
+use serde_json::Value;
+use std::ops::Deref;
+
+/// A response together with the block it was read at.
+#[derive(Clone, Debug)]
+pub struct Pinned<T> {
+    pub block_hash: CryptoHash,
+    pub block_height: u64,
+    pub value: T,
+}
+
+impl<T> Pinned<T> {
+    /// Unwrap the response
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> Deref for Pinned<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+/// Handle that runs every request against one block.
+///
+/// Created with [`NearRpcClient::at_block`] or [`NearRpcClient::snapshot`], which resolve a
+/// block reference such as `Finality::Final` to a concrete block hash once. Requests passed to
+/// the snapshot may use any block reference; it is replaced with the pinned block hash.
+///
+/// # Example
+///
+/// ```no_run
+/// use near_rpc_client::{types::*, NearRpcClient};
+///
+/// # async fn example() -> near_rpc_client::client::Result<()> {
+/// let client = NearRpcClient::mainnet();
+/// let snapshot = client.snapshot().await?;
+/// // Both accounts are read at the same block, however many blocks pass in between.
+/// let alice = snapshot.view_account("alice.near").await?;
+/// let bob = snapshot.view_account("bob.near").await?;
+/// println!("balances at block {}: {alice:?}, {bob:?}", snapshot.block_height());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct BlockSnapshot<'a> {
+    client: &'a NearRpcClient,
+    block_hash: CryptoHash,
+    block_height: u64,
+}
+
+impl NearRpcClient {
+    /// Resolve `block` to a concrete block and return a handle pinned to it.
+    pub async fn at_block(&self, block: BlockReference) -> Result<BlockSnapshot<'_>> {
+        let request = match block {
+            BlockReference::BlockId(id) => RpcBlockRequest::BlockId(id),
+            BlockReference::Finality(finality) => RpcBlockRequest::Finality(finality),
+            BlockReference::SyncCheckpoint(checkpoint) => {
+                RpcBlockRequest::SyncCheckpoint(checkpoint)
+            }
+        };
+        let block = self.block(request).await?;
+        Ok(BlockSnapshot {
+            client: self,
+            block_hash: block.header.hash,
+            block_height: block.header.height,
+        })
+    }
+
+    /// Return a handle pinned to the latest final block.
+    pub async fn snapshot(&self) -> Result<BlockSnapshot<'_>> {
+        self.at_block(BlockReference::Finality(Finality::Final))
+            .await
+    }
+}
+
+impl BlockSnapshot<'_> {
+    /// Hash of the pinned block
+    pub fn block_hash(&self) -> &CryptoHash {
+        &self.block_hash
+    }
+
+    /// Height of the pinned block
+    pub fn block_height(&self) -> u64 {
+        self.block_height
+    }
+
+    fn block_id(&self) -> BlockId {
+        BlockId::CryptoHash(self.block_hash.clone())
+    }
+
+    fn pinned<T>(&self, value: T) -> Pinned<T> {
+        Pinned {
+            block_hash: self.block_hash.clone(),
+            block_height: self.block_height,
+            value,
+        }
+    }
+
+    async fn call<P: Serialize, R: for<'de> serde::Deserialize<'de>>(
+        &self,
+        method: &'static str,
+        params: P,
+    ) -> Result<Pinned<R>> {
+        let params = pin_params(params, &self.block_hash)?;
+        let value = self.client.call(method, params).await?;
+        Ok(self.pinned(value))
+    }
+
+    // ==================== Block Methods ====================
+
+    /// Returns the pinned block.
+    pub async fn block(&self) -> Result<Pinned<RpcBlockResponse>> {
+        let block = self
+            .client
+            .block(RpcBlockRequest::BlockId(self.block_id()))
+            .await?;
+        Ok(self.pinned(block))
+    }
+
+    /// Returns a chunk of the pinned block.
+    pub async fn chunk(&self, shard_id: ShardId) -> Result<Pinned<RpcChunkResponse>> {
+        let request = RpcChunkRequest::BlockShardId {
+            block_id: self.block_id(),
+            shard_id,
+        };
+        let chunk = self.client.chunk(request).await?;
+        Ok(self.pinned(chunk))
+    }
+
+    /// Returns gas price at the pinned block.
+    pub async fn gas_price(&self) -> Result<Pinned<RpcGasPriceResponse>> {
+        let request = RpcGasPriceRequest {
+            block_id: Some(self.block_id()),
+        };
+        let gas_price = self.client.gas_price(request).await?;
+        Ok(self.pinned(gas_price))
+    }
+
+    // ==================== Account/State Query Methods ====================
+
+    /// Query the blockchain state at the pinned block.
+    pub async fn query(&self, request: RpcQueryRequest) -> Result<Pinned<RpcQueryResponse>> {
+        self.call("query", request).await
+    }
+
+    /// View an account at the pinned block.
+    pub async fn view_account(
+        &self,
+        account_id: impl Into<String>,
+    ) -> Result<Pinned<RpcQueryResponse>> {
+        self.query(RpcQueryRequest::ViewAccountBlockId {
+            account_id: AccountId(account_id.into()),
+            block_id: self.block_id(),
+            request_type: "view_account".to_string(),
+        })
+        .await
+    }
+
+    /// View an access key at the pinned block.
+    pub async fn view_access_key(
+        &self,
+        account_id: impl Into<String>,
+        public_key: impl Into<String>,
+    ) -> Result<Pinned<RpcQueryResponse>> {
+        self.query(RpcQueryRequest::ViewAccessKeyBlockId {
+            account_id: AccountId(account_id.into()),
+            block_id: self.block_id(),
+            public_key: PublicKey(public_key.into()),
+            request_type: "view_access_key".to_string(),
+        })
+        .await
+    }
+
+    /// Call a view function at the pinned block. `args_base64` is the base64-encoded argument bytes.
+    pub async fn call_function(
+        &self,
+        account_id: impl Into<String>,
+        method_name: impl Into<String>,
+        args_base64: impl Into<String>,
+    ) -> Result<Pinned<RpcQueryResponse>> {
+        self.query(RpcQueryRequest::CallFunctionBlockId {
+            account_id: AccountId(account_id.into()),
+            args_base64: FunctionArgs(args_base64.into()),
+            block_id: self.block_id(),
+            method_name: method_name.into(),
+            request_type: "call_function".to_string(),
+        })
+        .await
+    }
+
+    // ==================== State Changes Methods ====================
+
+    /// Returns state changes of the given kinds in the pinned block.
+    pub async fn changes(
+        &self,
+        request: RpcStateChangesInBlockByTypeRequest,
+    ) -> Result<Pinned<RpcStateChangesInBlockResponse>> {
+        self.call("EXPERIMENTAL_changes", request).await
+    }
+
+    /// Returns all changes in the pinned block.
+    pub async fn changes_in_block(&self) -> Result<Pinned<RpcStateChangesInBlockByTypeResponse>> {
+        let changes = self
+            .client
+            .changes_in_block(RpcStateChangesInBlockRequest::BlockId(self.block_id()))
+            .await?;
+        Ok(self.pinned(changes))
+    }
+
+    // ==================== Config Methods ====================
+
+    /// Returns protocol configuration at the pinned block.
+    pub async fn protocol_config(&self) -> Result<Pinned<RpcProtocolConfigResponse>> {
+        let config = self
+            .client
+            .protocol_config(RpcProtocolConfigRequest::BlockId(self.block_id()))
+            .await?;
+        Ok(self.pinned(config))
+    }
+}
+
+/// Replace whatever block reference `params` carry with `block_id: <block_hash>`.
+fn pin_params<P: Serialize>(params: P, block_hash: &CryptoHash) -> Result<Value> {
+    let mut params = serde_json::to_value(params)?;
+    if let Value::Object(map) = &mut params {
+        map.remove("finality");
+        map.remove("sync_checkpoint");
+        map.insert("block_id".to_string(), Value::String(block_hash.0.clone()));
+    }
+    Ok(params)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pin_params() {
+        let hash = CryptoHash("6RWmTYhXCzjMjoY3Mz1rfFcnBm8E6XeDDbFEPUA4sv1w".to_string());
+        let request = RpcQueryRequest::ViewAccountFinality {
+            account_id: AccountId("alice.near".to_string()),
+            finality: Finality::Optimistic,
+            request_type: "view_account".to_string(),
+        };
+        assert_eq!(
+            pin_params(request, &hash).unwrap(),
+            serde_json::json!({
+                "account_id": "alice.near",
+                "block_id": "6RWmTYhXCzjMjoY3Mz1rfFcnBm8E6XeDDbFEPUA4sv1w",
+                "request_type": "view_account",
+            })
+        );
+    }
+
+    #[cfg(feature = "testing")]
+    #[tokio::test]
+    async fn test_requests_pinned_to_one_block() {
+        use crate::testing::chain::{public_key, FakeChain, NEAR};
+        use crate::testing::MockServer;
+
+        let chain = FakeChain::new();
+        let key = public_key(&[1; 32]);
+        chain.add_account("alice.test", NEAR, key.clone());
+        chain.add_account("bob.test", NEAR, key.clone());
+        let server = MockServer::start().await;
+        for method in ["block", "gas_price", "query"] {
+            let chain = chain.clone();
+            server
+                .mock(method)
+                .handle(move |params| chain.handle(method, params));
+        }
+
+        let client = server.client();
+        let snapshot = client.snapshot().await.unwrap();
+        assert_eq!(snapshot.block_hash(), &chain.head_hash());
+        // New blocks must not move the snapshot.
+        chain.advance_blocks(3);
+        let alice = snapshot.view_account("alice.test").await.unwrap();
+        chain.advance_blocks(3);
+        let bob = snapshot.view_account("bob.test").await.unwrap();
+        let access_key = snapshot.view_access_key("alice.test", key.0).await.unwrap();
+        let gas_price = snapshot.gas_price().await.unwrap();
+        let block = snapshot.block().await.unwrap();
+        assert_eq!(block.header.hash, *snapshot.block_hash());
+        for height in [
+            alice.block_height,
+            bob.block_height,
+            access_key.block_height,
+            gas_price.block_height,
+        ] {
+            assert_eq!(height, snapshot.block_height());
+        }
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 6);
+        assert_eq!(
+            requests[0].params,
+            serde_json::json!({ "finality": "final" })
+        );
+        for request in &requests[1..] {
+            assert_eq!(
+                request.params["block_id"],
+                snapshot.block_hash().0,
+                "{} was not pinned: {}",
+                request.method,
+                request.params
+            );
+            assert!(request.params.get("finality").is_none());
+        }
+    }
+}
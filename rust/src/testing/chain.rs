@@ -0,0 +1,1439 @@
+//! In-process fake chain for integration tests.
+//!
+//! [`FakeChain`] holds accounts with balances and access keys, accepts signed transactions and
+//! answers the RPC methods below with the crate's generated types:
+//! - `block` and `gas_price`
+//! - `query` with `view_account`, `view_access_key`, `view_access_key_list` and
+//!   `call_function`, at the head or at any earlier block
+//! - `send_tx`, `broadcast_tx_commit`, `broadcast_tx_async`, `tx` and `EXPERIMENTAL_tx_status`
+//!
+//! Transactions may contain `CreateAccount`, `DeployContract`, `FunctionCall`, `Transfer`,
+//! `AddKey`, `DeleteKey` and `DeleteAccount` actions. Each accepted transaction is executed at
+//! once and included in a new, immediately final block. No gas or fees are charged and
+//! contracts are not executed: function calls and view calls are answered by closures
+//! registered with [`FakeChain::on_function_call`].
+//!
+//! ```no_run
+//! use near_rpc_client::testing::chain::{public_key, Action, FakeChain, Transaction, NEAR};
+//! use near_rpc_client::types::*;
+//!
+//! # async fn example() -> near_rpc_client::client::Result<()> {
+//! let secret_key = [1; 32];
+//! let chain = FakeChain::new();
+//! chain.add_account("alice.test", 100 * NEAR, public_key(&secret_key));
+//!
+//! let client = chain.client();
+//! let transaction = Transaction {
+//!     signer_id: "alice.test".to_string(),
+//!     public_key: public_key(&secret_key),
+//!     nonce: 1,
+//!     receiver_id: "bob.alice.test".to_string(),
+//!     block_hash: chain.head_hash(),
+//!     actions: vec![Action::CreateAccount, Action::Transfer { deposit: NEAR }],
+//! };
+//! client
+//!     .send_tx(RpcSendTransactionRequest {
+//!         signed_tx_base64: transaction.sign(&secret_key),
+//!         wait_until: TxExecutionStatus::Final,
+//!     })
+//!     .await?;
+//! assert_eq!(chain.balance("bob.alice.test"), Some(NEAR));
+//! # Ok(())
+//! # }
+//! ```
+
+use super::MockServer;
+use crate::client::{self, RpcError};
+use crate::light_client::borsh::{decode_hash, encode_hash, sha256, Hash, Reader, Writer};
+use crate::light_client::sync::{decode_key, verify_signature};
+use crate::service::RpcCall;
+use crate::types::*;
+use crate::NearRpcClient;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use ed25519_dalek::{Signer, SigningKey};
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use tower::Service;
+
+/// One NEAR in yoctoNEAR
+pub const NEAR: u128 = 1_000_000_000_000_000_000_000_000;
+
+const GAS_PRICE: u128 = 100_000_000;
+const PROTOCOL_VERSION: u32 = 73;
+const GENESIS_TIMESTAMP: u64 = 1_700_000_000_000_000_000;
+const BLOCK_TIME: u64 = 1_000_000_000;
+/// Nonces of new access keys start at `block height * ACCESS_KEY_NONCE_RANGE`, as in nearcore
+const ACCESS_KEY_NONCE_RANGE: u64 = 1_000_000;
+const EMPTY_HASH: Hash = [0; 32];
+
+/// An action of a [`Transaction`]
+#[derive(Clone, Debug, PartialEq)]
+pub enum Action {
+    CreateAccount,
+    DeployContract {
+        code: Vec<u8>,
+    },
+    FunctionCall {
+        method_name: String,
+        args: Vec<u8>,
+        gas: u64,
+        deposit: u128,
+    },
+    Transfer {
+        deposit: u128,
+    },
+    AddKey {
+        public_key: PublicKey,
+        permission: AccessKeyPermission,
+    },
+    DeleteKey {
+        public_key: PublicKey,
+    },
+    DeleteAccount {
+        beneficiary_id: String,
+    },
+}
+
+/// Permission of an access key added with [`Action::AddKey`]
+#[derive(Clone, Debug, PartialEq)]
+pub enum AccessKeyPermission {
+    FullAccess,
+    FunctionCall {
+        allowance: Option<u128>,
+        receiver_id: String,
+        method_names: Vec<String>,
+    },
+}
+
+/// An unsigned transaction
+#[derive(Clone, Debug, PartialEq)]
+pub struct Transaction {
+    pub signer_id: String,
+    pub public_key: PublicKey,
+    pub nonce: u64,
+    pub receiver_id: String,
+    pub block_hash: CryptoHash,
+    pub actions: Vec<Action>,
+}
+
+/// A function call passed to a handler registered with [`FakeChain::on_function_call`]
+#[derive(Clone, Debug)]
+pub struct FunctionCall {
+    pub account_id: String,
+    pub method_name: String,
+    pub args: Vec<u8>,
+    /// Signer of the transaction, or `None` for a view call
+    pub signer_id: Option<String>,
+    pub deposit: u128,
+}
+
+/// Public key of the ed25519 `secret_key`
+pub fn public_key(secret_key: &[u8; 32]) -> PublicKey {
+    let key = SigningKey::from_bytes(secret_key).verifying_key();
+    PublicKey(format!(
+        "ed25519:{}",
+        bs58::encode(key.as_bytes()).into_string()
+    ))
+}
+
+impl Transaction {
+    /// Borsh encoding of the transaction, as hashed and signed
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut w = Writer::default();
+        w.bytes(self.signer_id.as_bytes());
+        write_public_key(&mut w, &self.public_key);
+        w.u64(self.nonce)
+            .bytes(self.receiver_id.as_bytes())
+            .hash(&decode_hash(&self.block_hash).expect("valid block hash"))
+            .u32(self.actions.len() as u32);
+        for action in &self.actions {
+            write_action(&mut w, action);
+        }
+        w.finish().to_vec()
+    }
+
+    /// Hash of the transaction
+    pub fn hash(&self) -> CryptoHash {
+        encode_hash(&sha256(&self.to_bytes()))
+    }
+
+    /// Sign with the ed25519 `secret_key` and encode for `send_tx`
+    pub fn sign(&self, secret_key: &[u8; 32]) -> SignedTransaction {
+        let mut bytes = self.to_bytes();
+        let signature = SigningKey::from_bytes(secret_key).sign(&sha256(&bytes));
+        bytes.push(0);
+        bytes.extend_from_slice(&signature.to_bytes());
+        SignedTransaction(BASE64.encode(bytes))
+    }
+}
+
+fn write_public_key(w: &mut Writer, key: &PublicKey) {
+    let (key_type, bytes) = decode_key(&key.0).expect("valid public key");
+    w.u8(key_type).raw(&bytes);
+}
+
+fn write_action(w: &mut Writer, action: &Action) {
+    match action {
+        Action::CreateAccount => {
+            w.u8(0);
+        }
+        Action::DeployContract { code } => {
+            w.u8(1).bytes(code);
+        }
+        Action::FunctionCall {
+            method_name,
+            args,
+            gas,
+            deposit,
+        } => {
+            w.u8(2)
+                .bytes(method_name.as_bytes())
+                .bytes(args)
+                .u64(*gas)
+                .u128(*deposit);
+        }
+        Action::Transfer { deposit } => {
+            w.u8(3).u128(*deposit);
+        }
+        Action::AddKey {
+            public_key,
+            permission,
+        } => {
+            w.u8(5);
+            write_public_key(w, public_key);
+            w.u64(0);
+            match permission {
+                AccessKeyPermission::FullAccess => {
+                    w.u8(1);
+                }
+                AccessKeyPermission::FunctionCall {
+                    allowance,
+                    receiver_id,
+                    method_names,
+                } => {
+                    w.u8(0);
+                    match allowance {
+                        Some(allowance) => w.u8(1).u128(*allowance),
+                        None => w.u8(0),
+                    };
+                    w.bytes(receiver_id.as_bytes())
+                        .u32(method_names.len() as u32);
+                    for method_name in method_names {
+                        w.bytes(method_name.as_bytes());
+                    }
+                }
+            }
+        }
+        Action::DeleteKey { public_key } => {
+            w.u8(6);
+            write_public_key(w, public_key);
+        }
+        Action::DeleteAccount { beneficiary_id } => {
+            w.u8(7).bytes(beneficiary_id.as_bytes());
+        }
+    }
+}
+
+/// Decode a Borsh-encoded signed transaction into the transaction, its hash and signature.
+fn decode_signed_transaction(bytes: &[u8]) -> Option<(Transaction, Hash, Signature)> {
+    let mut r = Reader::new(bytes);
+    let transaction = Transaction {
+        signer_id: r.string()?,
+        public_key: read_public_key(&mut r)?,
+        nonce: r.u64()?,
+        receiver_id: r.string()?,
+        block_hash: encode_hash(&r.hash()?),
+        actions: (0..r.u32()?)
+            .map(|_| read_action(&mut r))
+            .collect::<Option<_>>()?,
+    };
+    let hash = sha256(&bytes[..bytes.len() - r.len()]);
+    let signature = match r.u8()? {
+        0 => format!("ed25519:{}", bs58::encode(r.raw(64)?).into_string()),
+        1 => format!("secp256k1:{}", bs58::encode(r.raw(65)?).into_string()),
+        _ => return None,
+    };
+    r.is_empty()
+        .then_some((transaction, hash, Signature(signature)))
+}
+
+fn read_public_key(r: &mut Reader) -> Option<PublicKey> {
+    let key = match r.u8()? {
+        0 => format!("ed25519:{}", bs58::encode(r.raw(32)?).into_string()),
+        1 => format!("secp256k1:{}", bs58::encode(r.raw(64)?).into_string()),
+        _ => return None,
+    };
+    Some(PublicKey(key))
+}
+
+fn read_action(r: &mut Reader) -> Option<Action> {
+    let action = match r.u8()? {
+        0 => Action::CreateAccount,
+        1 => Action::DeployContract {
+            code: r.bytes()?.to_vec(),
+        },
+        2 => Action::FunctionCall {
+            method_name: r.string()?,
+            args: r.bytes()?.to_vec(),
+            gas: r.u64()?,
+            deposit: r.u128()?,
+        },
+        3 => Action::Transfer { deposit: r.u128()? },
+        5 => {
+            let public_key = read_public_key(r)?;
+            let _nonce = r.u64()?;
+            let permission = match r.u8()? {
+                0 => AccessKeyPermission::FunctionCall {
+                    allowance: match r.u8()? {
+                        0 => None,
+                        1 => Some(r.u128()?),
+                        _ => return None,
+                    },
+                    receiver_id: r.string()?,
+                    method_names: (0..r.u32()?).map(|_| r.string()).collect::<Option<_>>()?,
+                },
+                1 => AccessKeyPermission::FullAccess,
+                _ => return None,
+            };
+            Action::AddKey {
+                public_key,
+                permission,
+            }
+        }
+        6 => Action::DeleteKey {
+            public_key: read_public_key(r)?,
+        },
+        7 => Action::DeleteAccount {
+            beneficiary_id: r.string()?,
+        },
+        _ => return None,
+    };
+    Some(action)
+}
+
+type FunctionHandler = dyn Fn(&FunctionCall) -> Result<Vec<u8>, String> + Send + Sync;
+
+type Accounts = BTreeMap<String, Account>;
+
+#[derive(Clone)]
+struct Account {
+    amount: u128,
+    code_hash: Hash,
+    keys: BTreeMap<String, AccessKeyView>,
+}
+
+impl Account {
+    fn new(amount: u128) -> Self {
+        Self {
+            amount,
+            code_hash: EMPTY_HASH,
+            keys: BTreeMap::new(),
+        }
+    }
+}
+
+struct Block {
+    height: u64,
+    hash: Hash,
+    prev_hash: Hash,
+    accounts: Arc<Accounts>,
+}
+
+struct Chain {
+    blocks: Vec<Block>,
+    transactions: HashMap<Hash, RpcTransactionResponse>,
+    functions: HashMap<(String, String), Arc<FunctionHandler>>,
+}
+
+/// Stateful in-memory chain answering RPC calls.
+///
+/// Clones share the same chain. Use [`client`](Self::client) for a client backed by it, or
+/// [`handle`](Self::handle) to serve it from a [`MockServer`].
+#[derive(Clone)]
+pub struct FakeChain {
+    chain: Arc<Mutex<Chain>>,
+}
+
+impl Default for FakeChain {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FakeChain {
+    /// Create a chain with an empty genesis block at height 1
+    pub fn new() -> Self {
+        let mut chain = Chain {
+            blocks: Vec::new(),
+            transactions: HashMap::new(),
+            functions: HashMap::new(),
+        };
+        chain.push_block(Accounts::new(), &[]);
+        Self {
+            chain: Arc::new(Mutex::new(chain)),
+        }
+    }
+
+    /// Add an account with a full access key to the head block
+    pub fn add_account(&self, account_id: impl Into<String>, amount: u128, public_key: PublicKey) {
+        let mut chain = self.chain.lock().unwrap();
+        let accounts = Arc::make_mut(&mut chain.head_mut().accounts);
+        let mut account = Account::new(amount);
+        account.keys.insert(
+            public_key.0,
+            AccessKeyView {
+                nonce: 0,
+                permission: AccessKeyPermissionView::FullAccess,
+            },
+        );
+        accounts.insert(account_id.into(), account);
+    }
+
+    /// Answer function calls and view calls of `method_name` on `account_id` with `handler`.
+    ///
+    /// The handler returns the call's return value or an error message. It runs while the
+    /// chain is locked and must not call back into the chain.
+    pub fn on_function_call<F>(
+        &self,
+        account_id: impl Into<String>,
+        method_name: impl Into<String>,
+        handler: F,
+    ) where
+        F: Fn(&FunctionCall) -> Result<Vec<u8>, String> + Send + Sync + 'static,
+    {
+        self.chain
+            .lock()
+            .unwrap()
+            .functions
+            .insert((account_id.into(), method_name.into()), Arc::new(handler));
+    }
+
+    /// Produce `count` empty blocks
+    pub fn advance_blocks(&self, count: u64) {
+        let mut chain = self.chain.lock().unwrap();
+        for _ in 0..count {
+            let accounts = (*chain.head().accounts).clone();
+            chain.push_block(accounts, &[]);
+        }
+    }
+
+    /// Height of the latest block
+    pub fn head_height(&self) -> u64 {
+        self.chain.lock().unwrap().head().height
+    }
+
+    /// Hash of the latest block
+    pub fn head_hash(&self) -> CryptoHash {
+        encode_hash(&self.chain.lock().unwrap().head().hash)
+    }
+
+    /// Balance of `account_id` at the latest block
+    pub fn balance(&self, account_id: &str) -> Option<u128> {
+        let chain = self.chain.lock().unwrap();
+        chain.head().accounts.get(account_id).map(|a| a.amount)
+    }
+
+    /// Create a client backed by this chain
+    pub fn client(&self) -> NearRpcClient {
+        NearRpcClient::from_service(self.clone())
+    }
+
+    /// Answer a JSON-RPC call
+    pub fn handle(&self, method: &str, params: &Value) -> Result<Value, RpcError> {
+        let mut chain = self.chain.lock().unwrap();
+        match method {
+            "block" => to_value(chain.block(chain.resolve_block(params)?)),
+            "gas_price" => {
+                chain.resolve_block(params)?;
+                to_value(RpcGasPriceResponse {
+                    gas_price: NearToken(GAS_PRICE.to_string()),
+                })
+            }
+            "query" => chain.query(params),
+            "broadcast_tx_async" => {
+                let hash = chain.submit(params)?;
+                to_value(encode_hash(&hash))
+            }
+            "send_tx" if params.get("wait_until") == Some(&json!("NONE")) => {
+                chain.submit(params)?;
+                to_value(RpcTransactionResponse::Empty {
+                    final_execution_status: TxExecutionStatus::None,
+                })
+            }
+            "send_tx" | "broadcast_tx_commit" => {
+                let hash = chain.submit(params)?;
+                to_value(&chain.transactions[&hash])
+            }
+            "tx" | "EXPERIMENTAL_tx_status" => chain.transaction(params),
+            _ => Err(RpcError::method_not_found(method)),
+        }
+    }
+}
+
+impl Service<RpcCall> for FakeChain {
+    type Response = Value;
+    type Error = client::Error;
+    type Future = std::future::Ready<client::Result<Value>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<client::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, call: RpcCall) -> Self::Future {
+        std::future::ready(
+            self.handle(&call.method, &call.params)
+                .map_err(client::Error::Rpc),
+        )
+    }
+}
+
+impl Chain {
+    fn head(&self) -> &Block {
+        self.blocks.last().expect("chain has a genesis block")
+    }
+
+    fn head_mut(&mut self) -> &mut Block {
+        self.blocks.last_mut().expect("chain has a genesis block")
+    }
+
+    fn push_block(&mut self, accounts: Accounts, transactions: &[Hash]) -> Hash {
+        let (height, prev_hash) = match self.blocks.last() {
+            Some(head) => (head.height + 1, head.hash),
+            None => (1, EMPTY_HASH),
+        };
+        let mut w = Writer::default();
+        w.u64(height).hash(&prev_hash);
+        for transaction in transactions {
+            w.hash(transaction);
+        }
+        let hash = sha256(w.finish());
+        self.blocks.push(Block {
+            height,
+            hash,
+            prev_hash,
+            accounts: Arc::new(accounts),
+        });
+        hash
+    }
+
+    /// Block referenced by `block_id` in `params`, or the head for any finality
+    fn resolve_block(&self, params: &Value) -> Result<&Block, RpcError> {
+        let block = match params.get("block_id") {
+            None | Some(Value::Null) => Some(self.head()),
+            Some(Value::Number(height)) => {
+                let height = height.as_u64();
+                self.blocks.iter().find(|b| Some(b.height) == height)
+            }
+            Some(Value::String(hash)) => {
+                self.blocks.iter().find(|b| encode_hash(&b.hash).0 == *hash)
+            }
+            Some(_) => None,
+        };
+        block.ok_or_else(|| {
+            MockServer::handler_error(
+                "UNKNOWN_BLOCK",
+                json!({ "block_reference": { "block_id": params["block_id"] } }),
+            )
+        })
+    }
+
+    fn block(&self, block: &Block) -> RpcBlockResponse {
+        let zero = || encode_hash(&EMPTY_HASH);
+        let timestamp = GENESIS_TIMESTAMP + (block.height - 1) * BLOCK_TIME;
+        let total_supply =
+            (block.accounts.values()).fold(0u128, |sum, a| sum.saturating_add(a.amount));
+        RpcBlockResponse {
+            author: AccountId("test.near".to_string()),
+            chunks: Vec::new(),
+            header: BlockHeaderView {
+                approvals: Vec::new(),
+                block_body_hash: None,
+                block_merkle_root: zero(),
+                block_ordinal: Some(block.height),
+                challenges_result: Vec::new(),
+                challenges_root: zero(),
+                chunk_endorsements: None,
+                chunk_headers_root: zero(),
+                chunk_mask: Vec::new(),
+                chunk_receipts_root: zero(),
+                chunk_tx_root: zero(),
+                chunks_included: 0,
+                epoch_id: zero(),
+                epoch_sync_data_hash: None,
+                gas_price: NearToken(GAS_PRICE.to_string()),
+                hash: encode_hash(&block.hash),
+                height: block.height,
+                last_ds_final_block: encode_hash(&block.hash),
+                last_final_block: encode_hash(&block.hash),
+                latest_protocol_version: PROTOCOL_VERSION,
+                next_bp_hash: zero(),
+                next_epoch_id: zero(),
+                outcome_root: zero(),
+                prev_hash: encode_hash(&block.prev_hash),
+                prev_height: block.height.checked_sub(1).filter(|h| *h > 0),
+                prev_state_root: zero(),
+                random_value: zero(),
+                rent_paid: NearToken("0".to_string()),
+                shard_split: None,
+                signature: Signature(format!("ed25519:{}", bs58::encode([0; 64]).into_string())),
+                timestamp,
+                timestamp_nanosec: timestamp.to_string(),
+                total_supply: NearToken(total_supply.to_string()),
+                validator_proposals: Vec::new(),
+                validator_reward: NearToken("0".to_string()),
+            },
+        }
+    }
+
+    fn query(&self, params: &Value) -> Result<Value, RpcError> {
+        let block = self.resolve_block(params)?;
+        let block_hash = encode_hash(&block.hash);
+        let block_height = block.height;
+        let field = |name: &str| params.get(name).and_then(Value::as_str).unwrap_or_default();
+        let account_id = field("account_id");
+        let Some(account) = block.accounts.get(account_id) else {
+            return Err(MockServer::handler_error(
+                "UNKNOWN_ACCOUNT",
+                json!({
+                    "requested_account_id": account_id,
+                    "block_height": block_height,
+                    "block_hash": block_hash,
+                }),
+            ));
+        };
+        let response = match field("request_type") {
+            "view_account" => RpcQueryResponse::AccountView {
+                amount: NearToken(account.amount.to_string()),
+                block_hash,
+                block_height,
+                code_hash: encode_hash(&account.code_hash),
+                global_contract_account_id: None,
+                global_contract_hash: None,
+                locked: NearToken("0".to_string()),
+                storage_paid_at: 0,
+                storage_usage: 100 + 82 * account.keys.len() as u64,
+            },
+            "view_access_key" => {
+                let public_key = field("public_key");
+                let Some(key) = account.keys.get(public_key) else {
+                    return Err(MockServer::handler_error(
+                        "UNKNOWN_ACCESS_KEY",
+                        json!({
+                            "public_key": public_key,
+                            "block_height": block_height,
+                            "block_hash": block_hash,
+                        }),
+                    ));
+                };
+                RpcQueryResponse::AccessKeyView {
+                    block_hash,
+                    block_height,
+                    nonce: key.nonce,
+                    permission: key.permission.clone(),
+                }
+            }
+            "view_access_key_list" => RpcQueryResponse::AccessKeyList {
+                block_hash,
+                block_height,
+                keys: account
+                    .keys
+                    .iter()
+                    .map(|(public_key, access_key)| AccessKeyInfoView {
+                        access_key: access_key.clone(),
+                        public_key: PublicKey(public_key.clone()),
+                    })
+                    .collect(),
+            },
+            "call_function" => {
+                let call = FunctionCall {
+                    account_id: account_id.to_string(),
+                    method_name: field("method_name").to_string(),
+                    args: BASE64.decode(field("args_base64")).unwrap_or_default(),
+                    signer_id: None,
+                    deposit: 0,
+                };
+                let result = match self.function(&call.account_id, &call.method_name) {
+                    Some(handler) => handler(&call),
+                    None => Err(
+                        "wasm execution failed with error: MethodResolveError(MethodNotFound)"
+                            .to_string(),
+                    ),
+                };
+                match result {
+                    Ok(result) => RpcQueryResponse::CallResult {
+                        block_hash,
+                        block_height,
+                        logs: Vec::new(),
+                        result,
+                    },
+                    Err(error) => {
+                        return Err(MockServer::handler_error(
+                            "CONTRACT_EXECUTION_ERROR",
+                            json!({
+                                "vm_error": error,
+                                "block_height": block_height,
+                                "block_hash": block_hash,
+                            }),
+                        ))
+                    }
+                }
+            }
+            request_type => {
+                return Err(RpcError::parse_error(format!(
+                    "unsupported request type `{request_type}`"
+                )))
+            }
+        };
+        to_value(response)
+    }
+
+    fn function(&self, account_id: &str, method_name: &str) -> Option<Arc<FunctionHandler>> {
+        self.functions
+            .get(&(account_id.to_string(), method_name.to_string()))
+            .cloned()
+    }
+
+    fn transaction(&self, params: &Value) -> Result<Value, RpcError> {
+        let hash = match params.get("tx_hash").and_then(Value::as_str) {
+            Some(hash) => decode_hash(&CryptoHash(hash.to_string()))
+                .map_err(|error| RpcError::parse_error(error.to_string()))?,
+            None => {
+                decode_signed_transaction(&signed_transaction_bytes(params)?)
+                    .ok_or_else(|| RpcError::parse_error("invalid signed transaction"))?
+                    .1
+            }
+        };
+        match self.transactions.get(&hash) {
+            Some(outcome) => to_value(outcome),
+            None => Err(MockServer::handler_error(
+                "UNKNOWN_TRANSACTION",
+                json!({ "requested_transaction_hash": encode_hash(&hash) }),
+            )),
+        }
+    }
+
+    /// Validate and execute a signed transaction, including it in a new block.
+    fn submit(&mut self, params: &Value) -> Result<Hash, RpcError> {
+        let bytes = signed_transaction_bytes(params)?;
+        let (transaction, hash, signature) = decode_signed_transaction(&bytes)
+            .ok_or_else(|| RpcError::parse_error("invalid signed transaction"))?;
+        if self.transactions.contains_key(&hash) {
+            return Ok(hash);
+        }
+
+        let mut accounts = (*self.head().accounts).clone();
+        self.validate(&accounts, &transaction, &hash, &signature)
+            .map_err(invalid_transaction)?;
+        let signer = accounts.get_mut(&transaction.signer_id).unwrap();
+        signer
+            .keys
+            .get_mut(&transaction.public_key.0)
+            .unwrap()
+            .nonce = transaction.nonce;
+
+        let height = self.head().height + 1;
+        let mut executed = accounts.clone();
+        let result = match self.execute(&mut executed, &transaction, height) {
+            Ok(value) => {
+                accounts = executed;
+                Ok(value)
+            }
+            Err(Failure::Action(error)) => Err(error),
+            Err(Failure::Invalid(error)) => return Err(invalid_transaction(error)),
+        };
+        let block_hash = self.push_block(accounts, &[hash]);
+        let outcome = outcome(&transaction, &hash, &signature, &block_hash, result);
+        self.transactions.insert(hash, outcome);
+        Ok(hash)
+    }
+
+    fn validate(
+        &self,
+        accounts: &Accounts,
+        transaction: &Transaction,
+        hash: &Hash,
+        signature: &Signature,
+    ) -> Result<(), InvalidTxError> {
+        let signer_id = AccountId(transaction.signer_id.clone());
+        let Some(signer) = accounts.get(&transaction.signer_id) else {
+            return Err(InvalidTxError::SignerDoesNotExist { signer_id });
+        };
+        let Some(key) = signer.keys.get(&transaction.public_key.0) else {
+            return Err(InvalidTxError::InvalidAccessKeyError(
+                InvalidAccessKeyError::AccessKeyNotFound {
+                    account_id: signer_id,
+                    public_key: transaction.public_key.clone(),
+                },
+            ));
+        };
+        if !verify_signature(&transaction.public_key, signature, hash).unwrap_or(false) {
+            return Err(InvalidTxError::InvalidSignature);
+        }
+        if transaction.nonce <= key.nonce {
+            return Err(InvalidTxError::InvalidNonce {
+                ak_nonce: key.nonce,
+                tx_nonce: transaction.nonce,
+            });
+        }
+        let known_block = self
+            .blocks
+            .iter()
+            .any(|b| encode_hash(&b.hash) == transaction.block_hash);
+        if !known_block {
+            return Err(InvalidTxError::Expired);
+        }
+        if let AccessKeyPermissionView::FunctionCall {
+            receiver_id,
+            method_names,
+            ..
+        } = &key.permission
+        {
+            let access_key_error = InvalidTxError::InvalidAccessKeyError;
+            let [Action::FunctionCall {
+                method_name,
+                deposit,
+                ..
+            }] = transaction.actions.as_slice()
+            else {
+                return Err(access_key_error(InvalidAccessKeyError::RequiresFullAccess));
+            };
+            if *deposit > 0 {
+                return Err(access_key_error(
+                    InvalidAccessKeyError::DepositWithFunctionCall,
+                ));
+            }
+            if *receiver_id != transaction.receiver_id {
+                return Err(access_key_error(InvalidAccessKeyError::ReceiverMismatch {
+                    ak_receiver: receiver_id.clone(),
+                    tx_receiver: AccountId(transaction.receiver_id.clone()),
+                }));
+            }
+            if !method_names.is_empty() && !method_names.contains(method_name) {
+                return Err(access_key_error(
+                    InvalidAccessKeyError::MethodNameMismatch {
+                        method_name: method_name.clone(),
+                    },
+                ));
+            }
+        }
+        let cost = total_deposit(transaction)?;
+        if cost > signer.amount {
+            return Err(InvalidTxError::NotEnoughBalance {
+                balance: NearToken(signer.amount.to_string()),
+                cost: NearToken(cost.to_string()),
+                signer_id,
+            });
+        }
+        Ok(())
+    }
+
+    /// Apply the actions of `transaction`, returning the value of the last function call.
+    fn execute(
+        &self,
+        accounts: &mut Accounts,
+        transaction: &Transaction,
+        height: u64,
+    ) -> Result<Vec<u8>, Failure> {
+        let signer_id = &transaction.signer_id;
+        let receiver_id = &transaction.receiver_id;
+        accounts.get_mut(signer_id).unwrap().amount -= total_deposit(transaction)?;
+
+        let mut created = false;
+        let mut value = Vec::new();
+        for (index, action) in transaction.actions.iter().enumerate() {
+            let error = |kind| {
+                Failure::Action(ActionError {
+                    index: Some(index as u64),
+                    kind,
+                })
+            };
+            let account_id = || AccountId(receiver_id.clone());
+            if let Action::CreateAccount = action {
+                if accounts.contains_key(receiver_id) {
+                    return Err(error(ActionErrorKind::AccountAlreadyExists {
+                        account_id: account_id(),
+                    }));
+                }
+                if !receiver_id.ends_with(&format!(".{signer_id}")) {
+                    return Err(error(ActionErrorKind::CreateAccountNotAllowed {
+                        account_id: account_id(),
+                        predecessor_id: AccountId(signer_id.clone()),
+                    }));
+                }
+                accounts.insert(receiver_id.clone(), Account::new(0));
+                created = true;
+                continue;
+            }
+            let Some(account) = accounts.get_mut(receiver_id) else {
+                return Err(error(ActionErrorKind::AccountDoesNotExist {
+                    account_id: account_id(),
+                }));
+            };
+            let requires_owner = matches!(
+                action,
+                Action::DeployContract { .. }
+                    | Action::AddKey { .. }
+                    | Action::DeleteKey { .. }
+                    | Action::DeleteAccount { .. }
+            );
+            if requires_owner && !created && signer_id != receiver_id {
+                return Err(error(ActionErrorKind::ActorNoPermission {
+                    account_id: account_id(),
+                    actor_id: AccountId(signer_id.clone()),
+                }));
+            }
+            match action {
+                Action::CreateAccount => unreachable!("handled above"),
+                Action::DeployContract { code } => account.code_hash = sha256(code),
+                Action::FunctionCall {
+                    method_name,
+                    args,
+                    deposit,
+                    ..
+                } => {
+                    credit(&mut account.amount, *deposit)?;
+                    let call = FunctionCall {
+                        account_id: receiver_id.clone(),
+                        method_name: method_name.clone(),
+                        args: args.clone(),
+                        signer_id: Some(signer_id.clone()),
+                        deposit: *deposit,
+                    };
+                    let result = match self.function(receiver_id, method_name) {
+                        Some(handler) => handler(&call).map_err(FunctionCallError::ExecutionError),
+                        None => Err(FunctionCallError::MethodResolveError(
+                            MethodResolveError::MethodNotFound,
+                        )),
+                    };
+                    value = result.map_err(|e| error(ActionErrorKind::FunctionCallError(e)))?;
+                }
+                Action::Transfer { deposit } => credit(&mut account.amount, *deposit)?,
+                Action::AddKey {
+                    public_key,
+                    permission,
+                } => {
+                    if account.keys.contains_key(&public_key.0) {
+                        return Err(error(ActionErrorKind::AddKeyAlreadyExists {
+                            account_id: account_id(),
+                            public_key: public_key.clone(),
+                        }));
+                    }
+                    let permission = match permission {
+                        AccessKeyPermission::FullAccess => AccessKeyPermissionView::FullAccess,
+                        AccessKeyPermission::FunctionCall {
+                            allowance,
+                            receiver_id,
+                            method_names,
+                        } => AccessKeyPermissionView::FunctionCall {
+                            allowance: allowance.map(|a| NearToken(a.to_string())),
+                            method_names: method_names.clone(),
+                            receiver_id: receiver_id.clone(),
+                        },
+                    };
+                    let nonce = (height - 1) * ACCESS_KEY_NONCE_RANGE;
+                    account
+                        .keys
+                        .insert(public_key.0.clone(), AccessKeyView { nonce, permission });
+                }
+                Action::DeleteKey { public_key } => {
+                    if account.keys.remove(&public_key.0).is_none() {
+                        return Err(error(ActionErrorKind::DeleteKeyDoesNotExist {
+                            account_id: account_id(),
+                            public_key: public_key.clone(),
+                        }));
+                    }
+                }
+                Action::DeleteAccount { beneficiary_id } => {
+                    let amount = accounts.remove(receiver_id).map_or(0, |a| a.amount);
+                    if let Some(beneficiary) = accounts.get_mut(beneficiary_id) {
+                        credit(&mut beneficiary.amount, amount)?;
+                    }
+                }
+            }
+        }
+        Ok(value)
+    }
+}
+
+/// Why a transaction could not be executed
+enum Failure {
+    /// An action failed: the transaction is included with a failed outcome
+    Action(ActionError),
+    /// The transaction is rejected without being included
+    Invalid(InvalidTxError),
+}
+
+impl From<InvalidTxError> for Failure {
+    fn from(error: InvalidTxError) -> Self {
+        Self::Invalid(error)
+    }
+}
+
+fn total_deposit(transaction: &Transaction) -> Result<u128, InvalidTxError> {
+    transaction
+        .actions
+        .iter()
+        .map(|action| match action {
+            Action::FunctionCall { deposit, .. } | Action::Transfer { deposit } => *deposit,
+            _ => 0,
+        })
+        .try_fold(0u128, u128::checked_add)
+        .ok_or(InvalidTxError::CostOverflow)
+}
+
+/// Add `amount` to `balance`, rejecting the transaction if it overflows
+fn credit(balance: &mut u128, amount: u128) -> Result<(), InvalidTxError> {
+    *balance = balance
+        .checked_add(amount)
+        .ok_or(InvalidTxError::CostOverflow)?;
+    Ok(())
+}
+
+fn outcome(
+    transaction: &Transaction,
+    hash: &Hash,
+    signature: &Signature,
+    block_hash: &Hash,
+    result: Result<Vec<u8>, ActionError>,
+) -> RpcTransactionResponse {
+    let receipt_id = sha256(&[hash.as_slice(), b"receipt"].concat());
+    let execution = |id: &Hash, executor_id: &str, status| ExecutionOutcomeWithIdView {
+        block_hash: encode_hash(block_hash),
+        id: encode_hash(id),
+        outcome: ExecutionOutcomeView {
+            executor_id: AccountId(executor_id.to_string()),
+            gas_burnt: NearGas(0),
+            logs: Vec::new(),
+            metadata: ExecutionMetadataView {
+                gas_profile: None,
+                version: 1,
+            },
+            receipt_ids: Vec::new(),
+            status,
+            tokens_burnt: NearToken("0".to_string()),
+        },
+        proof: Vec::new(),
+    };
+    let (status, receipt_status) = match result {
+        Ok(value) => {
+            let value = BASE64.encode(value);
+            (
+                FinalExecutionStatus::SuccessValue(value.clone()),
+                ExecutionStatusView::SuccessValue(value),
+            )
+        }
+        Err(error) => (
+            FinalExecutionStatus::Failure(TxExecutionError::ActionError(error.clone())),
+            ExecutionStatusView::Failure(TxExecutionError::ActionError(error)),
+        ),
+    };
+    let mut transaction_outcome = execution(
+        hash,
+        &transaction.signer_id,
+        ExecutionStatusView::SuccessReceiptId(encode_hash(&receipt_id)),
+    );
+    transaction_outcome.outcome.receipt_ids = vec![encode_hash(&receipt_id)];
+    RpcTransactionResponse::FinalExecutionOutcomeView {
+        final_execution_status: TxExecutionStatus::Final,
+        receipts_outcome: vec![execution(
+            &receipt_id,
+            &transaction.receiver_id,
+            receipt_status,
+        )],
+        status,
+        transaction: SignedTransactionView {
+            actions: transaction.actions.iter().map(action_view).collect(),
+            hash: encode_hash(hash),
+            nonce: transaction.nonce,
+            nonce_index: None,
+            priority_fee: 0,
+            public_key: transaction.public_key.clone(),
+            receiver_id: AccountId(transaction.receiver_id.clone()),
+            signature: signature.clone(),
+            signer_id: AccountId(transaction.signer_id.clone()),
+        },
+        transaction_outcome,
+    }
+}
+
+fn action_view(action: &Action) -> ActionView {
+    match action {
+        Action::CreateAccount => ActionView::CreateAccount,
+        Action::DeployContract { code } => ActionView::DeployContract {
+            code: BASE64.encode(sha256(code)),
+        },
+        Action::FunctionCall {
+            method_name,
+            args,
+            gas,
+            deposit,
+        } => ActionView::FunctionCall {
+            args: FunctionArgs(BASE64.encode(args)),
+            deposit: NearToken(deposit.to_string()),
+            gas: NearGas(*gas),
+            method_name: method_name.clone(),
+        },
+        Action::Transfer { deposit } => ActionView::Transfer {
+            deposit: NearToken(deposit.to_string()),
+        },
+        Action::AddKey {
+            public_key,
+            permission,
+        } => ActionView::AddKey {
+            access_key: AccessKeyView {
+                nonce: 0,
+                permission: match permission {
+                    AccessKeyPermission::FullAccess => AccessKeyPermissionView::FullAccess,
+                    AccessKeyPermission::FunctionCall {
+                        allowance,
+                        receiver_id,
+                        method_names,
+                    } => AccessKeyPermissionView::FunctionCall {
+                        allowance: allowance.map(|a| NearToken(a.to_string())),
+                        method_names: method_names.clone(),
+                        receiver_id: receiver_id.clone(),
+                    },
+                },
+            },
+            public_key: public_key.clone(),
+        },
+        Action::DeleteKey { public_key } => ActionView::DeleteKey {
+            public_key: public_key.clone(),
+        },
+        Action::DeleteAccount { beneficiary_id } => ActionView::DeleteAccount {
+            beneficiary_id: AccountId(beneficiary_id.clone()),
+        },
+    }
+}
+
+/// Signed transaction bytes from `{ "signed_tx_base64": .. }` or `[..]` params
+fn signed_transaction_bytes(params: &Value) -> Result<Vec<u8>, RpcError> {
+    let encoded = params
+        .get("signed_tx_base64")
+        .or_else(|| params.get(0))
+        .and_then(Value::as_str)
+        .ok_or_else(|| RpcError::parse_error("missing signed_tx_base64"))?;
+    BASE64
+        .decode(encoded)
+        .map_err(|error| RpcError::parse_error(error.to_string()))
+}
+
+fn invalid_transaction(error: InvalidTxError) -> RpcError {
+    let error = json!({ "TxExecutionError": TxExecutionError::InvalidTxError(error) });
+    let mut rpc_error = MockServer::handler_error("INVALID_TRANSACTION", error.clone());
+    rpc_error.data = Some(error);
+    rpc_error
+}
+
+fn to_value<T: serde::Serialize>(value: T) -> Result<Value, RpcError> {
+    Ok(serde_json::to_value(value).expect("generated types serialize to JSON"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::Error;
+
+    fn secret_key(secret_key: &str) -> [u8; 32] {
+        let bytes = bs58::decode(secret_key.trim_start_matches("ed25519:"))
+            .into_vec()
+            .unwrap();
+        bytes[..32].try_into().unwrap()
+    }
+
+    #[test]
+    fn test_transaction_encoding() {
+        // Signed with nearcore's near-primitives 0.17 from the same transaction.
+        let alice = secret_key("ed25519:2wyRcSwSKyifRPmYUycQEKQpsQELbazyVeJiGLW8nAHbYmUc86iygj9LQFiwAufcyWkKPBShLWgcMQTAqdUTSLV4");
+        let bob = PublicKey("ed25519:ErqnuHteocUcYYsPovaxjk55k3yZNUzwKNQyHFeuCYQe".to_string());
+        let transaction = Transaction {
+            signer_id: "alice.test".to_string(),
+            public_key: public_key(&alice),
+            nonce: 7,
+            receiver_id: "bob.alice.test".to_string(),
+            block_hash: CryptoHash("CmpNeggWJ4JaWJeJ8YKN1Zypmk7uvQq3PECGUCAEMbky".to_string()),
+            actions: vec![
+                Action::CreateAccount,
+                Action::DeployContract {
+                    code: vec![0, 1, 2],
+                },
+                Action::FunctionCall {
+                    method_name: "get".to_string(),
+                    args: b"{}".to_vec(),
+                    gas: 30_000_000_000_000,
+                    deposit: 1,
+                },
+                Action::Transfer { deposit: 10 * NEAR },
+                Action::AddKey {
+                    public_key: bob.clone(),
+                    permission: AccessKeyPermission::FullAccess,
+                },
+                Action::AddKey {
+                    public_key: bob.clone(),
+                    permission: AccessKeyPermission::FunctionCall {
+                        allowance: Some(NEAR),
+                        receiver_id: "app.test".to_string(),
+                        method_names: vec!["a".to_string(), "b".to_string()],
+                    },
+                },
+                Action::DeleteKey {
+                    public_key: bob.clone(),
+                },
+                Action::DeleteAccount {
+                    beneficiary_id: "alice.test".to_string(),
+                },
+            ],
+        };
+        let signed = transaction.sign(&alice);
+        assert_eq!(signed.0, "CgAAAGFsaWNlLnRlc3QAIdkewD51XVs9kZrKPDEgt4NkO4eva67SLcJUZMC1O28HAAAAAAAAAA4AAABib2IuYWxpY2UudGVzdK7rrUp5b8wuFdxMYGG0Xtmzc/Jq38eYyn0tjMWBgnGOCAAAAAABAwAAAAABAgIDAAAAZ2V0AgAAAHt9AOBX60gbAAABAAAAAAAAAAAAAAAAAAAAAwAAAEpIARQWlUUIAAAAAAAFAM3sju0qzEmw03T2KLYf/I7FXJblNwVfu7X6EJLZu7VPAAAAAAAAAAABBQDN7I7tKsxJsNN09ii2H/yOxVyW5TcFX7u1+hCS2bu1TwAAAAAAAAAAAAEAAACh7czOG8LTAAAAAAAACAAAAGFwcC50ZXN0AgAAAAEAAABhAQAAAGIGAM3sju0qzEmw03T2KLYf/I7FXJblNwVfu7X6EJLZu7VPBwoAAABhbGljZS50ZXN0AOHbunC9DDtrDBaEGAhBBf4pvPELj8tTbgrRIOaT8adGa3k3tl0S/E+Toa02CK78WdJ5SOMEFMW0ftGrAwhCHw0=");
+        assert_eq!(
+            transaction.hash().0,
+            "3TFoWRHcPbZrJVUDocGvvoh8bdmznDEuFZoRPHqKLCLA"
+        );
+
+        let (decoded, hash, _) =
+            decode_signed_transaction(&BASE64.decode(&signed.0).unwrap()).unwrap();
+        assert_eq!(decoded, transaction);
+        assert_eq!(encode_hash(&hash), transaction.hash());
+    }
+
+    fn transaction(
+        chain: &FakeChain,
+        secret_key: &[u8; 32],
+        nonce: u64,
+        actions: Vec<Action>,
+    ) -> Transaction {
+        Transaction {
+            signer_id: "alice.test".to_string(),
+            public_key: public_key(secret_key),
+            nonce,
+            receiver_id: "bob.alice.test".to_string(),
+            block_hash: chain.head_hash(),
+            actions,
+        }
+    }
+
+    async fn send(
+        client: &NearRpcClient,
+        transaction: &Transaction,
+        key: &[u8; 32],
+    ) -> client::Result<RpcTransactionResponse> {
+        client
+            .send_tx(RpcSendTransactionRequest {
+                signed_tx_base64: transaction.sign(key),
+                wait_until: TxExecutionStatus::Final,
+            })
+            .await
+    }
+
+    async fn view_account(
+        client: &NearRpcClient,
+        account_id: &str,
+        block_id: Option<u64>,
+    ) -> client::Result<u128> {
+        let request = match block_id {
+            Some(height) => RpcQueryRequest::ViewAccountBlockId {
+                account_id: AccountId(account_id.to_string()),
+                block_id: BlockId::BlockHeight(height),
+                request_type: "view_account".to_string(),
+            },
+            None => RpcQueryRequest::ViewAccountFinality {
+                account_id: AccountId(account_id.to_string()),
+                finality: Finality::Final,
+                request_type: "view_account".to_string(),
+            },
+        };
+        match client.query(request).await? {
+            RpcQueryResponse::AccountView { amount, .. } => Ok(amount.0.parse().unwrap()),
+            other => panic!("unexpected response {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fake_chain() {
+        let alice = [1; 32];
+        let bob = [2; 32];
+        let chain = FakeChain::new();
+        chain.add_account("alice.test", 100 * NEAR, public_key(&alice));
+        chain.on_function_call("bob.alice.test", "greet", |call| {
+            Ok(format!("hello {}", String::from_utf8_lossy(&call.args)).into_bytes())
+        });
+        let client = chain.client();
+
+        let create = transaction(
+            &chain,
+            &alice,
+            1,
+            vec![
+                Action::CreateAccount,
+                Action::Transfer { deposit: 10 * NEAR },
+                Action::AddKey {
+                    public_key: public_key(&bob),
+                    permission: AccessKeyPermission::FullAccess,
+                },
+            ],
+        );
+        let outcome = send(&client, &create, &alice).await.unwrap();
+        assert!(matches!(
+            outcome,
+            RpcTransactionResponse::FinalExecutionOutcomeView {
+                status: FinalExecutionStatus::SuccessValue(_),
+                ..
+            }
+        ));
+        assert_eq!(chain.head_height(), 2);
+        assert_eq!(
+            view_account(&client, "alice.test", None).await.unwrap(),
+            90 * NEAR
+        );
+        assert_eq!(
+            view_account(&client, "bob.alice.test", None).await.unwrap(),
+            10 * NEAR
+        );
+        assert_eq!(
+            view_account(&client, "alice.test", Some(1)).await.unwrap(),
+            100 * NEAR
+        );
+        assert!(matches!(
+            view_account(&client, "bob.alice.test", Some(1)).await,
+            Err(Error::Rpc(e)) if e.cause.as_ref().unwrap().name == "UNKNOWN_ACCOUNT"
+        ));
+
+        let key = client
+            .query(RpcQueryRequest::ViewAccessKeyFinality {
+                account_id: AccountId("bob.alice.test".to_string()),
+                finality: Finality::Final,
+                public_key: public_key(&bob),
+                request_type: "view_access_key".to_string(),
+            })
+            .await
+            .unwrap();
+        assert!(matches!(
+            key,
+            RpcQueryResponse::AccessKeyView {
+                nonce: 1_000_000,
+                ..
+            }
+        ));
+
+        let tx = client
+            .tx(RpcTransactionStatusRequest::TxHashSenderAccountId {
+                sender_account_id: AccountId("alice.test".to_string()),
+                tx_hash: create.hash(),
+                wait_until: TxExecutionStatus::Final,
+            })
+            .await
+            .unwrap();
+        assert!(matches!(
+            tx,
+            RpcTransactionResponse::FinalExecutionOutcomeView { transaction, .. }
+                if transaction.hash == create.hash()
+        ));
+
+        // Reusing a nonce is rejected before execution.
+        let replayed = transaction(&chain, &alice, 1, vec![Action::Transfer { deposit: 1 }]);
+        let error = send(&client, &replayed, &alice).await.unwrap_err();
+        assert!(matches!(
+            &error,
+            Error::Rpc(e) if e.cause.as_ref().unwrap().name == "INVALID_TRANSACTION"
+        ));
+        assert!(error.to_string().contains("Server error"));
+
+        // A failing action reverts the whole transaction.
+        let failing = transaction(
+            &chain,
+            &alice,
+            2,
+            vec![
+                Action::Transfer { deposit: NEAR },
+                Action::FunctionCall {
+                    method_name: "missing".to_string(),
+                    args: Vec::new(),
+                    gas: 30_000_000_000_000,
+                    deposit: 0,
+                },
+            ],
+        );
+        let outcome = send(&client, &failing, &alice).await.unwrap();
+        assert!(matches!(
+            outcome,
+            RpcTransactionResponse::FinalExecutionOutcomeView {
+                status: FinalExecutionStatus::Failure(TxExecutionError::ActionError(ActionError {
+                    index: Some(1),
+                    ..
+                })),
+                ..
+            }
+        ));
+        assert_eq!(chain.balance("bob.alice.test"), Some(10 * NEAR));
+
+        let result = client
+            .query(RpcQueryRequest::CallFunctionFinality {
+                account_id: AccountId("bob.alice.test".to_string()),
+                args_base64: FunctionArgs(BASE64.encode("world")),
+                finality: Finality::Final,
+                method_name: "greet".to_string(),
+                request_type: "call_function".to_string(),
+            })
+            .await
+            .unwrap();
+        assert!(matches!(
+            result,
+            RpcQueryResponse::CallResult { result, .. } if result == b"hello world"
+        ));
+
+        chain.advance_blocks(2);
+        let block = client
+            .block(RpcBlockRequest::Finality(Finality::Final))
+            .await
+            .unwrap();
+        assert_eq!(block.header.height, 5);
+        assert_eq!(block.header.hash, chain.head_hash());
+    }
+
+    #[tokio::test]
+    async fn test_balance_overflow() {
+        let alice = [1; 32];
+        let chain = FakeChain::new();
+        chain.add_account("alice.test", u128::MAX, public_key(&alice));
+        chain.add_account("bob.alice.test", NEAR, public_key(&[2; 32]));
+        let client = chain.client();
+
+        let is_cost_overflow = |error: &Error| matches!(error, Error::Rpc(e) if e.data.as_ref().unwrap().to_string().contains("CostOverflow"));
+        let deposits = transaction(
+            &chain,
+            &alice,
+            1,
+            vec![
+                Action::Transfer { deposit: u128::MAX },
+                Action::Transfer { deposit: 1 },
+            ],
+        );
+        let error = send(&client, &deposits, &alice).await.unwrap_err();
+        assert!(is_cost_overflow(&error), "{error:?}");
+
+        let balance = transaction(
+            &chain,
+            &alice,
+            1,
+            vec![Action::Transfer { deposit: u128::MAX }],
+        );
+        let error = send(&client, &balance, &alice).await.unwrap_err();
+        assert!(is_cost_overflow(&error), "{error:?}");
+
+        // The rejected transactions left the chain usable and unchanged.
+        assert_eq!(chain.head_height(), 1);
+        assert_eq!(chain.balance("bob.alice.test"), Some(NEAR));
+        let block = client
+            .block(RpcBlockRequest::Finality(Finality::Final))
+            .await
+            .unwrap();
+        assert_eq!(block.header.total_supply.0, u128::MAX.to_string());
+        let transfer = transaction(&chain, &alice, 1, vec![Action::Transfer { deposit: 1 }]);
+        send(&client, &transfer, &alice).await.unwrap();
+        assert_eq!(chain.balance("bob.alice.test"), Some(NEAR + 1));
+    }
+}
@@ -0,0 +1,407 @@
+//! Mock NEAR JSON-RPC server for tests.
+//!
+//! [`MockServer`] listens on a random local port and answers JSON-RPC requests from
+//! registered mocks, so code using [`NearRpcClient`] can be tested offline.
+//!
+//! ```no_run
+//! use near_rpc_client::testing::MockServer;
+//! use near_rpc_client::types::*;
+//! use serde_json::json;
+//!
+//! # async fn example() {
+//! let server = MockServer::start().await;
+//! server
+//!     .mock("gas_price")
+//!     .respond(RpcGasPriceResponse { gas_price: NearToken("100000000".to_string()) });
+//! server
+//!     .mock("query")
+//!     .match_params(json!({ "account_id": "missing.near" }))
+//!     .expect(1)
+//!     .respond_error(MockServer::handler_error("UNKNOWN_ACCOUNT", json!({})));
+//!
+//! let client = server.client();
+//! let gas_price = client.gas_price(RpcGasPriceRequest { block_id: None }).await.unwrap();
+//! # let _ = gas_price;
+//! server.verify();
+//! # }
+//! ```
+//!
+//! The [`replay`] module records real traffic into fixture files and replays it, and
+//! [`chain::FakeChain`] simulates a node executing signed transactions.
+
+pub mod chain;
+pub mod replay;
+
+use crate::client::RpcError;
+use crate::server::accept;
+use crate::NearRpcClient;
+use http_body_util::{BodyExt, Full};
+use hyper::body::{Bytes, Incoming};
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Request, Response};
+use hyper_util::rt::TokioIo;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::net::TcpListener;
+use tokio::task::JoinHandle;
+
+type Handler = dyn Fn(&Value) -> Result<Value, RpcError> + Send + Sync;
+
+/// A request received by a [`MockServer`]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReceivedRequest {
+    pub method: String,
+    pub params: Value,
+}
+
+/// Local JSON-RPC server answering from registered mocks.
+///
+/// When several mocks match a request, the one registered last wins. Requests no mock
+/// matches are answered with a `METHOD_NOT_FOUND` error. The server stops when dropped.
+pub struct MockServer {
+    addr: SocketAddr,
+    state: Arc<Mutex<State>>,
+    task: JoinHandle<()>,
+}
+
+#[derive(Default)]
+struct State {
+    mocks: Vec<Mock>,
+    requests: Vec<ReceivedRequest>,
+}
+
+struct Mock {
+    method: String,
+    params: Option<Value>,
+    delay: Option<Duration>,
+    expected: Option<usize>,
+    hits: usize,
+    handler: Box<Handler>,
+}
+
+impl Mock {
+    fn matches(&self, method: &str, params: &Value) -> bool {
+        self.method == method
+            && self
+                .params
+                .as_ref()
+                .is_none_or(|expected| is_subset(expected, params))
+    }
+}
+
+impl MockServer {
+    /// Start a server on a random port of `127.0.0.1`
+    pub async fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("failed to bind mock server");
+        let addr = listener.local_addr().expect("mock server has an address");
+        let state = Arc::new(Mutex::new(State::default()));
+        let task = tokio::spawn(serve(listener, state.clone()));
+        Self { addr, state, task }
+    }
+
+    /// URL of the server
+    pub fn url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// Create a client connected to the server
+    pub fn client(&self) -> NearRpcClient {
+        NearRpcClient::new(self.url())
+    }
+
+    /// Start registering a mock for `method`
+    pub fn mock(&self, method: impl Into<String>) -> MockBuilder<'_> {
+        MockBuilder {
+            server: self,
+            method: method.into(),
+            params: None,
+            delay: None,
+            expected: None,
+        }
+    }
+
+    /// All requests received so far, in order
+    pub fn requests(&self) -> Vec<ReceivedRequest> {
+        self.state.lock().unwrap().requests.clone()
+    }
+
+    /// Params of the requests received for `method`, in order
+    pub fn requests_for(&self, method: &str) -> Vec<Value> {
+        self.state
+            .lock()
+            .unwrap()
+            .requests
+            .iter()
+            .filter(|request| request.method == method)
+            .map(|request| request.params.clone())
+            .collect()
+    }
+
+    /// Panic unless every mock registered with [`MockBuilder::expect`] was hit as expected.
+    pub fn verify(&self) {
+        let state = self.state.lock().unwrap();
+        let failures: Vec<String> = state
+            .mocks
+            .iter()
+            .filter_map(|mock| {
+                let expected = mock.expected?;
+                (mock.hits != expected).then(|| {
+                    format!(
+                        "`{}` mock{} expected {expected} request(s), received {}",
+                        mock.method,
+                        mock.params
+                            .as_ref()
+                            .map_or(String::new(), |params| format!(" matching {params}")),
+                        mock.hits
+                    )
+                })
+            })
+            .collect();
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+
+    /// A nearcore `HANDLER_ERROR` with the given cause, e.g. `UNKNOWN_BLOCK`.
+    pub fn handler_error(cause: impl Into<String>, info: Value) -> RpcError {
+        RpcError::handler_error(cause, info)
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Builder for a mock registered with [`MockServer::mock`].
+pub struct MockBuilder<'a> {
+    server: &'a MockServer,
+    method: String,
+    params: Option<Value>,
+    delay: Option<Duration>,
+    expected: Option<usize>,
+}
+
+impl MockBuilder<'_> {
+    /// Only answer requests whose params contain `params`.
+    ///
+    /// Objects match if every field of `params` matches the request; other values must be
+    /// equal. For example `json!({ "account_id": "alice.near" })` matches any query for
+    /// `alice.near`.
+    pub fn match_params(mut self, params: Value) -> Self {
+        self.params = Some(params);
+        self
+    }
+
+    /// Wait for `delay` before answering
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = Some(delay);
+        self
+    }
+
+    /// Expect exactly `times` matching requests, checked by [`MockServer::verify`]
+    pub fn expect(mut self, times: usize) -> Self {
+        self.expected = Some(times);
+        self
+    }
+
+    /// Answer with `result`
+    pub fn respond<T: Serialize>(self, result: T) {
+        let result = serde_json::to_value(result).expect("mock result serializes to JSON");
+        self.handle(move |_| Ok(result.clone()))
+    }
+
+    /// Answer with a JSON-RPC error
+    pub fn respond_error(self, error: RpcError) {
+        self.handle(move |_| Err(error.clone()))
+    }
+
+    /// Answer with the result of `handler`, called with the request params
+    pub fn handle<F>(self, handler: F)
+    where
+        F: Fn(&Value) -> Result<Value, RpcError> + Send + Sync + 'static,
+    {
+        self.server.state.lock().unwrap().mocks.push(Mock {
+            method: self.method,
+            params: self.params,
+            delay: self.delay,
+            expected: self.expected,
+            hits: 0,
+            handler: Box::new(handler),
+        });
+    }
+}
+
+#[derive(Deserialize)]
+struct IncomingRequest {
+    #[serde(default)]
+    id: Value,
+    method: String,
+    #[serde(default)]
+    params: Value,
+}
+
+async fn serve(listener: TcpListener, state: Arc<Mutex<State>>) {
+    loop {
+        let (stream, _) = accept(&listener).await;
+        let state = state.clone();
+        tokio::spawn(async move {
+            let service = service_fn(move |request| respond(state.clone(), request));
+            let _ = http1::Builder::new()
+                .serve_connection(TokioIo::new(stream), service)
+                .await;
+        });
+    }
+}
+
+async fn respond(
+    state: Arc<Mutex<State>>,
+    request: Request<Incoming>,
+) -> Result<Response<Full<Bytes>>, hyper::Error> {
+    let body = request.into_body().collect().await?.to_bytes();
+    let reply = match serde_json::from_slice::<IncomingRequest>(&body) {
+        Ok(request) => {
+            let id = request.id.clone();
+            match dispatch(&state, request).await {
+                Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+                Err(error) => json!({ "jsonrpc": "2.0", "id": id, "error": error }),
+            }
+        }
+        Err(error) => json!({
+            "jsonrpc": "2.0",
+            "id": null,
+            "error": RpcError::parse_error(error.to_string()),
+        }),
+    };
+    let mut response = Response::new(Full::new(Bytes::from(reply.to_string())));
+    response.headers_mut().insert(
+        hyper::header::CONTENT_TYPE,
+        hyper::header::HeaderValue::from_static("application/json"),
+    );
+    Ok(response)
+}
+
+async fn dispatch(state: &Mutex<State>, request: IncomingRequest) -> Result<Value, RpcError> {
+    let (delay, reply) = {
+        let mut state = state.lock().unwrap();
+        state.requests.push(ReceivedRequest {
+            method: request.method.clone(),
+            params: request.params.clone(),
+        });
+        match state
+            .mocks
+            .iter_mut()
+            .rev()
+            .find(|mock| mock.matches(&request.method, &request.params))
+        {
+            Some(mock) => {
+                mock.hits += 1;
+                (mock.delay, (mock.handler)(&request.params))
+            }
+            None => (None, Err(RpcError::method_not_found(&request.method))),
+        }
+    };
+    if let Some(delay) = delay {
+        tokio::time::sleep(delay).await;
+    }
+    reply
+}
+
+/// Whether `actual` contains everything in `expected`
+fn is_subset(expected: &Value, actual: &Value) -> bool {
+    match (expected, actual) {
+        (Value::Object(expected), Value::Object(actual)) => expected.iter().all(|(key, value)| {
+            actual
+                .get(key)
+                .is_some_and(|actual| is_subset(value, actual))
+        }),
+        _ => expected == actual,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::Error;
+    use crate::types::*;
+
+    #[tokio::test]
+    async fn test_mock_server() {
+        let server = MockServer::start().await;
+        server.mock("gas_price").respond(RpcGasPriceResponse {
+            gas_price: NearToken("100000000".to_string()),
+        });
+        server
+            .mock("gas_price")
+            .match_params(json!({ "block_id": 1 }))
+            .expect(1)
+            .respond_error(MockServer::handler_error(
+                "UNKNOWN_BLOCK",
+                json!({ "block_reference": { "block_id": 1 } }),
+            ));
+        let client = server.client();
+
+        let gas_price = client
+            .gas_price(RpcGasPriceRequest { block_id: None })
+            .await
+            .unwrap();
+        assert_eq!(gas_price.gas_price.0, "100000000");
+
+        let error = client
+            .gas_price(RpcGasPriceRequest {
+                block_id: Some(BlockId::BlockHeight(1)),
+            })
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            Error::Rpc(e) if e.cause.as_ref().unwrap().name == "UNKNOWN_BLOCK"
+        ));
+
+        let error = client.status().await.unwrap_err();
+        assert!(matches!(error, Error::Rpc(e) if e.code == -32601));
+
+        assert_eq!(server.requests().len(), 3);
+        assert_eq!(
+            server.requests_for("gas_price"),
+            [json!({}), json!({ "block_id": 1 })]
+        );
+        server.verify();
+    }
+
+    #[tokio::test]
+    async fn test_handler_and_delay() {
+        let server = MockServer::start().await;
+        server
+            .mock("gas_price")
+            .delay(Duration::from_millis(50))
+            .handle(|params| {
+                let height = params["block_id"].as_u64().unwrap_or_default();
+                Ok(json!({ "gas_price": (height * 10).to_string() }))
+            });
+        let start = std::time::Instant::now();
+        let gas_price = server
+            .client()
+            .gas_price(RpcGasPriceRequest {
+                block_id: Some(BlockId::BlockHeight(7)),
+            })
+            .await
+            .unwrap();
+        assert_eq!(gas_price.gas_price.0, "70");
+        assert!(start.elapsed() >= Duration::from_millis(50));
+    }
+
+    #[test]
+    #[should_panic(expected = "expected 2 request(s), received 0")]
+    fn test_verify() {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let server = runtime.block_on(MockServer::start());
+        server.mock("status").expect(2).respond(json!({}));
+        server.verify();
+    }
+}
@@ -0,0 +1,322 @@
+//! Record RPC traffic into fixtures and replay it deterministically.
+//!
+//! [`Recorder`] wraps any transport service and keeps every call with its result or
+//! JSON-RPC error; [`Replay`] answers calls from such a recording without a network.
+//! Both are `tower` services, so they plug into [`NearRpcClient::from_service`].
+//!
+//! ```no_run
+//! use near_rpc_client::service::HttpService;
+//! use near_rpc_client::testing::replay::{Recorder, Replay};
+//! use near_rpc_client::NearRpcClient;
+//!
+//! # async fn example() -> std::io::Result<()> {
+//! // Once, against a real node:
+//! let recorder = Recorder::new(HttpService::new("https://rpc.testnet.near.org"));
+//! let client = NearRpcClient::from_service(recorder.clone());
+//! client.status().await.ok();
+//! recorder.save("tests/fixtures/status.json")?;
+//!
+//! // In CI:
+//! let replay = Replay::from_file("tests/fixtures/status.json")?.ignore_field("latest_block_hash");
+//! let client = NearRpcClient::from_service(replay);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`NearRpcClient::from_service`]: crate::NearRpcClient::from_service
+
+use crate::client::{Error, Result, RpcError};
+use crate::service::RpcCall;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::path::Path;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use tower::Service;
+
+/// A recorded call and its outcome
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Interaction {
+    pub method: String,
+    pub params: Value,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<RpcError>,
+}
+
+/// Error returned by [`Replay`] for a call that was not recorded
+#[derive(Debug, thiserror::Error)]
+#[error("no recorded response for `{method}` with params {params} ({recorded} recorded call(s) of `{method}` with other params)")]
+pub struct Unrecorded {
+    pub method: String,
+    pub params: Value,
+    pub recorded: usize,
+}
+
+/// Service recording calls passed to an inner transport service.
+///
+/// Clones share the same recording. Transport failures are passed through without being
+/// recorded.
+#[derive(Clone)]
+pub struct Recorder<S> {
+    inner: S,
+    interactions: Arc<Mutex<Vec<Interaction>>>,
+}
+
+impl<S> Recorder<S> {
+    /// Record calls passed to `inner`
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            interactions: Arc::default(),
+        }
+    }
+
+    /// Calls recorded so far, in order
+    pub fn interactions(&self) -> Vec<Interaction> {
+        self.interactions.lock().unwrap().clone()
+    }
+
+    /// Write the recording to `path` as JSON
+    pub fn save(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        let json = serde_json::to_vec_pretty(&*self.interactions.lock().unwrap())?;
+        std::fs::write(path, json)
+    }
+}
+
+impl<S> Service<RpcCall> for Recorder<S>
+where
+    S: Service<RpcCall, Response = Value, Error = Error>,
+    S::Future: Send + 'static,
+{
+    type Response = Value;
+    type Error = Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Value>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, call: RpcCall) -> Self::Future {
+        let interactions = self.interactions.clone();
+        let mut interaction = Interaction {
+            method: call.method.clone(),
+            params: call.params.clone(),
+            result: None,
+            error: None,
+        };
+        let response = self.inner.call(call);
+        Box::pin(async move {
+            let result = response.await;
+            match &result {
+                Ok(value) => interaction.result = Some(value.clone()),
+                Err(Error::Rpc(error)) => interaction.error = Some(error.clone()),
+                Err(_) => return result,
+            }
+            interactions.lock().unwrap().push(interaction);
+            result
+        })
+    }
+}
+
+/// Service answering calls from a recording.
+///
+/// Calls are matched on method and normalized params: `null` fields are dropped and fields
+/// passed to [`ignore_field`](Self::ignore_field) are ignored at any depth. Calls matching
+/// several recorded interactions get their responses in recorded order, with the last one
+/// repeated once all were used. Unrecorded calls fail with an [`Unrecorded`] error wrapped
+/// in [`Error::Service`].
+#[derive(Clone)]
+pub struct Replay {
+    interactions: Arc<[Interaction]>,
+    ignored: Arc<HashSet<String>>,
+    state: Arc<Mutex<HashMap<String, usize>>>,
+}
+
+impl Replay {
+    /// Replay `interactions`
+    pub fn new(interactions: Vec<Interaction>) -> Self {
+        Self {
+            interactions: interactions.into(),
+            ignored: Arc::default(),
+            state: Arc::default(),
+        }
+    }
+
+    /// Replay a recording saved with [`Recorder::save`]
+    pub fn from_file(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let json = std::fs::read(path)?;
+        Ok(Self::new(serde_json::from_slice(&json)?))
+    }
+
+    /// Ignore params named `field` when matching calls, e.g. a block hash that differs per run
+    pub fn ignore_field(mut self, field: impl Into<String>) -> Self {
+        Arc::make_mut(&mut self.ignored).insert(field.into());
+        self
+    }
+
+    fn key(&self, method: &str, params: &Value) -> String {
+        format!("{method}:{}", normalize(params, &self.ignored))
+    }
+
+    fn respond(&self, call: &RpcCall) -> Result<Value> {
+        let key = self.key(&call.method, &call.params);
+        let matching: Vec<&Interaction> = self
+            .interactions
+            .iter()
+            .filter(|interaction| self.key(&interaction.method, &interaction.params) == key)
+            .collect();
+        let Some(last) = matching.len().checked_sub(1) else {
+            let recorded = self
+                .interactions
+                .iter()
+                .filter(|interaction| interaction.method == call.method)
+                .count();
+            return Err(Error::Service(Arc::new(Unrecorded {
+                method: call.method.clone(),
+                params: call.params.clone(),
+                recorded,
+            })));
+        };
+        let mut state = self.state.lock().unwrap();
+        let used = state.entry(key).or_default();
+        let interaction = matching[(*used).min(last)];
+        *used += 1;
+        match (&interaction.error, &interaction.result) {
+            (Some(error), _) => Err(Error::Rpc(error.clone())),
+            (None, result) => Ok(result.clone().unwrap_or(Value::Null)),
+        }
+    }
+}
+
+impl Service<RpcCall> for Replay {
+    type Response = Value;
+    type Error = Error;
+    type Future = std::future::Ready<Result<Value>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, call: RpcCall) -> Self::Future {
+        std::future::ready(self.respond(&call))
+    }
+}
+
+/// Drop `null` object fields and `ignored` fields, recursively
+fn normalize(value: &Value, ignored: &HashSet<String>) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .filter(|(key, value)| !value.is_null() && !ignored.contains(*key))
+                .map(|(key, value)| (key.clone(), normalize(value, ignored)))
+                .collect(),
+        ),
+        Value::Array(values) => Value::Array(
+            values
+                .iter()
+                .map(|value| normalize(value, ignored))
+                .collect(),
+        ),
+        value => value.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::MockServer;
+    use crate::types::*;
+    use crate::NearRpcClient;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_record_and_replay() {
+        let server = MockServer::start().await;
+        server
+            .mock("gas_price")
+            .respond(json!({ "gas_price": "100000000" }));
+        server
+            .mock("gas_price")
+            .match_params(json!({ "block_id": 1 }))
+            .respond_error(MockServer::handler_error("UNKNOWN_BLOCK", json!({})));
+
+        let recorder = Recorder::new(crate::service::HttpService::new(server.url()));
+        let client = NearRpcClient::from_service(recorder.clone());
+        client
+            .gas_price(RpcGasPriceRequest { block_id: None })
+            .await
+            .unwrap();
+        client
+            .gas_price(RpcGasPriceRequest {
+                block_id: Some(BlockId::BlockHeight(1)),
+            })
+            .await
+            .unwrap_err();
+        drop(server);
+
+        let path =
+            std::env::temp_dir().join(format!("near-rpc-replay-{}.json", std::process::id()));
+        recorder.save(&path).unwrap();
+        let replay = Replay::from_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let client = NearRpcClient::from_service(replay);
+        let gas_price = client
+            .gas_price(RpcGasPriceRequest { block_id: None })
+            .await
+            .unwrap();
+        assert_eq!(gas_price.gas_price.0, "100000000");
+        assert!(matches!(
+            client
+                .gas_price(RpcGasPriceRequest {
+                    block_id: Some(BlockId::BlockHeight(1)),
+                })
+                .await,
+            Err(Error::Rpc(e)) if e.code == -32000
+        ));
+
+        let error = client
+            .gas_price(RpcGasPriceRequest {
+                block_id: Some(BlockId::BlockHeight(2)),
+            })
+            .await
+            .unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("no recorded response for `gas_price` with params {\"block_id\":2}"));
+    }
+
+    #[test]
+    fn test_replay_matching() {
+        let interaction = |params: Value, result: Value| Interaction {
+            method: "query".to_string(),
+            params,
+            result: Some(result),
+            error: None,
+        };
+        let replay = Replay::new(vec![
+            interaction(json!({ "account_id": "alice.near", "id": 1 }), json!(1)),
+            interaction(json!({ "account_id": "alice.near", "id": 2 }), json!(2)),
+        ])
+        .ignore_field("id");
+
+        let call = RpcCall::new(
+            "query",
+            json!({ "account_id": "alice.near", "id": 7, "x": null }),
+        );
+        assert_eq!(replay.respond(&call).unwrap(), json!(1));
+        assert_eq!(replay.respond(&call).unwrap(), json!(2));
+        assert_eq!(replay.respond(&call).unwrap(), json!(2));
+
+        let call = RpcCall::new("query", json!({ "account_id": "bob.near" }));
+        assert!(matches!(
+            replay.respond(&call),
+            Err(Error::Service(e)) if e.to_string().contains("2 recorded call(s)")
+        ));
+    }
+}
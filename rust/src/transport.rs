@@ -0,0 +1,178 @@
+//! HTTP transports for the async client.
+//!
+//! The client only needs to POST a JSON body and read the whole response, so it does not
+//! depend on a particular HTTP stack or executor. Implement [`Transport`] to send requests
+//! with another library, e.g. one running on `smol` or an embedded runtime, and build the
+//! client with [`NearRpcClient::from_transport`](crate::NearRpcClient::from_transport).
+//!
+//! [`NearRpcClient::new`](crate::NearRpcClient::new) uses [`ReqwestTransport`] with the
+//! `reqwest` feature, which needs a `tokio` runtime. On wasm32 it uses [`FetchTransport`],
+//! which calls the global `fetch` of browsers, Node and Cloudflare Workers.
+//!
+//! ```
+//! use near_rpc_client::transport::{http, BoxError, BoxFuture, Transport};
+//! use near_rpc_client::NearRpcClient;
+//!
+//! /// Answers every request with the same JSON-RPC response
+//! struct Canned(&'static str);
+//!
+//! impl Transport for Canned {
+//!     fn send(
+//!         &self,
+//!         _request: http::Request<Vec<u8>>,
+//!     ) -> BoxFuture<'_, Result<http::Response<Vec<u8>>, BoxError>> {
+//!         Box::pin(async move { Ok(http::Response::new(self.0.as_bytes().to_vec())) })
+//!     }
+//! }
+//!
+//! let client = NearRpcClient::from_transport(
+//!     "http://localhost:3030",
+//!     Canned(r#"{"jsonrpc":"2.0","id":1,"result":{}}"#),
+//! );
+//! ```
+
+use std::future::Future;
+use std::pin::Pin;
+
+pub use http;
+
+/// Boxed future returned by [`Transport`] and
+/// [`CacheBackend`](crate::cache::CacheBackend) methods
+#[cfg(not(target_arch = "wasm32"))]
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// Boxed future returned by [`Transport`] and
+/// [`CacheBackend`](crate::cache::CacheBackend) methods, not `Send` on wasm32 where
+/// JavaScript futures never are
+#[cfg(target_arch = "wasm32")]
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + 'a>>;
+
+/// Error of a [`Transport`], returned as [`Error::Http`](crate::client::Error::Http)
+pub type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Sends HTTP requests for a [`NearRpcClient`](crate::NearRpcClient).
+///
+/// Requests are `POST`s to the endpoint with a JSON body and a `content-type` header. The
+/// response must be returned with its status, headers and whole body, whatever the status.
+pub trait Transport: Send + Sync {
+    /// Send `request` and read the response
+    fn send(
+        &self,
+        request: http::Request<Vec<u8>>,
+    ) -> BoxFuture<'_, Result<http::Response<Vec<u8>>, BoxError>>;
+}
+
+impl<T: Transport + ?Sized> Transport for std::sync::Arc<T> {
+    fn send(
+        &self,
+        request: http::Request<Vec<u8>>,
+    ) -> BoxFuture<'_, Result<http::Response<Vec<u8>>, BoxError>> {
+        (**self).send(request)
+    }
+}
+
+/// Transport sending requests with a [`reqwest::Client`]
+#[cfg(feature = "reqwest")]
+#[derive(Clone, Debug, Default)]
+pub struct ReqwestTransport {
+    client: reqwest::Client,
+}
+
+#[cfg(feature = "reqwest")]
+impl ReqwestTransport {
+    /// Send requests with a preconfigured `client`, e.g. with timeouts or a proxy
+    pub fn new(client: reqwest::Client) -> Self {
+        Self { client }
+    }
+}
+
+#[cfg(feature = "reqwest")]
+impl Transport for ReqwestTransport {
+    fn send(
+        &self,
+        request: http::Request<Vec<u8>>,
+    ) -> BoxFuture<'_, Result<http::Response<Vec<u8>>, BoxError>> {
+        Box::pin(async move {
+            let response = self.client.execute(request.try_into()?).await?;
+            let mut builder = http::Response::builder().status(response.status());
+            if let Some(headers) = builder.headers_mut() {
+                *headers = response.headers().clone();
+            }
+            Ok(builder.body(response.bytes().await?.to_vec())?)
+        })
+    }
+}
+
+/// Transport sending requests with the global `fetch` of the JavaScript host
+#[cfg(target_arch = "wasm32")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FetchTransport;
+
+#[cfg(target_arch = "wasm32")]
+impl Transport for FetchTransport {
+    fn send(
+        &self,
+        request: http::Request<Vec<u8>>,
+    ) -> BoxFuture<'_, Result<http::Response<Vec<u8>>, BoxError>> {
+        Box::pin(async move { fetch::send(request).await.map_err(fetch::into_error) })
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+mod fetch {
+    use super::BoxError;
+    use wasm_bindgen::prelude::*;
+    use wasm_bindgen_futures::JsFuture;
+
+    #[wasm_bindgen]
+    extern "C" {
+        /// `fetch` of the global scope, which is not a `Window` in workers and Node
+        #[wasm_bindgen(js_name = fetch)]
+        fn fetch_with_request(request: &web_sys::Request) -> js_sys::Promise;
+    }
+
+    pub(super) async fn send(
+        request: http::Request<Vec<u8>>,
+    ) -> Result<http::Response<Vec<u8>>, JsValue> {
+        let init = web_sys::RequestInit::new();
+        init.set_method(request.method().as_str());
+        let headers = web_sys::Headers::new()?;
+        for (name, value) in request.headers() {
+            headers.append(name.as_str(), value.to_str().unwrap_or_default())?;
+        }
+        init.set_headers(&headers);
+        let body = js_sys::Uint8Array::from(request.body().as_slice());
+        init.set_body(&body);
+        let request = web_sys::Request::new_with_str_and_init(&request.uri().to_string(), &init)?;
+
+        let response: web_sys::Response = JsFuture::from(fetch_with_request(&request))
+            .await?
+            .dyn_into()?;
+        let mut builder = http::Response::builder().status(response.status());
+        // `Headers` iterates over `[name, value]` pairs
+        if let Some(entries) = js_sys::try_iter(&response.headers())? {
+            for entry in entries {
+                let entry: js_sys::Array = entry?.dyn_into()?;
+                if let (Some(name), Some(value)) =
+                    (entry.get(0).as_string(), entry.get(1).as_string())
+                {
+                    builder = builder.header(name, value);
+                }
+            }
+        }
+        let body = JsFuture::from(response.array_buffer()?).await?;
+        let body = js_sys::Uint8Array::new(&body).to_vec();
+        builder
+            .body(body)
+            .map_err(|error| JsValue::from_str(&error.to_string()))
+    }
+
+    /// The message of a JavaScript error, e.g. a `TypeError` for a failed request
+    pub(super) fn into_error(error: JsValue) -> BoxError {
+        let message = match error.dyn_ref::<js_sys::Error>() {
+            Some(error) => String::from(error.message()),
+            None => format!("{error:?}"),
+        };
+        message.into()
+    }
+}
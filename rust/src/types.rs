@@ -1,6 +1,6 @@
 //! Generated types from NEAR Protocol OpenRPC schema.
 //!
-//! This module is auto-generated by typify from `schema.json`.
-//! Do not edit manually.
+//! Re-exported from the `near-rpc-types` crate, which can be used on its own, e.g. without
+//! `std`.
 
-include!("generated.rs");
+pub use near_rpc_types::*;
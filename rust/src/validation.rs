@@ -0,0 +1,711 @@
+//! Validation of responses against the JSON Schema in `openrpc.json`.
+//!
+//! The schemas are embedded at build time. [`validate_response`] checks the raw `result`
+//! of a method against the schema of its response and reports every mismatch with the
+//! JSON pointer of the offending value, e.g. `/header/gas_price: expected string, found
+//! number`. Fields that the schema does not declare are reported too, as
+//! [`IssueKind::UnknownField`]: serde ignores them, so they are the first sign of a
+//! nearcore release the spec has not caught up with.
+//!
+//! Clients validate every response with
+//! [`NearRpcClient::with_validation`](crate::NearRpcClient::with_validation). Issues that
+//! do not fail a call are kept by the client until read with
+//! [`NearRpcClient::take_validation_warnings`](crate::NearRpcClient::take_validation_warnings),
+//! and logged to `tracing` with the `tracing` feature.
+//!
+//! The validator implements the keywords the spec uses. `oneOf` is checked like `anyOf`,
+//! since variants generated from Rust enums may overlap, and string formats are not
+//! checked.
+
+use crate::methods;
+use serde_json::{Map, Value};
+use std::collections::BTreeSet;
+#[cfg(any(feature = "client", feature = "blocking"))]
+use std::collections::VecDeque;
+use std::fmt;
+#[cfg(any(feature = "client", feature = "blocking"))]
+use std::sync::Mutex;
+use std::sync::OnceLock;
+
+/// Number of recent warnings a client keeps
+#[cfg(any(feature = "client", feature = "blocking"))]
+const MAX_WARNINGS: usize = 100;
+
+/// What is wrong with a response, see [`validate_response`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IssueKind {
+    /// The value does not match the schema
+    Mismatch,
+    /// An object has a field the schema does not declare
+    UnknownField,
+}
+
+/// A problem at one location of a response
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Issue {
+    /// JSON pointer to the value, empty for the whole response
+    pub pointer: String,
+    pub kind: IssueKind,
+    pub message: String,
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let pointer = if self.pointer.is_empty() {
+            "/"
+        } else {
+            &self.pointer
+        };
+        write!(f, "{pointer}: {}", self.message)
+    }
+}
+
+/// How clients handle responses that do not match the schema
+#[cfg(any(feature = "client", feature = "blocking"))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Validation {
+    /// Keep the issues as warnings, log them with the `tracing` feature, and return the
+    /// response
+    Warn,
+    /// Fail with [`Error::Validation`](crate::client::Error::Validation) when a value does
+    /// not match the schema. Unknown fields alone are only warnings, as with
+    /// [`Warn`](Self::Warn).
+    Fail,
+}
+
+/// The most recent issues that did not fail a call
+#[cfg(any(feature = "client", feature = "blocking"))]
+#[derive(Default)]
+pub(crate) struct Warnings(Mutex<VecDeque<ValidationError>>);
+
+#[cfg(any(feature = "client", feature = "blocking"))]
+impl Warnings {
+    fn push(&self, warning: ValidationError) {
+        let mut warnings = self.0.lock().unwrap();
+        if warnings.len() == MAX_WARNINGS {
+            warnings.pop_front();
+        }
+        warnings.push_back(warning);
+    }
+
+    pub(crate) fn take(&self) -> Vec<ValidationError> {
+        self.0.lock().unwrap().drain(..).collect()
+    }
+}
+
+/// A response that does not match the schema of its method
+#[derive(Clone, Debug, thiserror::Error)]
+#[error("response to `{method}` does not match the schema: {}", summary(.issues))]
+pub struct ValidationError {
+    pub method: String,
+    pub issues: Vec<Issue>,
+}
+
+impl ValidationError {
+    /// Whether any issue is a [`IssueKind::Mismatch`], not only unknown fields
+    pub fn has_mismatches(&self) -> bool {
+        self.issues
+            .iter()
+            .any(|issue| issue.kind == IssueKind::Mismatch)
+    }
+}
+
+/// Validate the `result` of `method` in `mode`, adding the issues that do not fail it to
+/// `warnings`
+#[cfg(any(feature = "client", feature = "blocking"))]
+pub(crate) fn enforce(
+    method: &str,
+    result: &Value,
+    mode: Validation,
+    warnings: &Warnings,
+) -> Result<(), ValidationError> {
+    let Some(issues) = validate_response(method, result) else {
+        return Ok(());
+    };
+    if issues.is_empty() {
+        return Ok(());
+    }
+    let error = ValidationError {
+        method: method.to_string(),
+        issues,
+    };
+    if mode == Validation::Fail && error.has_mismatches() {
+        return Err(error);
+    }
+    #[cfg(feature = "tracing")]
+    tracing::warn!(method, issues = error.issues.len(), "{error}");
+    warnings.push(error);
+    Ok(())
+}
+
+/// The first issues, to keep messages readable
+fn summary(issues: &[Issue]) -> String {
+    const SHOWN: usize = 5;
+    let mut summary: Vec<String> = issues.iter().take(SHOWN).map(Issue::to_string).collect();
+    if issues.len() > SHOWN {
+        summary.push(format!("and {} more", issues.len() - SHOWN));
+    }
+    summary.join("; ")
+}
+
+fn schemas() -> &'static Map<String, Value> {
+    static SCHEMAS: OnceLock<Map<String, Value>> = OnceLock::new();
+    SCHEMAS.get_or_init(|| {
+        serde_json::from_str(include_str!(concat!(env!("OUT_DIR"), "/schemas.json")))
+            .expect("build.rs embeds valid schemas")
+    })
+}
+
+/// Check the `result` of `method` against the schema of its response.
+///
+/// Returns `None` for methods missing from `openrpc.json`, otherwise the issues found,
+/// empty when the response is valid.
+pub fn validate_response(method: &str, result: &Value) -> Option<Vec<Issue>> {
+    let response = methods::find(method)?.response;
+    validate(response, result)
+}
+
+/// Check `value` against the schema named `schema`, e.g. `RpcBlockResponse`.
+///
+/// Returns `None` if there is no such schema.
+pub fn validate(schema: &str, value: &Value) -> Option<Vec<Issue>> {
+    let schema = schemas().get(schema)?;
+    let mut issues = Vec::new();
+    check(schema, value, &mut String::new(), &mut issues);
+    Some(issues)
+}
+
+/// Properties of an object checked by a schema, to find the unknown ones
+#[derive(Default)]
+struct Evaluated {
+    /// Whether the schema declares properties at all, as opposed to a free-form object
+    declared: bool,
+    /// Whether every property is covered, by `additionalProperties`
+    all: bool,
+    names: BTreeSet<String>,
+}
+
+impl Evaluated {
+    fn merge(&mut self, other: Evaluated) {
+        self.declared |= other.declared;
+        self.all |= other.all;
+        self.names.extend(other.names);
+    }
+}
+
+/// Validate `value` and report the fields of objects no subschema evaluated
+fn check(schema: &Value, value: &Value, pointer: &mut String, issues: &mut Vec<Issue>) {
+    let evaluated = validate_schema(schema, value, pointer, issues);
+    let Value::Object(map) = value else {
+        return;
+    };
+    if !evaluated.declared || evaluated.all {
+        return;
+    }
+    for key in map.keys().filter(|key| !evaluated.names.contains(*key)) {
+        issues.push(Issue {
+            pointer: format!("{pointer}/{}", escape(key)),
+            kind: IssueKind::UnknownField,
+            message: "field is not in the schema".to_string(),
+        });
+    }
+}
+
+fn validate_schema(
+    schema: &Value,
+    value: &Value,
+    pointer: &mut String,
+    issues: &mut Vec<Issue>,
+) -> Evaluated {
+    let mut evaluated = Evaluated::default();
+    let Value::Object(schema) = schema else {
+        // `true` accepts anything, `false` nothing
+        if schema == &Value::Bool(false) {
+            mismatch(issues, pointer, "no value is allowed here".to_string());
+        }
+        evaluated.all = true;
+        return evaluated;
+    };
+    if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+        let name = reference.trim_start_matches("#/components/schemas/");
+        match schemas().get(name) {
+            Some(target) => evaluated.merge(validate_schema(target, value, pointer, issues)),
+            None => mismatch(issues, pointer, format!("unknown schema `{reference}`")),
+        }
+    }
+
+    if let Some(types) = schema.get("type") {
+        let matches = match types {
+            Value::String(name) => has_type(value, name),
+            Value::Array(names) => names
+                .iter()
+                .filter_map(Value::as_str)
+                .any(|name| has_type(value, name)),
+            _ => true,
+        };
+        if !matches {
+            let expected = match types {
+                Value::Array(names) => names
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .collect::<Vec<_>>()
+                    .join(" or "),
+                types => types.as_str().unwrap_or_default().to_string(),
+            };
+            let message = format!("expected {expected}, found {}", type_name(value));
+            mismatch(issues, pointer, message);
+            // The other keywords would only repeat the mismatch
+            return evaluated;
+        }
+    }
+    if let Some(expected) = schema.get("const") {
+        if value != expected {
+            mismatch(
+                issues,
+                pointer,
+                format!("expected {expected}, found {value}"),
+            );
+        }
+    }
+    if let Some(Value::Array(allowed)) = schema.get("enum") {
+        if !allowed.contains(value) {
+            let message = format!("{value} is not one of {}", Value::from(allowed.clone()));
+            mismatch(issues, pointer, message);
+        }
+    }
+    check_number(schema, value, |message| mismatch(issues, pointer, message));
+
+    if let Value::Object(map) = value {
+        evaluated.merge(check_object(schema, map, pointer, issues));
+    }
+    if let Value::Array(items) = value {
+        check_array(schema, items, pointer, issues);
+    }
+
+    if let Some(Value::Array(all)) = schema.get("allOf") {
+        for subschema in all {
+            evaluated.merge(validate_schema(subschema, value, pointer, issues));
+        }
+    }
+    for keyword in ["oneOf", "anyOf"] {
+        if let Some(Value::Array(variants)) = schema.get(keyword) {
+            evaluated.merge(check_variants(variants, value, pointer, issues));
+        }
+    }
+    evaluated
+}
+
+/// Check that `value` matches one of `variants`, reporting the issues of the closest one.
+///
+/// The closest variant is the one declaring the most fields of the value, then the one
+/// with the fewest mismatches, so that a broken field of an account is reported as such
+/// rather than as the fields missing for another kind of query result.
+fn check_variants(
+    variants: &[Value],
+    value: &Value,
+    pointer: &mut String,
+    issues: &mut Vec<Issue>,
+) -> Evaluated {
+    let mut closest: Option<((usize, usize), Vec<Issue>, Evaluated)> = None;
+    for variant in variants {
+        let mut variant_issues = Vec::new();
+        let evaluated = validate_schema(variant, value, pointer, &mut variant_issues);
+        let mismatches = count_mismatches(&variant_issues);
+        if mismatches == 0 {
+            issues.extend(variant_issues);
+            return evaluated;
+        }
+        let distance = (usize::MAX - evaluated.names.len(), mismatches);
+        if closest
+            .as_ref()
+            .is_none_or(|(closest, _, _)| distance < *closest)
+        {
+            closest = Some((distance, variant_issues, evaluated));
+        }
+    }
+    match closest {
+        Some((_, variant_issues, evaluated)) => {
+            mismatch(
+                issues,
+                pointer,
+                format!("matches none of {} variants, closest:", variants.len()),
+            );
+            issues.extend(variant_issues);
+            evaluated
+        }
+        None => Evaluated::default(),
+    }
+}
+
+fn count_mismatches(issues: &[Issue]) -> usize {
+    issues
+        .iter()
+        .filter(|issue| issue.kind == IssueKind::Mismatch)
+        .count()
+}
+
+fn check_object(
+    schema: &Map<String, Value>,
+    map: &Map<String, Value>,
+    pointer: &mut String,
+    issues: &mut Vec<Issue>,
+) -> Evaluated {
+    let mut evaluated = Evaluated::default();
+    if let Some(Value::Array(required)) = schema.get("required") {
+        for name in required.iter().filter_map(Value::as_str) {
+            if !map.contains_key(name) {
+                mismatch(issues, pointer, format!("missing required field `{name}`"));
+            }
+        }
+    }
+    if let Some(Value::Object(properties)) = schema.get("properties") {
+        evaluated.declared = true;
+        for (name, property) in properties {
+            if let Some(value) = map.get(name) {
+                evaluated.names.insert(name.clone());
+                at(pointer, name, |pointer| {
+                    check(property, value, pointer, issues)
+                });
+            }
+        }
+    }
+    if let Some(Value::Object(patterns)) = schema.get("patternProperties") {
+        evaluated.declared = true;
+        for (pattern, property) in patterns {
+            let Ok(regex) = regress::Regex::new(pattern) else {
+                continue;
+            };
+            for (name, value) in map {
+                if regex.find(name).is_some() {
+                    evaluated.names.insert(name.clone());
+                    at(pointer, name, |pointer| {
+                        check(property, value, pointer, issues)
+                    });
+                }
+            }
+        }
+    }
+    if let Some(additional) = schema.get("additionalProperties") {
+        evaluated.declared = true;
+        for (name, value) in map {
+            if evaluated.names.contains(name) {
+                continue;
+            }
+            if additional == &Value::Bool(false) {
+                at(pointer, name, |pointer| {
+                    mismatch(issues, pointer, "field is not allowed".to_string())
+                });
+            } else {
+                at(pointer, name, |pointer| {
+                    check(additional, value, pointer, issues)
+                });
+            }
+        }
+        // Extra fields are either rejected above or validated, never unknown
+        evaluated.all = true;
+    }
+    evaluated
+}
+
+fn check_array(
+    schema: &Map<String, Value>,
+    items: &[Value],
+    pointer: &mut String,
+    issues: &mut Vec<Issue>,
+) {
+    let len = items.len() as u64;
+    if let Some(min) = schema.get("minItems").and_then(Value::as_u64) {
+        if len < min {
+            mismatch(
+                issues,
+                pointer,
+                format!("expected at least {min} items, found {len}"),
+            );
+        }
+    }
+    if let Some(max) = schema.get("maxItems").and_then(Value::as_u64) {
+        if len > max {
+            mismatch(
+                issues,
+                pointer,
+                format!("expected at most {max} items, found {len}"),
+            );
+        }
+    }
+    for (index, item) in items.iter().enumerate() {
+        let item_schema = match schema.get("items") {
+            // Tuples list a schema per position
+            Some(Value::Array(tuple)) => tuple.get(index),
+            item_schema => item_schema,
+        };
+        if let Some(item_schema) = item_schema {
+            at(pointer, &index.to_string(), |pointer| {
+                check(item_schema, item, pointer, issues)
+            });
+        }
+    }
+}
+
+/// Check `minimum`, `maximum` and the integer formats, reporting the first violation
+fn check_number(schema: &Map<String, Value>, value: &Value, mut fail: impl FnMut(String)) {
+    let Some(number) = value.as_f64() else {
+        return;
+    };
+    if let Some(min) = schema.get("minimum").and_then(Value::as_f64) {
+        if number < min {
+            return fail(format!("{value} is less than the minimum {min}"));
+        }
+    }
+    if let Some(max) = schema.get("maximum").and_then(Value::as_f64) {
+        if number > max {
+            return fail(format!("{value} is greater than the maximum {max}"));
+        }
+    }
+    let (min, max): (i128, i128) = match schema.get("format").and_then(Value::as_str) {
+        Some("uint8") => (0, u8::MAX.into()),
+        Some("uint16") => (0, u16::MAX.into()),
+        Some("uint32") => (0, u32::MAX.into()),
+        Some("uint64" | "uint") => (0, u64::MAX.into()),
+        Some("int32") => (i32::MIN.into(), i32::MAX.into()),
+        Some("int64") => (i64::MIN.into(), i64::MAX.into()),
+        _ => return,
+    };
+    let integer = value
+        .as_i64()
+        .map(i128::from)
+        .or_else(|| value.as_u64().map(i128::from));
+    match integer {
+        Some(integer) if (min..=max).contains(&integer) => {}
+        _ => fail(format!(
+            "{value} does not fit in {}",
+            schema["format"].as_str().unwrap_or_default()
+        )),
+    }
+}
+
+fn has_type(value: &Value, name: &str) -> bool {
+    match name {
+        "null" => value.is_null(),
+        "boolean" => value.is_boolean(),
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "string" => value.is_string(),
+        "number" => value.is_number(),
+        "integer" => value.is_i64() || value.is_u64(),
+        _ => true,
+    }
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(number) if number.is_f64() => "number",
+        Value::Number(_) => "integer",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+fn mismatch(issues: &mut Vec<Issue>, pointer: &str, message: String) {
+    issues.push(Issue {
+        pointer: pointer.to_string(),
+        kind: IssueKind::Mismatch,
+        message,
+    });
+}
+
+/// Run `f` with `pointer` extended by the reference token `token`
+fn at<T>(pointer: &mut String, token: &str, f: impl FnOnce(&mut String) -> T) -> T {
+    let len = pointer.len();
+    pointer.push('/');
+    pointer.push_str(&escape(token));
+    let result = f(pointer);
+    pointer.truncate(len);
+    result
+}
+
+/// Escape a JSON pointer reference token
+fn escape(token: &str) -> String {
+    token.replace('~', "~0").replace('/', "~1")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn pointers(issues: &[Issue], kind: IssueKind) -> Vec<&str> {
+        issues
+            .iter()
+            .filter(|issue| issue.kind == kind)
+            .map(|issue| issue.pointer.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_validate_response() {
+        let valid = json!({ "gas_price": "100" });
+        assert_eq!(validate_response("gas_price", &valid), Some(vec![]));
+        assert_eq!(validate_response("no_such_method", &valid), None);
+
+        let issues = validate_response("gas_price", &json!({ "gas_price": 100 })).unwrap();
+        assert_eq!(
+            issues[0].to_string(),
+            "/gas_price: expected string, found integer"
+        );
+        let issues = validate_response("gas_price", &json!({})).unwrap();
+        assert_eq!(
+            issues[0].to_string(),
+            "/: missing required field `gas_price`"
+        );
+    }
+
+    #[test]
+    fn test_validate_account_view() {
+        let account = json!({
+            "amount": "10",
+            "locked": "0",
+            "code_hash": "11111111111111111111111111111111",
+            "storage_usage": 182,
+            "storage_paid_at": 0,
+            "block_height": 5,
+            "block_hash": "11111111111111111111111111111111",
+        });
+        assert_eq!(validate("RpcQueryResponse", &account), Some(vec![]));
+
+        let mut changed = account.clone();
+        changed["storage_usage"] = json!(-1);
+        changed["shiny_new_field"] = json!(true);
+        let issues = validate("RpcQueryResponse", &changed).unwrap();
+        assert_eq!(
+            pointers(&issues, IssueKind::UnknownField),
+            ["/shiny_new_field"]
+        );
+        assert_eq!(
+            issues[1].to_string(),
+            "/storage_usage: -1 is less than the minimum 0"
+        );
+    }
+
+    #[test]
+    fn test_validate_nested() {
+        let issues = validate(
+            "CatchupStatusView",
+            &json!({
+                "blocks_to_catchup": [],
+                "shard_sync_status": { "0": "done", "x/y": "?" },
+                "sync_block_hash": "11111111111111111111111111111111",
+                "sync_block_height": 1,
+            }),
+        )
+        .unwrap();
+        assert_eq!(
+            pointers(&issues, IssueKind::Mismatch),
+            ["/shard_sync_status/x~1y"]
+        );
+
+        let issues = validate(
+            "RpcGasPriceResponse",
+            &json!({ "gas_price": "1", "extra": { "nested": 1 } }),
+        )
+        .unwrap();
+        assert_eq!(pointers(&issues, IssueKind::UnknownField), ["/extra"]);
+    }
+
+    #[cfg(feature = "testing")]
+    #[tokio::test]
+    async fn test_client_validation() {
+        use crate::types::{BlockId, RpcGasPriceRequest};
+
+        let server = crate::testing::MockServer::start().await;
+        let request = || RpcGasPriceRequest {
+            block_id: Some(BlockId::BlockHeight(1)),
+        };
+        server
+            .mock("gas_price")
+            .respond(json!({ "gas_price": "1", "extra": true }));
+        server
+            .mock("gas_price")
+            .match_params(json!({ "block_id": 1 }))
+            .respond(json!({ "gas_price": 1, "extra": true }));
+
+        let client = server.client().with_validation(Validation::Fail);
+        let Err(crate::client::Error::Validation(error)) = client.gas_price(request()).await else {
+            panic!("expected a validation error");
+        };
+        assert_eq!(error.method, "gas_price");
+        assert_eq!(
+            error.to_string(),
+            "response to `gas_price` does not match the schema: \
+             /gas_price: expected string, found integer; /extra: field is not in the schema"
+        );
+        // Unknown fields alone are only warnings
+        assert!(client.take_validation_warnings().is_empty());
+        let gas_price = client
+            .gas_price(RpcGasPriceRequest { block_id: None })
+            .await;
+        assert_eq!(gas_price.unwrap().gas_price.0, "1");
+        let warnings = client.take_validation_warnings();
+        assert_eq!(warnings.len(), 1);
+        assert!(!warnings[0].has_mismatches());
+
+        // Warnings leave the failure to deserialization
+        let client = server.client().with_validation(Validation::Warn);
+        let error = client.gas_price(request()).await;
+        assert!(matches!(error, Err(crate::client::Error::Decode(_))));
+        let warnings = client.take_validation_warnings();
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].has_mismatches());
+        assert!(client.take_validation_warnings().is_empty());
+    }
+
+    /// The fake chain answers with the generated types, which must match the schema
+    #[cfg(feature = "testing")]
+    #[tokio::test]
+    async fn test_fake_chain_responses_match_schema() {
+        use crate::testing::chain::{public_key, Action, FakeChain, Transaction, NEAR};
+
+        let secret_key = [1; 32];
+        let chain = FakeChain::new();
+        chain.add_account("alice.test", 100 * NEAR, public_key(&secret_key));
+        let client = chain.client().with_validation(Validation::Fail);
+
+        let transaction = Transaction {
+            signer_id: "alice.test".to_string(),
+            public_key: public_key(&secret_key),
+            nonce: 1,
+            receiver_id: "bob.alice.test".to_string(),
+            block_hash: chain.head_hash(),
+            actions: vec![Action::CreateAccount, Action::Transfer { deposit: NEAR }],
+        };
+        let signed = transaction.sign(&secret_key);
+        let methods = [
+            (
+                "send_tx",
+                json!({ "signed_tx_base64": signed, "wait_until": "FINAL" }),
+            ),
+            ("block", json!({ "finality": "final" })),
+            ("gas_price", json!({ "block_id": null })),
+            (
+                "query",
+                json!({ "request_type": "view_account", "account_id": "bob.alice.test", "finality": "final" }),
+            ),
+            (
+                "query",
+                json!({ "request_type": "view_access_key_list", "account_id": "alice.test", "finality": "final" }),
+            ),
+            (
+                "tx",
+                json!({ "tx_hash": transaction.hash(), "sender_account_id": "alice.test" }),
+            ),
+        ];
+        for (method, params) in methods {
+            let result = client.call_raw(method, params).await;
+            let result = result.unwrap_or_else(|error| panic!("{method}: {error}"));
+            assert_eq!(validate_response(method, &result), Some(vec![]), "{method}");
+        }
+    }
+}
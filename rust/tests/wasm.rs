@@ -0,0 +1,140 @@
+//! Client tests on wasm32, run in Node against a local mock server:
+//!
+//! ```sh
+//! cargo test --target wasm32-unknown-unknown --no-default-features --features client --test wasm
+//! ```
+//!
+//! `wasm-bindgen-test-runner` from `wasm-bindgen-cli` must be installed.
+#![cfg(target_arch = "wasm32")]
+
+use near_rpc_client::client::{Error, HttpErrorKind};
+use near_rpc_client::types::*;
+use near_rpc_client::NearRpcClient;
+use serde_json::{json, Value};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::JsFuture;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen(inline_js = r#"
+export function startMock(replies) {
+    const http = globalThis.process.getBuiltinModule('node:http');
+    replies = JSON.parse(replies);
+    const server = http.createServer((request, response) => {
+        let body = '';
+        request.on('data', (chunk) => body += chunk);
+        request.on('end', () => {
+            const call = JSON.parse(body);
+            const reply = replies[call.method];
+            if (reply.status) {
+                response.writeHead(reply.status, reply.headers);
+                response.end(reply.body);
+            } else {
+                response.writeHead(200, { 'content-type': 'application/json' });
+                response.end(JSON.stringify({ jsonrpc: '2.0', id: call.id, ...reply }));
+            }
+        });
+    });
+    server.unref();
+    return new Promise((resolve) => server.listen(0, '127.0.0.1', () => {
+        resolve(`http://127.0.0.1:${server.address().port}`);
+    }));
+}
+"#)]
+extern "C" {
+    /// Serve `replies`, the JSON-RPC `result` or `error` per method or a raw HTTP reply
+    #[wasm_bindgen(js_name = startMock)]
+    fn start_mock(replies: &str) -> js_sys::Promise;
+}
+
+async fn mock(replies: Value) -> NearRpcClient {
+    let url = JsFuture::from(start_mock(&replies.to_string())).await.unwrap();
+    NearRpcClient::new(url.as_string().unwrap())
+}
+
+#[wasm_bindgen_test]
+async fn test_typed_call() {
+    let client = mock(json!({ "gas_price": { "result": { "gas_price": "100000000" } } })).await;
+    let response = client
+        .gas_price(RpcGasPriceRequest { block_id: None })
+        .await
+        .unwrap();
+    assert_eq!(response.gas_price.0, "100000000");
+}
+
+#[wasm_bindgen_test]
+async fn test_rpc_error() {
+    let client = mock(json!({
+        "block": {
+            "error": {
+                "code": -32000,
+                "message": "Server error",
+                "name": "HANDLER_ERROR",
+                "cause": { "name": "UNKNOWN_BLOCK", "info": {} },
+            },
+        },
+    }))
+    .await;
+    let request = RpcBlockRequest::BlockId(BlockId::BlockHeight(1));
+    let Err(Error::Rpc(error)) = client.block(request).await else {
+        panic!("expected an RPC error");
+    };
+    assert_eq!(error.cause.unwrap().name, "UNKNOWN_BLOCK");
+}
+
+#[wasm_bindgen_test]
+async fn test_http_status() {
+    let client = mock(json!({
+        "status": {
+            "status": 429,
+            "headers": { "retry-after": "5" },
+            "body": "Too many requests",
+        },
+    }))
+    .await;
+    let error = client.status().await.unwrap_err();
+    assert_eq!(error.http_error_kind(), Some(HttpErrorKind::RateLimited));
+    let Error::HttpStatus { retry_after, .. } = error else {
+        panic!("expected an HTTP status error");
+    };
+    assert_eq!(retry_after, Some(std::time::Duration::from_secs(5)));
+}
+
+#[wasm_bindgen_test]
+async fn test_connection_refused() {
+    // Nothing listens on port 1
+    let client = NearRpcClient::new("http://127.0.0.1:1");
+    assert!(matches!(client.health().await, Err(Error::Http(_))));
+}
+
+/// Metrics time requests, which must not use `std::time::Instant` on wasm32
+#[wasm_bindgen_test]
+async fn test_metrics_hook() {
+    use near_rpc_client::metrics::{MetricsHook, Outcome, Request};
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    #[derive(Default)]
+    struct Recorder(Mutex<Vec<String>>);
+
+    impl MetricsHook for Recorder {
+        fn request_started(&self, _: Request<'_>) {}
+
+        fn request_finished(&self, request: Request<'_>, _: Duration, outcome: Outcome<'_>) {
+            let ok = matches!(outcome, Outcome::Success);
+            self.0
+                .lock()
+                .unwrap()
+                .push(format!("{} {ok}", request.method));
+        }
+    }
+
+    let recorder = Arc::new(Recorder::default());
+    let client = mock(json!({ "gas_price": { "result": { "gas_price": "100000000" } } }))
+        .await
+        .with_metrics(recorder.clone());
+    client
+        .gas_price(RpcGasPriceRequest { block_id: None })
+        .await
+        .unwrap();
+    assert_eq!(*recorder.0.lock().unwrap(), ["gas_price true"]);
+}
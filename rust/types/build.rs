@@ -0,0 +1,509 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::Path;
+
+use quote::{quote, ToTokens};
+use syn::visit::Visit;
+
+/// Rarely used types, dropped with the types only they use without their feature
+const RARE_TYPES: &[(&str, &str)] = &[
+    ("genesis-config", "GenesisConfig"),
+    ("client-config", "RpcClientConfigResponse"),
+];
+
+fn main() {
+    let openrpc_path = Path::new("../../shared/openrpc.json");
+    let out_path = Path::new("src/generated.rs");
+
+    println!("cargo:rerun-if-changed={}", openrpc_path.display());
+    println!("cargo:rerun-if-changed=build.rs");
+
+    // Read the OpenRPC spec
+    let openrpc_content = fs::read_to_string(openrpc_path).expect("Failed to read openrpc.json");
+    let openrpc: serde_json::Value =
+        serde_json::from_str(&openrpc_content).expect("Failed to parse openrpc.json");
+
+    // Extract the JSON Schema from OpenRPC's components.schemas
+    let schemas = openrpc
+        .get("components")
+        .and_then(|c| c.get("schemas"))
+        .expect("OpenRPC must have components.schemas");
+
+    // Create a JSON Schema document with definitions
+    let json_schema = serde_json::json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "definitions": schemas
+    });
+
+    // Convert $refs from OpenRPC format to JSON Schema format
+    let json_schema_str = serde_json::to_string(&json_schema)
+        .expect("Failed to serialize schema")
+        .replace("#/components/schemas/", "#/definitions/");
+
+    let mut schema: serde_json::Value =
+        serde_json::from_str(&json_schema_str).expect("Failed to parse converted schema");
+
+    // Preprocess: expand allOf[$ref, $ref] into oneOf with cartesian product
+    // This helps typify generate better enum variants instead of merging
+    expand_allof_refs(&mut schema);
+
+    // Generate Rust types with typify, with `BTreeMap`s as `HashMap` needs std
+    let mut settings = typify::TypeSpaceSettings::default();
+    settings.with_map_type("::alloc::collections::BTreeMap".to_string());
+    let mut type_space = typify::TypeSpace::new(&settings);
+    type_space
+        .add_root_schema(serde_json::from_value(schema).expect("Failed to convert schema"))
+        .expect("Failed to add schema to type space");
+
+    let mut file: syn::File =
+        syn::parse2(type_space.to_stream()).expect("Failed to parse generated types");
+    gate_rare_types(&mut file);
+    import_alloc(&mut file);
+
+    // Format with prettyplease
+    let code = port_to_no_std(&prettyplease::unparse(&file));
+    let formatted = prettyplease_format(&code).expect("Failed to parse ported types");
+
+    // Strip verbose JSON schema doc blocks and fix doctests
+    let stripped = strip_json_schema_docs(&formatted);
+
+    fs::write(out_path, stripped).expect("Failed to write generated.rs");
+}
+
+fn prettyplease_format(code: &str) -> Option<String> {
+    let syntax_tree = syn::parse_file(code).ok()?;
+    Some(prettyplease::unparse(&syntax_tree))
+}
+
+/// Put each type of [`RARE_TYPES`], the types only it uses, and the impls and serde defaults
+/// naming them behind `#[cfg(feature = ...)]`
+fn gate_rare_types(file: &mut syn::File) {
+    // Types named by the definition of each type
+    let mut uses = BTreeMap::new();
+    for item in &file.items {
+        let name = match item {
+            syn::Item::Struct(item) => &item.ident,
+            syn::Item::Enum(item) => &item.ident,
+            syn::Item::Type(item) => &item.ident,
+            _ => continue,
+        };
+        uses.insert(name.to_string(), type_names(item));
+    }
+    let reachable = |mut stack: Vec<String>| {
+        let mut seen = BTreeSet::new();
+        while let Some(name) = stack.pop() {
+            if let Some(names) = uses.get(&name) {
+                if seen.insert(name) {
+                    stack.extend(names.iter().cloned());
+                }
+            }
+        }
+        seen
+    };
+    let rare: Vec<_> = RARE_TYPES
+        .iter()
+        .map(|&(feature, root)| (feature, reachable(vec![root.to_string()])))
+        .collect();
+    let common = reachable(
+        uses.keys()
+            .filter(|name| !rare.iter().any(|(_, types)| types.contains(*name)))
+            .cloned()
+            .collect(),
+    );
+    // Features enabling each rare type, which several roots may use
+    let mut features: BTreeMap<String, BTreeSet<&str>> = BTreeMap::new();
+    for (feature, types) in &rare {
+        for name in types.difference(&common) {
+            features.entry(name.clone()).or_default().insert(feature);
+        }
+    }
+
+    // Items need one of the features of each rare type they name
+    let mut needs = Vec::new();
+    for item in &mut file.items {
+        let item_needs: BTreeSet<BTreeSet<&str>> = match item {
+            syn::Item::Mod(_) => BTreeSet::new(),
+            _ => type_names(item)
+                .iter()
+                .filter_map(|name| features.get(name).cloned())
+                .collect(),
+        };
+        needs.push((item.to_token_stream().to_string(), item_needs.clone()));
+        add_cfg(item, &item_needs);
+    }
+
+    // Serde defaults are needed by the items naming them in `#[serde(default = ...)]`
+    for item in &mut file.items {
+        let syn::Item::Mod(module) = item else {
+            continue;
+        };
+        let Some((_, defaults)) = module
+            .content
+            .as_mut()
+            .filter(|_| module.ident == "defaults")
+        else {
+            continue;
+        };
+        for default in defaults {
+            let syn::Item::Fn(function) = default else {
+                continue;
+            };
+            let path = format!("defaults::{}", function.sig.ident);
+            let users: Vec<_> = needs
+                .iter()
+                .filter(|(code, _)| {
+                    code.contains(&format!("{path}\"")) || code.contains(&format!("{path}::"))
+                })
+                .map(|(_, needs)| needs)
+                .collect();
+            if users.is_empty() || users.iter().any(|needs| needs.is_empty()) {
+                continue;
+            }
+            let any: BTreeSet<&str> = users.into_iter().flatten().flatten().copied().collect();
+            add_cfg(default, &BTreeSet::from([any]));
+        }
+    }
+}
+
+/// Names of the generated types an item refers to, as `Name` or `super::Name`
+fn type_names(item: &syn::Item) -> BTreeSet<String> {
+    #[derive(Default)]
+    struct Names(BTreeSet<String>);
+
+    impl<'ast> Visit<'ast> for Names {
+        fn visit_path(&mut self, path: &'ast syn::Path) {
+            let segments: Vec<_> = path.segments.iter().map(|s| &s.ident).collect();
+            match segments.as_slice() {
+                [name] if path.leading_colon.is_none() => self.0.insert(name.to_string()),
+                [parent, name] if *parent == "super" => self.0.insert(name.to_string()),
+                _ => false,
+            };
+            syn::visit::visit_path(self, path);
+        }
+    }
+
+    let mut names = Names::default();
+    names.visit_item(item);
+    names.0
+}
+
+/// Enable `item` only when, for each set of `needs`, one of its features is on
+fn add_cfg(item: &mut syn::Item, needs: &BTreeSet<BTreeSet<&str>>) {
+    let any: Vec<_> = needs
+        .iter()
+        .map(|features| match features.len() {
+            1 => quote!(feature = #(#features)*),
+            _ => quote!(any(#(feature = #features),*)),
+        })
+        .collect();
+    let cfg: syn::Attribute = match any.as_slice() {
+        [] => return,
+        [one] => syn::parse_quote!(#[cfg(#one)]),
+        _ => syn::parse_quote!(#[cfg(all(#(#any),*))]),
+    };
+    let attrs = match item {
+        syn::Item::Struct(item) => &mut item.attrs,
+        syn::Item::Enum(item) => &mut item.attrs,
+        syn::Item::Type(item) => &mut item.attrs,
+        syn::Item::Impl(item) => &mut item.attrs,
+        syn::Item::Fn(item) => &mut item.attrs,
+        _ => panic!("unexpected generated item naming a rare type"),
+    };
+    // After the documentation
+    let at = attrs
+        .iter()
+        .take_while(|attr| attr.path().is_ident("doc"))
+        .count();
+    attrs.insert(at, cfg);
+}
+
+/// Import the `alloc` items the generated code uses without a path, missing from the `no_std`
+/// prelude
+fn import_alloc(file: &mut syn::File) {
+    file.items.insert(
+        0,
+        syn::parse_quote!(
+            use ::alloc::string::{String, ToString};
+        ),
+    );
+    for item in &mut file.items {
+        let syn::Item::Mod(module) = item else {
+            continue;
+        };
+        let import: syn::Item = match module.ident.to_string().as_str() {
+            "error" => syn::parse_quote!(
+                use ::alloc::string::String;
+            ),
+            "defaults" => syn::parse_quote!(
+                use ::alloc::string::ToString;
+            ),
+            _ => continue,
+        };
+        if let Some((_, items)) = &mut module.content {
+            items.insert(0, import);
+        }
+    }
+}
+
+/// Replace the `std` paths of the generated code with `core` and `alloc` ones, `chrono`
+/// timestamps with `crate::DateTime`, and `regress` pattern checks with plain Rust
+fn port_to_no_std(code: &str) -> String {
+    let mut code = code
+        .replace(
+            "::chrono::DateTime<::chrono::offset::Utc>",
+            "crate::DateTime",
+        )
+        .replace("vec![", "::alloc::vec![");
+    for module in ["string", "vec", "borrow", "collections"] {
+        code = code.replace(
+            &format!("::std::{module}::"),
+            &format!("::alloc::{module}::"),
+        );
+    }
+    let code = replace_pattern_checks(&code.replace("::std::", "::core::"));
+    assert!(
+        !code.contains("::regress::") && !code.contains("::chrono::"),
+        "generated types use a crate other than serde and serde_json"
+    );
+    code
+}
+
+/// Replace the lazily compiled `regress::Regex` of each pattern check with an equivalent
+/// condition, so that the types do not need a regex engine.
+///
+/// Panics on a pattern without a translation: add one when the schema gets a new pattern.
+fn replace_pattern_checks(code: &str) -> String {
+    let mut code = code.to_string();
+    while let Some(start) = code.find("static PATTERN:") {
+        let end = start + code[start..].find("});").expect("PATTERN is a LazyLock") + 3;
+        let pattern = code[start..end]
+            .split('"')
+            .nth(1)
+            .expect("PATTERN has a literal");
+        // Patterns as they are escaped in the generated code
+        let condition = match pattern {
+            r"^\\d+$" => "value.is_empty() || !value.bytes().all(|b| b.is_ascii_digit())",
+            _ => panic!("no translation of the pattern {pattern:?} in build.rs"),
+        };
+        let check = "PATTERN.find(value).is_none()";
+        let at = end + code[end..].find(check).expect("PATTERN is checked");
+        code.replace_range(at..at + check.len(), condition);
+        code.replace_range(start..end, "");
+    }
+    code
+}
+
+/// Expand allOf schemas containing only $ref items into oneOf with cartesian product.
+///
+/// This transforms:
+/// ```json
+/// { "allOf": [{ "$ref": "#/definitions/A" }, { "$ref": "#/definitions/B" }] }
+/// ```
+///
+/// Into an expanded oneOf where A and B's oneOf variants are combined.
+/// This helps typify generate meaningful enum variant names instead of Variant0, Variant1, etc.
+fn expand_allof_refs(schema: &mut serde_json::Value) {
+    if let serde_json::Value::Object(obj) = schema {
+        // First, recursively process nested schemas
+        for (_, v) in obj.iter_mut() {
+            expand_allof_refs(v);
+        }
+
+        // Process definitions
+        if let Some(serde_json::Value::Object(defs)) = obj.get_mut("definitions") {
+            let def_keys: Vec<String> = defs.keys().cloned().collect();
+
+            for key in def_keys {
+                if let Some(def) = defs.get(&key).cloned() {
+                    if let Some(expanded) = try_expand_allof(&def, defs) {
+                        defs.insert(key, expanded);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Try to expand an allOf schema into oneOf with cartesian product.
+/// Returns None if not applicable.
+fn try_expand_allof(
+    schema: &serde_json::Value,
+    definitions: &serde_json::Map<String, serde_json::Value>,
+) -> Option<serde_json::Value> {
+    let obj = schema.as_object()?;
+    let all_of = obj.get("allOf")?.as_array()?;
+
+    // Check if all items are $ref
+    let refs: Vec<&str> = all_of
+        .iter()
+        .filter_map(|item| {
+            item.as_object()
+                .and_then(|o| o.get("$ref"))
+                .and_then(|r| r.as_str())
+        })
+        .collect();
+
+    if refs.len() != all_of.len() || refs.len() < 2 {
+        return None; // Not all items are refs, or too few
+    }
+
+    // Resolve each ref and get its oneOf variants (or treat as single variant)
+    let mut variant_groups: Vec<Vec<serde_json::Value>> = Vec::new();
+
+    for ref_path in &refs {
+        let ref_name = ref_path.strip_prefix("#/definitions/")?;
+        let ref_schema = definitions.get(ref_name)?;
+
+        let variants = if let Some(one_of) = ref_schema.get("oneOf").and_then(|v| v.as_array()) {
+            one_of.clone()
+        } else {
+            // Single variant - wrap in array
+            vec![ref_schema.clone()]
+        };
+
+        variant_groups.push(variants);
+    }
+
+    // Generate cartesian product
+    let mut combined_variants = vec![serde_json::json!({})];
+
+    for group in variant_groups {
+        let mut new_combined = Vec::new();
+        for existing in &combined_variants {
+            for variant in &group {
+                if let Some(merged) = merge_variant_properties(existing, variant) {
+                    new_combined.push(merged);
+                }
+            }
+        }
+        combined_variants = new_combined;
+    }
+
+    // Build the expanded oneOf schema
+    let title = obj.get("title").cloned();
+    let mut result = serde_json::json!({
+        "oneOf": combined_variants
+    });
+
+    if let Some(t) = title {
+        result
+            .as_object_mut()
+            .unwrap()
+            .insert("title".to_string(), t);
+    }
+
+    Some(result)
+}
+
+/// Merge properties from two variant objects into one.
+fn merge_variant_properties(
+    a: &serde_json::Value,
+    b: &serde_json::Value,
+) -> Option<serde_json::Value> {
+    let a_obj = a.as_object()?;
+    let b_obj = b.as_object()?;
+
+    let mut result = serde_json::Map::new();
+
+    // Merge properties
+    let mut props = serde_json::Map::new();
+    if let Some(a_props) = a_obj.get("properties").and_then(|p| p.as_object()) {
+        for (k, v) in a_props {
+            props.insert(k.clone(), v.clone());
+        }
+    }
+    if let Some(b_props) = b_obj.get("properties").and_then(|p| p.as_object()) {
+        for (k, v) in b_props {
+            props.insert(k.clone(), v.clone());
+        }
+    }
+    if !props.is_empty() {
+        result.insert("properties".to_string(), serde_json::Value::Object(props));
+    }
+
+    // Merge required arrays
+    let mut required: Vec<String> = Vec::new();
+    if let Some(a_req) = a_obj.get("required").and_then(|r| r.as_array()) {
+        for r in a_req {
+            if let Some(s) = r.as_str() {
+                if !required.contains(&s.to_string()) {
+                    required.push(s.to_string());
+                }
+            }
+        }
+    }
+    if let Some(b_req) = b_obj.get("required").and_then(|r| r.as_array()) {
+        for r in b_req {
+            if let Some(s) = r.as_str() {
+                if !required.contains(&s.to_string()) {
+                    required.push(s.to_string());
+                }
+            }
+        }
+    }
+    if !required.is_empty() {
+        result.insert("required".to_string(), serde_json::json!(required));
+    }
+
+    // Generate a title from combined variant titles
+    let a_title = a_obj.get("title").and_then(|t| t.as_str()).unwrap_or("");
+    let b_title = b_obj.get("title").and_then(|t| t.as_str()).unwrap_or("");
+    if !a_title.is_empty() || !b_title.is_empty() {
+        let combined_title = if a_title.is_empty() {
+            b_title.to_string()
+        } else if b_title.is_empty() {
+            a_title.to_string()
+        } else {
+            format!("{}{}", b_title, a_title)
+        };
+        result.insert("title".to_string(), serde_json::json!(combined_title));
+    }
+
+    result.insert("type".to_string(), serde_json::json!("object"));
+
+    Some(serde_json::Value::Object(result))
+}
+
+/// Strip JSON schema documentation blocks from generated code.
+/// These are collapsible `<details>` blocks containing raw JSON schemas
+/// that bloat the generated file significantly.
+/// Also marks code examples with `ignore` to prevent doctest failures.
+fn strip_json_schema_docs(code: &str) -> String {
+    let mut result = Vec::new();
+    let mut in_details_block = false;
+
+    for line in code.lines() {
+        let trimmed = line.trim();
+
+        // Detect start of JSON schema details block
+        if trimmed.starts_with("///")
+            && trimmed.contains("<details>")
+            && trimmed.contains("JSON schema")
+        {
+            in_details_block = true;
+            continue;
+        }
+
+        // Detect end of details block
+        if in_details_block && trimmed.starts_with("///") && trimmed.contains("</details>") {
+            in_details_block = false;
+            continue;
+        }
+
+        // Skip lines inside details block
+        if in_details_block {
+            continue;
+        }
+
+        // Mark code blocks with `ignore` to prevent doctest failures
+        // (These examples reference external crates like near_account_id)
+        // Handle both /// ``` and bare ``` in block comments
+        if trimmed == "/// ```" || trimmed == "///```" || trimmed == "```" {
+            result.push(line.replace("```", "```ignore"));
+        } else {
+            result.push(line.to_string());
+        }
+    }
+
+    result.join("\n")
+}
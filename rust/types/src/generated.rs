@@ -1,22 +1,24 @@
+use ::alloc::string::{String, ToString};
 /// Error types.
 pub mod error {
+    use ::alloc::string::String;
     /// Error from a `TryFrom` or `FromStr` implementation.
-    pub struct ConversionError(::std::borrow::Cow<'static, str>);
-    impl ::std::error::Error for ConversionError {}
-    impl ::std::fmt::Display for ConversionError {
+    pub struct ConversionError(::alloc::borrow::Cow<'static, str>);
+    impl ::core::error::Error for ConversionError {}
+    impl ::core::fmt::Display for ConversionError {
         fn fmt(
             &self,
-            f: &mut ::std::fmt::Formatter<'_>,
-        ) -> Result<(), ::std::fmt::Error> {
-            ::std::fmt::Display::fmt(&self.0, f)
+            f: &mut ::core::fmt::Formatter<'_>,
+        ) -> Result<(), ::core::fmt::Error> {
+            ::core::fmt::Display::fmt(&self.0, f)
         }
     }
-    impl ::std::fmt::Debug for ConversionError {
+    impl ::core::fmt::Debug for ConversionError {
         fn fmt(
             &self,
-            f: &mut ::std::fmt::Formatter<'_>,
-        ) -> Result<(), ::std::fmt::Error> {
-            ::std::fmt::Debug::fmt(&self.0, f)
+            f: &mut ::core::fmt::Formatter<'_>,
+        ) -> Result<(), ::core::fmt::Error> {
+            ::core::fmt::Debug::fmt(&self.0, f)
         }
     }
     impl From<&'static str> for ConversionError {
@@ -67,7 +69,7 @@ pub struct AccessKeyInfoView {
 ///
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub struct AccessKeyList {
-    pub keys: ::std::vec::Vec<AccessKeyInfoView>,
+    pub keys: ::alloc::vec::Vec<AccessKeyInfoView>,
 }
 ///Defines permissions for AccessKey
 ///
@@ -84,17 +86,18 @@ Gas keys are a kind of access keys with a prepaid balance to pay for gas.*/
 Gas keys are a kind of access keys with a prepaid balance to pay for gas.*/
     GasKeyFullAccess(GasKeyInfo),
 }
-impl ::std::convert::From<FunctionCallPermission> for AccessKeyPermission {
+impl ::core::convert::From<FunctionCallPermission> for AccessKeyPermission {
     fn from(value: FunctionCallPermission) -> Self {
         Self::FunctionCall(value)
     }
 }
-impl ::std::convert::From<(GasKeyInfo, FunctionCallPermission)> for AccessKeyPermission {
+impl ::core::convert::From<(GasKeyInfo, FunctionCallPermission)>
+for AccessKeyPermission {
     fn from(value: (GasKeyInfo, FunctionCallPermission)) -> Self {
         Self::GasKeyFunctionCall(value.0, value.1)
     }
 }
-impl ::std::convert::From<GasKeyInfo> for AccessKeyPermission {
+impl ::core::convert::From<GasKeyInfo> for AccessKeyPermission {
     fn from(value: GasKeyInfo) -> Self {
         Self::GasKeyFullAccess(value)
     }
@@ -105,18 +108,18 @@ impl ::std::convert::From<GasKeyInfo> for AccessKeyPermission {
 pub enum AccessKeyPermissionView {
     FullAccess,
     FunctionCall {
-        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-        allowance: ::std::option::Option<NearToken>,
-        method_names: ::std::vec::Vec<::std::string::String>,
-        receiver_id: ::std::string::String,
+        #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+        allowance: ::core::option::Option<NearToken>,
+        method_names: ::alloc::vec::Vec<::alloc::string::String>,
+        receiver_id: ::alloc::string::String,
     },
     GasKeyFunctionCall {
-        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-        allowance: ::std::option::Option<NearToken>,
+        #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+        allowance: ::core::option::Option<NearToken>,
         balance: NearToken,
-        method_names: ::std::vec::Vec<::std::string::String>,
+        method_names: ::alloc::vec::Vec<::alloc::string::String>,
         num_nonces: u16,
-        receiver_id: ::std::string::String,
+        receiver_id: ::alloc::string::String,
     },
     GasKeyFullAccess { balance: NearToken, num_nonces: u16 },
 }
@@ -159,9 +162,9 @@ pub struct AccountDataView {
 (this list may include the validator node itself).
 TIER1 nodes should connect to one of the proxies to sent TIER1
 messages to the validator.*/
-    pub proxies: ::std::vec::Vec<Tier1ProxyView>,
+    pub proxies: ::alloc::vec::Vec<Tier1ProxyView>,
     ///UTC timestamp of when the AccountData has been signed.
-    pub timestamp: ::std::string::String,
+    pub timestamp: ::alloc::string::String,
 }
 /**NEAR Account Identifier.
 
@@ -193,31 +196,31 @@ assert!("ƒelicia.near".parse::<AccountId>().is_err()); // (ƒ is not f)
     PartialOrd
 )]
 #[serde(transparent)]
-pub struct AccountId(pub ::std::string::String);
-impl ::std::ops::Deref for AccountId {
-    type Target = ::std::string::String;
-    fn deref(&self) -> &::std::string::String {
+pub struct AccountId(pub ::alloc::string::String);
+impl ::core::ops::Deref for AccountId {
+    type Target = ::alloc::string::String;
+    fn deref(&self) -> &::alloc::string::String {
         &self.0
     }
 }
-impl ::std::convert::From<AccountId> for ::std::string::String {
+impl ::core::convert::From<AccountId> for ::alloc::string::String {
     fn from(value: AccountId) -> Self {
         value.0
     }
 }
-impl ::std::convert::From<::std::string::String> for AccountId {
-    fn from(value: ::std::string::String) -> Self {
+impl ::core::convert::From<::alloc::string::String> for AccountId {
+    fn from(value: ::alloc::string::String) -> Self {
         Self(value)
     }
 }
-impl ::std::str::FromStr for AccountId {
-    type Err = ::std::convert::Infallible;
-    fn from_str(value: &str) -> ::std::result::Result<Self, Self::Err> {
+impl ::core::str::FromStr for AccountId {
+    type Err = ::core::convert::Infallible;
+    fn from_str(value: &str) -> ::core::result::Result<Self, Self::Err> {
         Ok(Self(value.to_string()))
     }
 }
-impl ::std::fmt::Display for AccountId {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for AccountId {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         self.0.fmt(f)
     }
 }
@@ -226,42 +229,42 @@ impl ::std::fmt::Display for AccountId {
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 #[serde(transparent)]
 pub struct AccountIdValidityRulesVersion(pub u8);
-impl ::std::ops::Deref for AccountIdValidityRulesVersion {
+impl ::core::ops::Deref for AccountIdValidityRulesVersion {
     type Target = u8;
     fn deref(&self) -> &u8 {
         &self.0
     }
 }
-impl ::std::convert::From<AccountIdValidityRulesVersion> for u8 {
+impl ::core::convert::From<AccountIdValidityRulesVersion> for u8 {
     fn from(value: AccountIdValidityRulesVersion) -> Self {
         value.0
     }
 }
-impl ::std::convert::From<u8> for AccountIdValidityRulesVersion {
+impl ::core::convert::From<u8> for AccountIdValidityRulesVersion {
     fn from(value: u8) -> Self {
         Self(value)
     }
 }
-impl ::std::str::FromStr for AccountIdValidityRulesVersion {
-    type Err = <u8 as ::std::str::FromStr>::Err;
-    fn from_str(value: &str) -> ::std::result::Result<Self, Self::Err> {
+impl ::core::str::FromStr for AccountIdValidityRulesVersion {
+    type Err = <u8 as ::core::str::FromStr>::Err;
+    fn from_str(value: &str) -> ::core::result::Result<Self, Self::Err> {
         Ok(Self(value.parse()?))
     }
 }
-impl ::std::convert::TryFrom<&str> for AccountIdValidityRulesVersion {
-    type Error = <u8 as ::std::str::FromStr>::Err;
-    fn try_from(value: &str) -> ::std::result::Result<Self, Self::Error> {
+impl ::core::convert::TryFrom<&str> for AccountIdValidityRulesVersion {
+    type Error = <u8 as ::core::str::FromStr>::Err;
+    fn try_from(value: &str) -> ::core::result::Result<Self, Self::Error> {
         value.parse()
     }
 }
-impl ::std::convert::TryFrom<String> for AccountIdValidityRulesVersion {
-    type Error = <u8 as ::std::str::FromStr>::Err;
-    fn try_from(value: String) -> ::std::result::Result<Self, Self::Error> {
+impl ::core::convert::TryFrom<String> for AccountIdValidityRulesVersion {
+    type Error = <u8 as ::core::str::FromStr>::Err;
+    fn try_from(value: String) -> ::core::result::Result<Self, Self::Error> {
         value.parse()
     }
 }
-impl ::std::fmt::Display for AccountIdValidityRulesVersion {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for AccountIdValidityRulesVersion {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         self.0.fmt(f)
     }
 }
@@ -279,10 +282,10 @@ pub struct AccountInfo {
 pub struct AccountView {
     pub amount: NearToken,
     pub code_hash: CryptoHash,
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub global_contract_account_id: ::std::option::Option<AccountId>,
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub global_contract_hash: ::std::option::Option<CryptoHash>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub global_contract_account_id: ::core::option::Option<AccountId>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub global_contract_hash: ::core::option::Option<CryptoHash>,
     pub locked: NearToken,
     ///TODO(2271): deprecated.
     #[serde(default)]
@@ -331,8 +334,8 @@ This is on top of the costs for the actions inside the delegate action.*/
 pub struct ActionError {
     /**Index of the failed action in the transaction.
 Action index is not defined if ActionError.kind is `ActionErrorKind::LackBalanceForState`*/
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub index: ::std::option::Option<u64>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub index: ::core::option::Option<u64>,
     ///The kind of ActionError happened
     pub kind: ActionErrorKind,
 }
@@ -422,17 +425,17 @@ TODO(#8598): This error is named very poorly. A better name would be
         required: NearToken,
     },
 }
-impl ::std::convert::From<FunctionCallError> for ActionErrorKind {
+impl ::core::convert::From<FunctionCallError> for ActionErrorKind {
     fn from(value: FunctionCallError) -> Self {
         Self::FunctionCallError(value)
     }
 }
-impl ::std::convert::From<ReceiptValidationError> for ActionErrorKind {
+impl ::core::convert::From<ReceiptValidationError> for ActionErrorKind {
     fn from(value: ReceiptValidationError) -> Self {
         Self::NewReceiptValidationError(value)
     }
 }
-impl ::std::convert::From<InvalidAccessKeyError> for ActionErrorKind {
+impl ::core::convert::From<InvalidAccessKeyError> for ActionErrorKind {
     fn from(value: InvalidAccessKeyError) -> Self {
         Self::DelegateActionAccessKeyError(value)
     }
@@ -442,12 +445,12 @@ impl ::std::convert::From<InvalidAccessKeyError> for ActionErrorKind {
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub enum ActionView {
     CreateAccount,
-    DeployContract { code: ::std::string::String },
+    DeployContract { code: ::alloc::string::String },
     FunctionCall {
         args: FunctionArgs,
         deposit: NearToken,
         gas: NearGas,
-        method_name: ::std::string::String,
+        method_name: ::alloc::string::String,
     },
     Transfer { deposit: NearToken },
     Stake { public_key: PublicKey, stake: NearToken },
@@ -455,13 +458,16 @@ pub enum ActionView {
     DeleteKey { public_key: PublicKey },
     DeleteAccount { beneficiary_id: AccountId },
     Delegate { delegate_action: DelegateAction, signature: Signature },
-    DeployGlobalContract { code: ::std::string::String },
-    DeployGlobalContractByAccountId { code: ::std::string::String },
+    DeployGlobalContract { code: ::alloc::string::String },
+    DeployGlobalContractByAccountId { code: ::alloc::string::String },
     UseGlobalContract { code_hash: CryptoHash },
     UseGlobalContractByAccountId { account_id: AccountId },
     DeterministicStateInit {
         code: GlobalContractIdentifierView,
-        data: ::std::collections::HashMap<::std::string::String, ::std::string::String>,
+        data: ::alloc::collections::BTreeMap<
+            ::alloc::string::String,
+            ::alloc::string::String,
+        >,
         deposit: NearToken,
     },
     TransferToGasKey { deposit: NearToken, public_key: PublicKey },
@@ -484,7 +490,7 @@ pub enum ActionsValidationError {
     ///Integer overflow during a compute.
     IntegerOverflow,
     ///Invalid account ID.
-    InvalidAccountId { account_id: ::std::string::String },
+    InvalidAccountId { account_id: ::alloc::string::String },
     ///The size of the contract code exceeded the limit in a DeployContract action.
     ContractSizeExceeded { limit: u64, size: u64 },
     ///The length of the method name exceeded the limit in a Function Call action.
@@ -503,7 +509,10 @@ does not support.
 Note: we stringify the protocol feature name instead of using
 `ProtocolFeature` here because we don't want to leak the internals of
 that type into observable borsh serialization.*/
-    UnsupportedProtocolFeature { protocol_feature: ::std::string::String, version: u32 },
+    UnsupportedProtocolFeature {
+        protocol_feature: ::alloc::string::String,
+        version: u32,
+    },
     InvalidDeterministicStateInitReceiver {
         derived_id: AccountId,
         receiver_id: AccountId,
@@ -549,7 +558,7 @@ Describes how much the shard would like to send to other shards.*/
 pub enum BandwidthRequests {
     V1(BandwidthRequestsV1),
 }
-impl ::std::convert::From<BandwidthRequestsV1> for BandwidthRequests {
+impl ::core::convert::From<BandwidthRequestsV1> for BandwidthRequests {
     fn from(value: BandwidthRequestsV1) -> Self {
         Self::V1(value)
     }
@@ -558,7 +567,7 @@ impl ::std::convert::From<BandwidthRequestsV1> for BandwidthRequests {
 ///
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub struct BandwidthRequestsV1 {
-    pub requests: ::std::vec::Vec<BandwidthRequest>,
+    pub requests: ::alloc::vec::Vec<BandwidthRequest>,
 }
 ///A part of a state for the current head of a light client. More info [here](https://nomicon.io/ChainSpec/LightClient).
 ///
@@ -577,30 +586,32 @@ pub struct BlockHeaderInnerLiteView {
     pub prev_state_root: CryptoHash,
     ///Legacy json number. Should not be used.
     pub timestamp: u64,
-    pub timestamp_nanosec: ::std::string::String,
+    pub timestamp_nanosec: ::alloc::string::String,
 }
 ///Contains main info about the block.
 ///
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub struct BlockHeaderView {
-    pub approvals: ::std::vec::Vec<::std::option::Option<Signature>>,
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub block_body_hash: ::std::option::Option<CryptoHash>,
+    pub approvals: ::alloc::vec::Vec<::core::option::Option<Signature>>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub block_body_hash: ::core::option::Option<CryptoHash>,
     pub block_merkle_root: CryptoHash,
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub block_ordinal: ::std::option::Option<u64>,
-    pub challenges_result: ::std::vec::Vec<SlashedValidator>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub block_ordinal: ::core::option::Option<u64>,
+    pub challenges_result: ::alloc::vec::Vec<SlashedValidator>,
     pub challenges_root: CryptoHash,
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub chunk_endorsements: ::std::option::Option<::std::vec::Vec<::std::vec::Vec<u8>>>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub chunk_endorsements: ::core::option::Option<
+        ::alloc::vec::Vec<::alloc::vec::Vec<u8>>,
+    >,
     pub chunk_headers_root: CryptoHash,
-    pub chunk_mask: ::std::vec::Vec<bool>,
+    pub chunk_mask: ::alloc::vec::Vec<bool>,
     pub chunk_receipts_root: CryptoHash,
     pub chunk_tx_root: CryptoHash,
     pub chunks_included: u64,
     pub epoch_id: CryptoHash,
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub epoch_sync_data_hash: ::std::option::Option<CryptoHash>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub epoch_sync_data_hash: ::core::option::Option<CryptoHash>,
     pub gas_price: NearToken,
     pub hash: CryptoHash,
     pub height: u64,
@@ -612,22 +623,22 @@ pub struct BlockHeaderView {
     pub outcome_root: CryptoHash,
     ///The hash of the previous Block
     pub prev_hash: CryptoHash,
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub prev_height: ::std::option::Option<u64>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub prev_height: ::core::option::Option<u64>,
     pub prev_state_root: CryptoHash,
     pub random_value: CryptoHash,
     ///TODO(2271): deprecated.
     #[serde(default = "defaults::block_header_view_rent_paid")]
     pub rent_paid: NearToken,
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub shard_split: ::std::option::Option<(ShardId, AccountId)>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub shard_split: ::core::option::Option<(ShardId, AccountId)>,
     ///Signature of the block producer.
     pub signature: Signature,
     ///Legacy json number. Should not be used.
     pub timestamp: u64,
-    pub timestamp_nanosec: ::std::string::String,
+    pub timestamp_nanosec: ::alloc::string::String,
     pub total_supply: NearToken,
-    pub validator_proposals: ::std::vec::Vec<ValidatorStakeView>,
+    pub validator_proposals: ::alloc::vec::Vec<ValidatorStakeView>,
     ///TODO(2271): deprecated.
     #[serde(default = "defaults::block_header_view_validator_reward")]
     pub validator_reward: NearToken,
@@ -643,20 +654,20 @@ pub struct BlockHeightRange {
 ///
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 #[serde(transparent)]
-pub struct BlockHeightRanges(pub ::std::vec::Vec<BlockHeightRange>);
-impl ::std::ops::Deref for BlockHeightRanges {
-    type Target = ::std::vec::Vec<BlockHeightRange>;
-    fn deref(&self) -> &::std::vec::Vec<BlockHeightRange> {
+pub struct BlockHeightRanges(pub ::alloc::vec::Vec<BlockHeightRange>);
+impl ::core::ops::Deref for BlockHeightRanges {
+    type Target = ::alloc::vec::Vec<BlockHeightRange>;
+    fn deref(&self) -> &::alloc::vec::Vec<BlockHeightRange> {
         &self.0
     }
 }
-impl ::std::convert::From<BlockHeightRanges> for ::std::vec::Vec<BlockHeightRange> {
+impl ::core::convert::From<BlockHeightRanges> for ::alloc::vec::Vec<BlockHeightRange> {
     fn from(value: BlockHeightRanges) -> Self {
         value.0
     }
 }
-impl ::std::convert::From<::std::vec::Vec<BlockHeightRange>> for BlockHeightRanges {
-    fn from(value: ::std::vec::Vec<BlockHeightRange>) -> Self {
+impl ::core::convert::From<::alloc::vec::Vec<BlockHeightRange>> for BlockHeightRanges {
+    fn from(value: ::alloc::vec::Vec<BlockHeightRange>) -> Self {
         Self(value)
     }
 }
@@ -668,20 +679,20 @@ pub enum BlockId {
     BlockHeight(u64),
     CryptoHash(CryptoHash),
 }
-impl ::std::fmt::Display for BlockId {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for BlockId {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         match self {
             Self::BlockHeight(x) => x.fmt(f),
             Self::CryptoHash(x) => x.fmt(f),
         }
     }
 }
-impl ::std::convert::From<u64> for BlockId {
+impl ::core::convert::From<u64> for BlockId {
     fn from(value: u64) -> Self {
         Self::BlockHeight(value)
     }
 }
-impl ::std::convert::From<CryptoHash> for BlockId {
+impl ::core::convert::From<CryptoHash> for BlockId {
     fn from(value: CryptoHash) -> Self {
         Self::CryptoHash(value)
     }
@@ -697,17 +708,17 @@ pub enum BlockReference {
     #[serde(rename = "sync_checkpoint")]
     SyncCheckpoint(SyncCheckpoint),
 }
-impl ::std::convert::From<BlockId> for BlockReference {
+impl ::core::convert::From<BlockId> for BlockReference {
     fn from(value: BlockId) -> Self {
         Self::BlockId(value)
     }
 }
-impl ::std::convert::From<Finality> for BlockReference {
+impl ::core::convert::From<Finality> for BlockReference {
     fn from(value: Finality) -> Self {
         Self::Finality(value)
     }
 }
-impl ::std::convert::From<SyncCheckpoint> for BlockReference {
+impl ::core::convert::From<SyncCheckpoint> for BlockReference {
     fn from(value: SyncCheckpoint) -> Self {
         Self::SyncCheckpoint(value)
     }
@@ -723,17 +734,17 @@ pub struct BlockStatusView {
 ///
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub struct CallResult {
-    pub logs: ::std::vec::Vec<::std::string::String>,
-    pub result: ::std::vec::Vec<u8>,
+    pub logs: ::alloc::vec::Vec<::alloc::string::String>,
+    pub result: ::alloc::vec::Vec<u8>,
 }
 ///Status of the [catchup](https://near.github.io/nearcore/architecture/how/sync.html#catchup) process
 ///
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub struct CatchupStatusView {
-    pub blocks_to_catchup: ::std::vec::Vec<BlockStatusView>,
-    pub shard_sync_status: ::std::collections::HashMap<
+    pub blocks_to_catchup: ::alloc::vec::Vec<BlockStatusView>,
+    pub shard_sync_status: ::alloc::collections::BTreeMap<
         CatchupStatusViewShardSyncStatusKey,
-        ::std::string::String,
+        ::alloc::string::String,
     >,
     pub sync_block_hash: CryptoHash,
     pub sync_block_height: u64,
@@ -742,64 +753,62 @@ pub struct CatchupStatusView {
 ///
 #[derive(::serde::Serialize, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 #[serde(transparent)]
-pub struct CatchupStatusViewShardSyncStatusKey(::std::string::String);
-impl ::std::ops::Deref for CatchupStatusViewShardSyncStatusKey {
-    type Target = ::std::string::String;
-    fn deref(&self) -> &::std::string::String {
+pub struct CatchupStatusViewShardSyncStatusKey(::alloc::string::String);
+impl ::core::ops::Deref for CatchupStatusViewShardSyncStatusKey {
+    type Target = ::alloc::string::String;
+    fn deref(&self) -> &::alloc::string::String {
         &self.0
     }
 }
-impl ::std::convert::From<CatchupStatusViewShardSyncStatusKey>
-for ::std::string::String {
+impl ::core::convert::From<CatchupStatusViewShardSyncStatusKey>
+for ::alloc::string::String {
     fn from(value: CatchupStatusViewShardSyncStatusKey) -> Self {
         value.0
     }
 }
-impl ::std::str::FromStr for CatchupStatusViewShardSyncStatusKey {
+impl ::core::str::FromStr for CatchupStatusViewShardSyncStatusKey {
     type Err = self::error::ConversionError;
     fn from_str(
         value: &str,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
-        static PATTERN: ::std::sync::LazyLock<::regress::Regex> = ::std::sync::LazyLock::new(||
-        { ::regress::Regex::new("^\\d+$").unwrap() });
-        if PATTERN.find(value).is_none() {
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
+        if value.is_empty() || !value.bytes().all(|b| b.is_ascii_digit()) {
             return Err("doesn't match pattern \"^\\d+$\"".into());
         }
         Ok(Self(value.to_string()))
     }
 }
-impl ::std::convert::TryFrom<&str> for CatchupStatusViewShardSyncStatusKey {
+impl ::core::convert::TryFrom<&str> for CatchupStatusViewShardSyncStatusKey {
     type Error = self::error::ConversionError;
     fn try_from(
         value: &str,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
         value.parse()
     }
 }
-impl ::std::convert::TryFrom<&::std::string::String>
+impl ::core::convert::TryFrom<&::alloc::string::String>
 for CatchupStatusViewShardSyncStatusKey {
     type Error = self::error::ConversionError;
     fn try_from(
-        value: &::std::string::String,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value: &::alloc::string::String,
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
         value.parse()
     }
 }
-impl ::std::convert::TryFrom<::std::string::String>
+impl ::core::convert::TryFrom<::alloc::string::String>
 for CatchupStatusViewShardSyncStatusKey {
     type Error = self::error::ConversionError;
     fn try_from(
-        value: ::std::string::String,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value: ::alloc::string::String,
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
         value.parse()
     }
 }
 impl<'de> ::serde::Deserialize<'de> for CatchupStatusViewShardSyncStatusKey {
-    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+    fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
     where
         D: ::serde::Deserializer<'de>,
     {
-        ::std::string::String::deserialize(deserializer)?
+        ::alloc::string::String::deserialize(deserializer)?
             .parse()
             .map_err(|e: self::error::ConversionError| {
                 <D::Error as ::serde::de::Error>::custom(e.to_string())
@@ -811,6 +820,7 @@ This allows nodes to push and pull chunks from a central stream.
 The two benefits of this approach are: (1) less request/response traffic
 on the peer-to-peer network and (2) lower latency for RPC nodes indexing the chain.*/
 ///
+#[cfg(feature = "client-config")]
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub struct ChunkDistributionNetworkConfig {
     pub enabled: bool,
@@ -821,20 +831,20 @@ pub struct ChunkDistributionNetworkConfig {
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub struct ChunkDistributionUris {
     ///URI for pulling chunks from the stream.
-    pub get: ::std::string::String,
+    pub get: ::alloc::string::String,
     ///URI for publishing chunks to the stream.
-    pub set: ::std::string::String,
+    pub set: ::alloc::string::String,
 }
 ///Contains main info about the chunk.
 ///
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub struct ChunkHeaderView {
     pub balance_burnt: NearToken,
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub bandwidth_requests: ::std::option::Option<BandwidthRequests>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub bandwidth_requests: ::core::option::Option<BandwidthRequests>,
     pub chunk_hash: CryptoHash,
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub congestion_info: ::std::option::Option<CongestionInfoView>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub congestion_info: ::core::option::Option<CongestionInfoView>,
     pub encoded_length: u64,
     pub encoded_merkle_root: CryptoHash,
     pub gas_limit: NearGas,
@@ -851,7 +861,7 @@ pub struct ChunkHeaderView {
     pub shard_id: ShardId,
     pub signature: Signature,
     pub tx_root: CryptoHash,
-    pub validator_proposals: ::std::vec::Vec<ValidatorStakeView>,
+    pub validator_proposals: ::alloc::vec::Vec<ValidatorStakeView>,
     ///TODO(2271): deprecated.
     #[serde(default = "defaults::chunk_header_view_validator_reward")]
     pub validator_reward: NearToken,
@@ -860,6 +870,7 @@ pub struct ChunkHeaderView {
 writes chunk-related data based on the tracked shards. This config also controls additional archival
 behavior such as block data and polling interval.*/
 ///
+#[cfg(feature = "client-config")]
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub struct CloudArchivalWriterConfig {
     ///Determines whether block-related data should be written to cloud storage.
@@ -869,7 +880,8 @@ pub struct CloudArchivalWriterConfig {
     #[serde(default = "defaults::cloud_archival_writer_config_polling_interval")]
     pub polling_interval: DurationAsStdSchemaProvider,
 }
-impl ::std::default::Default for CloudArchivalWriterConfig {
+#[cfg(feature = "client-config")]
+impl ::core::default::Default for CloudArchivalWriterConfig {
     fn default() -> Self {
         Self {
             archive_block_data: Default::default(),
@@ -886,9 +898,9 @@ pub enum CompilationError {
     /**This is for defense in depth.
 We expect our runtime-independent preparation code to fully catch all invalid wasms,
 but, if it ever misses something we’ll emit this error*/
-    WasmerCompileError { msg: ::std::string::String },
+    WasmerCompileError { msg: ::alloc::string::String },
 }
-impl ::std::convert::From<PrepareError> for CompilationError {
+impl ::core::convert::From<PrepareError> for CompilationError {
     fn from(value: PrepareError) -> Self {
         Self::PrepareError(value)
     }
@@ -944,6 +956,8 @@ It limits the total sum of outgoing receipts, not individual receipts.*/
 This limit is pretty small to keep the size of source_receipt_proofs under control.
 It limits the total sum of outgoing receipts, not individual receipts.*/
     pub outgoing_receipts_usual_size_limit: u64,
+    /**How much congestion a shard can tolerate before it stops all shards from
+accepting new transactions with the receiver set to the congested shard.*/
     pub reject_tx_congestion_threshold: f64,
 }
 ///Stores the congestion level of a shard. More info about congestion [here](https://near.github.io/nearcore/architecture/how/receipt-congestion.html?highlight=congestion#receipt-congestion)
@@ -951,49 +965,50 @@ It limits the total sum of outgoing receipts, not individual receipts.*/
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub struct CongestionInfoView {
     pub allowed_shard: u16,
-    pub buffered_receipts_gas: ::std::string::String,
-    pub delayed_receipts_gas: ::std::string::String,
+    pub buffered_receipts_gas: ::alloc::string::String,
+    pub delayed_receipts_gas: ::alloc::string::String,
     pub receipt_bytes: u64,
 }
 ///A view of the contract code.
 ///
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub struct ContractCodeView {
-    pub code_base64: ::std::string::String,
+    pub code_base64: ::alloc::string::String,
     pub hash: CryptoHash,
 }
 ///Shows gas profile. More info [here](https://near.github.io/nearcore/architecture/gas/gas_profile.html?highlight=WASM_HOST_COST#example-transaction-gas-profile).
 ///
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub struct CostGasUsed {
-    pub cost: ::std::string::String,
+    pub cost: ::alloc::string::String,
     ///Either ACTION_COST or WASM_HOST_COST.
-    pub cost_category: ::std::string::String,
-    pub gas_used: ::std::string::String,
+    pub cost_category: ::alloc::string::String,
+    pub gas_used: ::alloc::string::String,
 }
 ///Create account action
 ///
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 #[serde(transparent)]
 pub struct CreateAccountAction(
-    pub ::serde_json::Map<::std::string::String, ::serde_json::Value>,
+    pub ::serde_json::Map<::alloc::string::String, ::serde_json::Value>,
 );
-impl ::std::ops::Deref for CreateAccountAction {
-    type Target = ::serde_json::Map<::std::string::String, ::serde_json::Value>;
-    fn deref(&self) -> &::serde_json::Map<::std::string::String, ::serde_json::Value> {
+impl ::core::ops::Deref for CreateAccountAction {
+    type Target = ::serde_json::Map<::alloc::string::String, ::serde_json::Value>;
+    fn deref(&self) -> &::serde_json::Map<::alloc::string::String, ::serde_json::Value> {
         &self.0
     }
 }
-impl ::std::convert::From<CreateAccountAction>
-for ::serde_json::Map<::std::string::String, ::serde_json::Value> {
+impl ::core::convert::From<CreateAccountAction>
+for ::serde_json::Map<::alloc::string::String, ::serde_json::Value> {
     fn from(value: CreateAccountAction) -> Self {
         value.0
     }
 }
-impl ::std::convert::From<::serde_json::Map<::std::string::String, ::serde_json::Value>>
-for CreateAccountAction {
+impl ::core::convert::From<
+    ::serde_json::Map<::alloc::string::String, ::serde_json::Value>,
+> for CreateAccountAction {
     fn from(
-        value: ::serde_json::Map<::std::string::String, ::serde_json::Value>,
+        value: ::serde_json::Map<::alloc::string::String, ::serde_json::Value>,
     ) -> Self {
         Self(value)
     }
@@ -1012,31 +1027,31 @@ for CreateAccountAction {
     PartialOrd
 )]
 #[serde(transparent)]
-pub struct CryptoHash(pub ::std::string::String);
-impl ::std::ops::Deref for CryptoHash {
-    type Target = ::std::string::String;
-    fn deref(&self) -> &::std::string::String {
+pub struct CryptoHash(pub ::alloc::string::String);
+impl ::core::ops::Deref for CryptoHash {
+    type Target = ::alloc::string::String;
+    fn deref(&self) -> &::alloc::string::String {
         &self.0
     }
 }
-impl ::std::convert::From<CryptoHash> for ::std::string::String {
+impl ::core::convert::From<CryptoHash> for ::alloc::string::String {
     fn from(value: CryptoHash) -> Self {
         value.0
     }
 }
-impl ::std::convert::From<::std::string::String> for CryptoHash {
-    fn from(value: ::std::string::String) -> Self {
+impl ::core::convert::From<::alloc::string::String> for CryptoHash {
+    fn from(value: ::alloc::string::String) -> Self {
         Self(value)
     }
 }
-impl ::std::str::FromStr for CryptoHash {
-    type Err = ::std::convert::Infallible;
-    fn from_str(value: &str) -> ::std::result::Result<Self, Self::Err> {
+impl ::core::str::FromStr for CryptoHash {
+    type Err = ::core::convert::Infallible;
+    fn from_str(value: &str) -> ::core::result::Result<Self, Self::Err> {
         Ok(Self(value.to_string()))
     }
 }
-impl ::std::fmt::Display for CryptoHash {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for CryptoHash {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         self.0.fmt(f)
     }
 }
@@ -1051,29 +1066,29 @@ pub struct CurrentEpochValidatorInfo {
     pub num_expected_chunks: u64,
     /**Number of chunks this validator was expected to produce in each shard.
 Each entry in the array corresponds to the shard in the `shards_produced` array.*/
-    #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
-    pub num_expected_chunks_per_shard: ::std::vec::Vec<u64>,
+    #[serde(default, skip_serializing_if = "::alloc::vec::Vec::is_empty")]
+    pub num_expected_chunks_per_shard: ::alloc::vec::Vec<u64>,
     #[serde(default)]
     pub num_expected_endorsements: u64,
     /**Number of chunks this validator was expected to validate and endorse in each shard.
 Each entry in the array corresponds to the shard in the `shards_endorsed` array.*/
-    #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
-    pub num_expected_endorsements_per_shard: ::std::vec::Vec<u64>,
+    #[serde(default, skip_serializing_if = "::alloc::vec::Vec::is_empty")]
+    pub num_expected_endorsements_per_shard: ::alloc::vec::Vec<u64>,
     pub num_produced_blocks: u64,
     #[serde(default)]
     pub num_produced_chunks: u64,
-    #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
-    pub num_produced_chunks_per_shard: ::std::vec::Vec<u64>,
+    #[serde(default, skip_serializing_if = "::alloc::vec::Vec::is_empty")]
+    pub num_produced_chunks_per_shard: ::alloc::vec::Vec<u64>,
     #[serde(default)]
     pub num_produced_endorsements: u64,
-    #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
-    pub num_produced_endorsements_per_shard: ::std::vec::Vec<u64>,
+    #[serde(default, skip_serializing_if = "::alloc::vec::Vec::is_empty")]
+    pub num_produced_endorsements_per_shard: ::alloc::vec::Vec<u64>,
     pub public_key: PublicKey,
     ///Shards this validator is assigned to as chunk producer in the current epoch.
-    pub shards: ::std::vec::Vec<ShardId>,
+    pub shards: ::alloc::vec::Vec<ShardId>,
     ///Shards this validator is assigned to as chunk validator in the current epoch.
-    #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
-    pub shards_endorsed: ::std::vec::Vec<ShardId>,
+    #[serde(default, skip_serializing_if = "::alloc::vec::Vec::is_empty")]
+    pub shards_endorsed: ::alloc::vec::Vec<ShardId>,
     pub stake: NearToken,
 }
 ///The fees settings for a data receipt creation
@@ -1109,7 +1124,7 @@ pub struct DelegateAction {
 
 With the meta transactions MVP defined in NEP-366, nested
 DelegateActions are not allowed. A separate type is used to enforce it.*/
-    pub actions: ::std::vec::Vec<NonDelegateAction>,
+    pub actions: ::alloc::vec::Vec<NonDelegateAction>,
     ///The maximal height of the block in the blockchain below which the given DelegateAction is valid.
     pub max_block_height: u64,
     /**Nonce to ensure that the same delegate action is not sent twice by a
@@ -1141,14 +1156,14 @@ pub struct DeleteKeyAction {
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub struct DeployContractAction {
     ///WebAssembly binary
-    pub code: ::std::string::String,
+    pub code: ::alloc::string::String,
 }
 ///Deploy global contract action
 ///
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub struct DeployGlobalContractAction {
     ///WebAssembly binary
-    pub code: ::std::string::String,
+    pub code: ::alloc::string::String,
     pub deploy_mode: GlobalContractDeployMode,
 }
 ///`DetailedDebugStatus`
@@ -1156,11 +1171,11 @@ pub struct DeployGlobalContractAction {
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub struct DetailedDebugStatus {
     pub block_production_delay_millis: u64,
-    pub catchup_status: ::std::vec::Vec<CatchupStatusView>,
+    pub catchup_status: ::alloc::vec::Vec<CatchupStatusView>,
     pub current_head_status: BlockStatusView,
     pub current_header_head_status: BlockStatusView,
     pub network_info: NetworkInfoView,
-    pub sync_status: ::std::string::String,
+    pub sync_status: ::alloc::string::String,
 }
 ///`DeterministicAccountStateInit`
 ///
@@ -1168,7 +1183,7 @@ pub struct DetailedDebugStatus {
 pub enum DeterministicAccountStateInit {
     V1(DeterministicAccountStateInitV1),
 }
-impl ::std::convert::From<DeterministicAccountStateInitV1>
+impl ::core::convert::From<DeterministicAccountStateInitV1>
 for DeterministicAccountStateInit {
     fn from(value: DeterministicAccountStateInitV1) -> Self {
         Self::V1(value)
@@ -1179,7 +1194,10 @@ for DeterministicAccountStateInit {
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub struct DeterministicAccountStateInitV1 {
     pub code: GlobalContractIdentifier,
-    pub data: ::std::collections::HashMap<::std::string::String, ::std::string::String>,
+    pub data: ::alloc::collections::BTreeMap<
+        ::alloc::string::String,
+        ::alloc::string::String,
+    >,
 }
 ///`DeterministicStateInitAction`
 ///
@@ -1206,19 +1224,19 @@ pub enum Direction {
     Left,
     Right,
 }
-impl ::std::fmt::Display for Direction {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for Direction {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         match *self {
             Self::Left => f.write_str("Left"),
             Self::Right => f.write_str("Right"),
         }
     }
 }
-impl ::std::str::FromStr for Direction {
+impl ::core::str::FromStr for Direction {
     type Err = self::error::ConversionError;
     fn from_str(
         value: &str,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
         match value {
             "Left" => Ok(Self::Left),
             "Right" => Ok(Self::Right),
@@ -1226,47 +1244,48 @@ impl ::std::str::FromStr for Direction {
         }
     }
 }
-impl ::std::convert::TryFrom<&str> for Direction {
+impl ::core::convert::TryFrom<&str> for Direction {
     type Error = self::error::ConversionError;
     fn try_from(
         value: &str,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
         value.parse()
     }
 }
-impl ::std::convert::TryFrom<&::std::string::String> for Direction {
+impl ::core::convert::TryFrom<&::alloc::string::String> for Direction {
     type Error = self::error::ConversionError;
     fn try_from(
-        value: &::std::string::String,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value: &::alloc::string::String,
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
         value.parse()
     }
 }
-impl ::std::convert::TryFrom<::std::string::String> for Direction {
+impl ::core::convert::TryFrom<::alloc::string::String> for Direction {
     type Error = self::error::ConversionError;
     fn try_from(
-        value: ::std::string::String,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value: ::alloc::string::String,
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
         value.parse()
     }
 }
 ///Configures how to dump state to external storage.
 ///
+#[cfg(feature = "client-config")]
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub struct DumpConfig {
     ///Location of a json file with credentials allowing access to the bucket.
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub credentials_file: ::std::option::Option<::std::string::String>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub credentials_file: ::core::option::Option<::alloc::string::String>,
     /**How often to check if a new epoch has started.
 Feel free to set to `None`, defaults are sensible.*/
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub iteration_delay: ::std::option::Option<DurationAsStdSchemaProvider>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub iteration_delay: ::core::option::Option<DurationAsStdSchemaProvider>,
     ///Specifies where to write the obtained state parts.
     pub location: ExternalStorageLocation,
     /**Use in case a node that dumps state to the external storage
 gets in trouble.*/
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub restart_dump_for_shards: ::std::option::Option<::std::vec::Vec<ShardId>>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub restart_dump_for_shards: ::core::option::Option<::alloc::vec::Vec<ShardId>>,
 }
 ///`DurationAsStdSchemaProvider`
 ///
@@ -1282,47 +1301,48 @@ EpochId of first two epochs is 0*/
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 #[serde(transparent)]
 pub struct EpochId(pub CryptoHash);
-impl ::std::ops::Deref for EpochId {
+impl ::core::ops::Deref for EpochId {
     type Target = CryptoHash;
     fn deref(&self) -> &CryptoHash {
         &self.0
     }
 }
-impl ::std::convert::From<EpochId> for CryptoHash {
+impl ::core::convert::From<EpochId> for CryptoHash {
     fn from(value: EpochId) -> Self {
         value.0
     }
 }
-impl ::std::convert::From<CryptoHash> for EpochId {
+impl ::core::convert::From<CryptoHash> for EpochId {
     fn from(value: CryptoHash) -> Self {
         Self(value)
     }
 }
-impl ::std::str::FromStr for EpochId {
-    type Err = <CryptoHash as ::std::str::FromStr>::Err;
-    fn from_str(value: &str) -> ::std::result::Result<Self, Self::Err> {
+impl ::core::str::FromStr for EpochId {
+    type Err = <CryptoHash as ::core::str::FromStr>::Err;
+    fn from_str(value: &str) -> ::core::result::Result<Self, Self::Err> {
         Ok(Self(value.parse()?))
     }
 }
-impl ::std::convert::TryFrom<&str> for EpochId {
-    type Error = <CryptoHash as ::std::str::FromStr>::Err;
-    fn try_from(value: &str) -> ::std::result::Result<Self, Self::Error> {
+impl ::core::convert::TryFrom<&str> for EpochId {
+    type Error = <CryptoHash as ::core::str::FromStr>::Err;
+    fn try_from(value: &str) -> ::core::result::Result<Self, Self::Error> {
         value.parse()
     }
 }
-impl ::std::convert::TryFrom<String> for EpochId {
-    type Error = <CryptoHash as ::std::str::FromStr>::Err;
-    fn try_from(value: String) -> ::std::result::Result<Self, Self::Error> {
+impl ::core::convert::TryFrom<String> for EpochId {
+    type Error = <CryptoHash as ::core::str::FromStr>::Err;
+    fn try_from(value: String) -> ::core::result::Result<Self, Self::Error> {
         value.parse()
     }
 }
-impl ::std::fmt::Display for EpochId {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for EpochId {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         self.0.fmt(f)
     }
 }
 ///`EpochSyncConfig`
 ///
+#[cfg(feature = "client-config")]
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub struct EpochSyncConfig {
     /**This serves as two purposes: (1) the node will not epoch sync and instead resort to
@@ -1338,8 +1358,8 @@ if this timeout is exceeded.*/
 ///
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub struct ExecutionMetadataView {
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub gas_profile: ::std::option::Option<::std::vec::Vec<CostGasUsed>>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub gas_profile: ::core::option::Option<::alloc::vec::Vec<CostGasUsed>>,
     pub version: u32,
 }
 ///`ExecutionOutcomeView`
@@ -1352,12 +1372,12 @@ for receipt this is receiver_id.*/
     ///The amount of the gas burnt by the given transaction or receipt.
     pub gas_burnt: NearGas,
     ///Logs from this transaction or receipt.
-    pub logs: ::std::vec::Vec<::std::string::String>,
+    pub logs: ::alloc::vec::Vec<::alloc::string::String>,
     ///Execution metadata, versioned
     #[serde(default = "defaults::execution_outcome_view_metadata")]
     pub metadata: ExecutionMetadataView,
     ///Receipt IDs generated by this transaction or receipt.
-    pub receipt_ids: ::std::vec::Vec<CryptoHash>,
+    pub receipt_ids: ::alloc::vec::Vec<CryptoHash>,
     ///Execution status. Contains the result in case of successful execution.
     pub status: ExecutionStatusView,
     /**The amount of tokens burnt corresponding to the burnt gas amount.
@@ -1374,7 +1394,7 @@ pub struct ExecutionOutcomeWithIdView {
     pub block_hash: CryptoHash,
     pub id: CryptoHash,
     pub outcome: ExecutionOutcomeView,
-    pub proof: ::std::vec::Vec<MerklePathItem>,
+    pub proof: ::alloc::vec::Vec<MerklePathItem>,
 }
 ///`ExecutionStatusView`
 ///
@@ -1385,17 +1405,17 @@ pub enum ExecutionStatusView {
     ///The execution has failed.
     Failure(TxExecutionError),
     ///The final action succeeded and returned some value or an empty vec encoded in base64.
-    SuccessValue(::std::string::String),
+    SuccessValue(::alloc::string::String),
     /**The final action of the receipt returned a promise or the signed transaction was converted
 to a receipt. Contains the receipt_id of the generated receipt.*/
     SuccessReceiptId(CryptoHash),
 }
-impl ::std::convert::From<TxExecutionError> for ExecutionStatusView {
+impl ::core::convert::From<TxExecutionError> for ExecutionStatusView {
     fn from(value: TxExecutionError) -> Self {
         Self::Failure(value)
     }
 }
-impl ::std::convert::From<CryptoHash> for ExecutionStatusView {
+impl ::core::convert::From<CryptoHash> for ExecutionStatusView {
     fn from(value: CryptoHash) -> Self {
         Self::SuccessReceiptId(value)
     }
@@ -1562,6 +1582,7 @@ pub struct ExtCostsConfigView {
 }
 ///`ExternalStorageConfig`
 ///
+#[cfg(feature = "client-config")]
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub struct ExternalStorageConfig {
     /**The number of attempts the node will make to obtain a part from peers in
@@ -1585,15 +1606,15 @@ to reduce the performance impact of state sync.*/
 pub enum ExternalStorageLocation {
     S3 {
         ///Location on S3.
-        bucket: ::std::string::String,
+        bucket: ::alloc::string::String,
         ///Data may only be available in certain locations.
-        region: ::std::string::String,
+        region: ::alloc::string::String,
     },
     ///Local filesystem root for storing data.
-    Filesystem { root_dir: ::std::string::String },
+    Filesystem { root_dir: ::alloc::string::String },
     ///Google Cloud Storage bucket name.
     #[serde(rename = "GCS")]
-    Gcs { bucket: ::std::string::String },
+    Gcs { bucket: ::alloc::string::String },
 }
 /**Costs associated with an object that can only be sent over the network (and executed
 by the receiver).
@@ -1616,7 +1637,7 @@ Could be not finalized yet*/
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub struct FinalExecutionOutcomeView {
     ///The execution outcome of receipts.
-    pub receipts_outcome: ::std::vec::Vec<ExecutionOutcomeWithIdView>,
+    pub receipts_outcome: ::alloc::vec::Vec<ExecutionOutcomeWithIdView>,
     /**Execution status defined by chain.rs:get_final_transaction_result
 FinalExecutionStatus::NotStarted - the tx is not converted to the receipt yet
 FinalExecutionStatus::Started - we have at least 1 receipt, but the first leaf receipt_id (using dfs) hasn't finished the execution
@@ -1634,9 +1655,9 @@ the generated receipt.*/
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub struct FinalExecutionOutcomeWithReceiptView {
     ///Receipts generated from the transaction
-    pub receipts: ::std::vec::Vec<ReceiptView>,
+    pub receipts: ::alloc::vec::Vec<ReceiptView>,
     ///The execution outcome of receipts.
-    pub receipts_outcome: ::std::vec::Vec<ExecutionOutcomeWithIdView>,
+    pub receipts_outcome: ::alloc::vec::Vec<ExecutionOutcomeWithIdView>,
     /**Execution status defined by chain.rs:get_final_transaction_result
 FinalExecutionStatus::NotStarted - the tx is not converted to the receipt yet
 FinalExecutionStatus::Started - we have at least 1 receipt, but the first leaf receipt_id (using dfs) hasn't finished the execution
@@ -1659,9 +1680,9 @@ pub enum FinalExecutionStatus {
     ///The execution has failed with the given error.
     Failure(TxExecutionError),
     ///The execution has succeeded and returned some value or an empty vec encoded in base64.
-    SuccessValue(::std::string::String),
+    SuccessValue(::alloc::string::String),
 }
-impl ::std::convert::From<TxExecutionError> for FinalExecutionStatus {
+impl ::core::convert::From<TxExecutionError> for FinalExecutionStatus {
     fn from(value: TxExecutionError) -> Self {
         Self::Failure(value)
     }
@@ -1688,8 +1709,8 @@ pub enum Finality {
     #[serde(rename = "final")]
     Final,
 }
-impl ::std::fmt::Display for Finality {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for Finality {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         match *self {
             Self::Optimistic => f.write_str("optimistic"),
             Self::NearFinal => f.write_str("near-final"),
@@ -1697,11 +1718,11 @@ impl ::std::fmt::Display for Finality {
         }
     }
 }
-impl ::std::str::FromStr for Finality {
+impl ::core::str::FromStr for Finality {
     type Err = self::error::ConversionError;
     fn from_str(
         value: &str,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
         match value {
             "optimistic" => Ok(Self::Optimistic),
             "near-final" => Ok(Self::NearFinal),
@@ -1710,27 +1731,27 @@ impl ::std::str::FromStr for Finality {
         }
     }
 }
-impl ::std::convert::TryFrom<&str> for Finality {
+impl ::core::convert::TryFrom<&str> for Finality {
     type Error = self::error::ConversionError;
     fn try_from(
         value: &str,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
         value.parse()
     }
 }
-impl ::std::convert::TryFrom<&::std::string::String> for Finality {
+impl ::core::convert::TryFrom<&::alloc::string::String> for Finality {
     type Error = self::error::ConversionError;
     fn try_from(
-        value: &::std::string::String,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value: &::alloc::string::String,
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
         value.parse()
     }
 }
-impl ::std::convert::TryFrom<::std::string::String> for Finality {
+impl ::core::convert::TryFrom<::alloc::string::String> for Finality {
     type Error = self::error::ConversionError;
     fn try_from(
-        value: ::std::string::String,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value: ::alloc::string::String,
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
         value.parse()
     }
 }
@@ -1752,31 +1773,31 @@ transparently serialized and deserialized as a base64-encoded string when serde
     PartialOrd
 )]
 #[serde(transparent)]
-pub struct FunctionArgs(pub ::std::string::String);
-impl ::std::ops::Deref for FunctionArgs {
-    type Target = ::std::string::String;
-    fn deref(&self) -> &::std::string::String {
+pub struct FunctionArgs(pub ::alloc::string::String);
+impl ::core::ops::Deref for FunctionArgs {
+    type Target = ::alloc::string::String;
+    fn deref(&self) -> &::alloc::string::String {
         &self.0
     }
 }
-impl ::std::convert::From<FunctionArgs> for ::std::string::String {
+impl ::core::convert::From<FunctionArgs> for ::alloc::string::String {
     fn from(value: FunctionArgs) -> Self {
         value.0
     }
 }
-impl ::std::convert::From<::std::string::String> for FunctionArgs {
-    fn from(value: ::std::string::String) -> Self {
+impl ::core::convert::From<::alloc::string::String> for FunctionArgs {
+    fn from(value: ::alloc::string::String) -> Self {
         Self(value)
     }
 }
-impl ::std::str::FromStr for FunctionArgs {
-    type Err = ::std::convert::Infallible;
-    fn from_str(value: &str) -> ::std::result::Result<Self, Self::Err> {
+impl ::core::str::FromStr for FunctionArgs {
+    type Err = ::core::convert::Infallible;
+    fn from_str(value: &str) -> ::core::result::Result<Self, Self::Err> {
         Ok(Self(value.to_string()))
     }
 }
-impl ::std::fmt::Display for FunctionArgs {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for FunctionArgs {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         self.0.fmt(f)
     }
 }
@@ -1784,10 +1805,10 @@ impl ::std::fmt::Display for FunctionArgs {
 ///
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub struct FunctionCallAction {
-    pub args: ::std::string::String,
+    pub args: ::alloc::string::String,
     pub deposit: NearToken,
     pub gas: NearGas,
-    pub method_name: ::std::string::String,
+    pub method_name: ::alloc::string::String,
 }
 /**Serializable version of `near-vm-runner::FunctionCallError`.
 
@@ -1804,7 +1825,7 @@ pub enum FunctionCallError {
     /**Wasm binary env link error
 
 Note: this is only to deserialize old data, use execution error for new data*/
-    LinkError { msg: ::std::string::String },
+    LinkError { msg: ::alloc::string::String },
     ///Import/export resolve error
     MethodResolveError(MethodResolveError),
     /**A trap happened during execution of a binary
@@ -1813,24 +1834,24 @@ Note: this is only to deserialize old data, use execution error for new data*/
     WasmTrap(WasmTrap),
     ///Note: this is only to deserialize old data, use execution error for new data
     HostError(HostError),
-    ExecutionError(::std::string::String),
+    ExecutionError(::alloc::string::String),
 }
-impl ::std::convert::From<CompilationError> for FunctionCallError {
+impl ::core::convert::From<CompilationError> for FunctionCallError {
     fn from(value: CompilationError) -> Self {
         Self::CompilationError(value)
     }
 }
-impl ::std::convert::From<MethodResolveError> for FunctionCallError {
+impl ::core::convert::From<MethodResolveError> for FunctionCallError {
     fn from(value: MethodResolveError) -> Self {
         Self::MethodResolveError(value)
     }
 }
-impl ::std::convert::From<WasmTrap> for FunctionCallError {
+impl ::core::convert::From<WasmTrap> for FunctionCallError {
     fn from(value: WasmTrap) -> Self {
         Self::WasmTrap(value)
     }
 }
-impl ::std::convert::From<HostError> for FunctionCallError {
+impl ::core::convert::From<HostError> for FunctionCallError {
     fn from(value: HostError) -> Self {
         Self::HostError(value)
     }
@@ -1848,14 +1869,14 @@ decreased by the same value.
 `None` means unlimited allowance.
 NOTE: To change or increase the allowance, the old access key needs to be deleted and a new
 access key should be created.*/
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub allowance: ::std::option::Option<NearToken>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub allowance: ::core::option::Option<NearToken>,
     /**A list of method names that can be used. The access key only allows transactions with the
 function call of one of the given method names.
 Empty list means any method name can be used.*/
-    pub method_names: ::std::vec::Vec<::std::string::String>,
+    pub method_names: ::alloc::vec::Vec<::alloc::string::String>,
     ///The access key only allows transactions with the given receiver's account id.
-    pub receiver_id: ::std::string::String,
+    pub receiver_id: ::alloc::string::String,
 }
 ///`GasKeyInfo`
 ///
@@ -1866,6 +1887,7 @@ pub struct GasKeyInfo {
 }
 ///Configuration for garbage collection.
 ///
+#[cfg(feature = "client-config")]
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub struct GcConfig {
     /**Maximum number of blocks to garbage collect at every garbage collection
@@ -1883,7 +1905,8 @@ when cleaning forks during garbage collection.*/
     #[serde(default = "defaults::gc_config_gc_step_period")]
     pub gc_step_period: DurationAsStdSchemaProvider,
 }
-impl ::std::default::Default for GcConfig {
+#[cfg(feature = "client-config")]
+impl ::core::default::Default for GcConfig {
     fn default() -> Self {
         Self {
             gc_blocks_limit: defaults::default_u64::<u64, 2>(),
@@ -1895,15 +1918,16 @@ impl ::std::default::Default for GcConfig {
 }
 ///`GenesisConfig`
 ///
+#[cfg(feature = "genesis-config")]
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub struct GenesisConfig {
     ///Expected number of hidden validators per shard.
-    pub avg_hidden_validator_seats_per_shard: ::std::vec::Vec<u64>,
+    pub avg_hidden_validator_seats_per_shard: ::alloc::vec::Vec<u64>,
     ///Threshold for kicking out block producers, between 0 and 100.
     pub block_producer_kickout_threshold: u8,
     /**ID of the blockchain. This must be unique for every blockchain.
 If your testnet blockchains do not have unique chain IDs, you will have a bad time.*/
-    pub chain_id: ::std::string::String,
+    pub chain_id: ::alloc::string::String,
     /**Limits the number of shard changes in chunk producer assignments,
 if algorithm is able to choose assignment with better balance of
 number of chunk producers for shards.*/
@@ -1927,7 +1951,7 @@ number of chunk producers for shards.*/
     ///Height of genesis block.
     pub genesis_height: u64,
     ///Official time of blockchain start.
-    pub genesis_time: ::chrono::DateTime<::chrono::offset::Utc>,
+    pub genesis_time: crate::DateTime,
     pub max_gas_price: NearToken,
     ///Maximum inflation on the total supply every epoch.
     pub max_inflation_rate: [i32; 2usize],
@@ -1951,7 +1975,7 @@ See <https://github.com/near/NEPs/pull/167> for details*/
     /**Defines number of shards and number of block producer seats per each shard at genesis.
 Note: not used with protocol_feature_chunk_only_producers -- replaced by minimum_validators_per_shard
 Note: not used before as all block producers produce chunks for all shards*/
-    pub num_block_producer_seats_per_shard: ::std::vec::Vec<u64>,
+    pub num_block_producer_seats_per_shard: ::alloc::vec::Vec<u64>,
     ///Expected number of blocks per year
     pub num_blocks_per_year: u64,
     ///Deprecated.
@@ -2000,31 +2024,32 @@ If set to true, the node will use the same config override path as mainnet and t
     #[serde(default)]
     pub use_production_config: bool,
     ///List of initial validators.
-    pub validators: ::std::vec::Vec<AccountInfo>,
+    pub validators: ::alloc::vec::Vec<AccountInfo>,
 }
 ///`GenesisConfigRequest`
 ///
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 #[serde(transparent)]
 pub struct GenesisConfigRequest(
-    pub ::serde_json::Map<::std::string::String, ::serde_json::Value>,
+    pub ::serde_json::Map<::alloc::string::String, ::serde_json::Value>,
 );
-impl ::std::ops::Deref for GenesisConfigRequest {
-    type Target = ::serde_json::Map<::std::string::String, ::serde_json::Value>;
-    fn deref(&self) -> &::serde_json::Map<::std::string::String, ::serde_json::Value> {
+impl ::core::ops::Deref for GenesisConfigRequest {
+    type Target = ::serde_json::Map<::alloc::string::String, ::serde_json::Value>;
+    fn deref(&self) -> &::serde_json::Map<::alloc::string::String, ::serde_json::Value> {
         &self.0
     }
 }
-impl ::std::convert::From<GenesisConfigRequest>
-for ::serde_json::Map<::std::string::String, ::serde_json::Value> {
+impl ::core::convert::From<GenesisConfigRequest>
+for ::serde_json::Map<::alloc::string::String, ::serde_json::Value> {
     fn from(value: GenesisConfigRequest) -> Self {
         value.0
     }
 }
-impl ::std::convert::From<::serde_json::Map<::std::string::String, ::serde_json::Value>>
-for GenesisConfigRequest {
+impl ::core::convert::From<
+    ::serde_json::Map<::alloc::string::String, ::serde_json::Value>,
+> for GenesisConfigRequest {
     fn from(
-        value: ::serde_json::Map<::std::string::String, ::serde_json::Value>,
+        value: ::serde_json::Map<::alloc::string::String, ::serde_json::Value>,
     ) -> Self {
         Self(value)
     }
@@ -2053,19 +2078,19 @@ Users will be able reference it by that account id.
 This allows the owner to update the contract for all its users.*/
     AccountId,
 }
-impl ::std::fmt::Display for GlobalContractDeployMode {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for GlobalContractDeployMode {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         match *self {
             Self::CodeHash => f.write_str("CodeHash"),
             Self::AccountId => f.write_str("AccountId"),
         }
     }
 }
-impl ::std::str::FromStr for GlobalContractDeployMode {
+impl ::core::str::FromStr for GlobalContractDeployMode {
     type Err = self::error::ConversionError;
     fn from_str(
         value: &str,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
         match value {
             "CodeHash" => Ok(Self::CodeHash),
             "AccountId" => Ok(Self::AccountId),
@@ -2073,27 +2098,27 @@ impl ::std::str::FromStr for GlobalContractDeployMode {
         }
     }
 }
-impl ::std::convert::TryFrom<&str> for GlobalContractDeployMode {
+impl ::core::convert::TryFrom<&str> for GlobalContractDeployMode {
     type Error = self::error::ConversionError;
     fn try_from(
         value: &str,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
         value.parse()
     }
 }
-impl ::std::convert::TryFrom<&::std::string::String> for GlobalContractDeployMode {
+impl ::core::convert::TryFrom<&::alloc::string::String> for GlobalContractDeployMode {
     type Error = self::error::ConversionError;
     fn try_from(
-        value: &::std::string::String,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value: &::alloc::string::String,
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
         value.parse()
     }
 }
-impl ::std::convert::TryFrom<::std::string::String> for GlobalContractDeployMode {
+impl ::core::convert::TryFrom<::alloc::string::String> for GlobalContractDeployMode {
     type Error = self::error::ConversionError;
     fn try_from(
-        value: ::std::string::String,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value: ::alloc::string::String,
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
         value.parse()
     }
 }
@@ -2104,12 +2129,12 @@ pub enum GlobalContractIdentifier {
     CodeHash(CryptoHash),
     AccountId(AccountId),
 }
-impl ::std::convert::From<CryptoHash> for GlobalContractIdentifier {
+impl ::core::convert::From<CryptoHash> for GlobalContractIdentifier {
     fn from(value: CryptoHash) -> Self {
         Self::CodeHash(value)
     }
 }
-impl ::std::convert::From<AccountId> for GlobalContractIdentifier {
+impl ::core::convert::From<AccountId> for GlobalContractIdentifier {
     fn from(value: AccountId) -> Self {
         Self::AccountId(value)
     }
@@ -2123,12 +2148,12 @@ pub enum GlobalContractIdentifierView {
     #[serde(rename = "account_id")]
     AccountId(AccountId),
 }
-impl ::std::convert::From<CryptoHash> for GlobalContractIdentifierView {
+impl ::core::convert::From<CryptoHash> for GlobalContractIdentifierView {
     fn from(value: CryptoHash) -> Self {
         Self::Hash(value)
     }
 }
-impl ::std::convert::From<AccountId> for GlobalContractIdentifierView {
+impl ::core::convert::From<AccountId> for GlobalContractIdentifierView {
     fn from(value: AccountId) -> Self {
         Self::AccountId(value)
     }
@@ -2152,7 +2177,7 @@ pub enum HostError {
     ///Tried to call an empty method name
     EmptyMethodName,
     ///Smart contract panicked
-    GuestPanic { panic_msg: ::std::string::String },
+    GuestPanic { panic_msg: ::alloc::string::String },
     ///IntegerOverflow happened during a contract execution
     IntegerOverflow,
     ///`promise_idx` does not correspond to existing promises
@@ -2180,7 +2205,7 @@ pub enum HostError {
     ///VM Logic provided an invalid public key
     InvalidPublicKey,
     ///`method_name` is not allowed in view calls
-    ProhibitedInView { method_name: ::std::string::String },
+    ProhibitedInView { method_name: ::alloc::string::String },
     ///The total number of logs will exceed the limit.
     NumberOfLogsExceeded { limit: u64 },
     ///The storage key length exceeded the limit.
@@ -2201,16 +2226,16 @@ pub enum HostError {
     ///The contract size for DeployContract action exceeded the limit.
     ContractSizeExceeded { limit: u64, size: u64 },
     ///The host function was deprecated.
-    Deprecated { method_name: ::std::string::String },
+    Deprecated { method_name: ::alloc::string::String },
     ///General errors for ECDSA recover.
     #[serde(rename = "ECRecoverError")]
-    EcRecoverError { msg: ::std::string::String },
+    EcRecoverError { msg: ::alloc::string::String },
     /**Invalid input to alt_bn128 family of functions (e.g., point which isn't
 on the curve).*/
-    AltBn128InvalidInput { msg: ::std::string::String },
+    AltBn128InvalidInput { msg: ::alloc::string::String },
     /**Invalid input to ed25519 signature verification function (e.g. signature cannot be
 derived from bytes).*/
-    Ed25519VerifyInvalidInput { msg: ::std::string::String },
+    Ed25519VerifyInvalidInput { msg: ::alloc::string::String },
 }
 ///`InvalidAccessKeyError`
 ///
@@ -2219,9 +2244,9 @@ pub enum InvalidAccessKeyError {
     ///The access key identified by the `public_key` doesn't exist for the account
     AccessKeyNotFound { account_id: AccountId, public_key: PublicKey },
     ///Transaction `receiver_id` doesn't match the access key receiver_id
-    ReceiverMismatch { ak_receiver: ::std::string::String, tx_receiver: AccountId },
+    ReceiverMismatch { ak_receiver: ::alloc::string::String, tx_receiver: AccountId },
     ///Transaction method name isn't allowed by the access key
-    MethodNameMismatch { method_name: ::std::string::String },
+    MethodNameMismatch { method_name: ::alloc::string::String },
     ///Transaction requires a full permission access key.
     RequiresFullAccess,
     ///Access Key does not have enough allowance to cover transaction cost
@@ -2241,7 +2266,7 @@ pub enum InvalidTxError {
     ///Happens if a wrong AccessKey used or AccessKey has not enough permissions
     InvalidAccessKeyError(InvalidAccessKeyError),
     ///TX signer_id is not a valid [`AccountId`]
-    InvalidSignerId { signer_id: ::std::string::String },
+    InvalidSignerId { signer_id: ::alloc::string::String },
     ///TX signer_id is not found in a storage
     SignerDoesNotExist { signer_id: AccountId },
     ///Transaction nonce must be strictly greater than `account[access_key].nonce`.
@@ -2249,7 +2274,7 @@ pub enum InvalidTxError {
     ///Transaction nonce is larger than the upper bound given by the block height
     NonceTooLarge { tx_nonce: u64, upper_bound: u64 },
     ///TX receiver_id is not a valid AccountId
-    InvalidReceiverId { receiver_id: ::std::string::String },
+    InvalidReceiverId { receiver_id: ::alloc::string::String },
     ///TX signature is not valid
     InvalidSignature,
     ///Account does not have enough balance to cover TX cost
@@ -2277,6 +2302,7 @@ pub enum InvalidTxError {
     /**The receiver shard of the transaction is too congested to accept new
 transactions at the moment.*/
     ShardCongested {
+        ///A value between 0 (no congestion) and 1 (max congestion).
         congestion_level: f64,
         ///The congested shard.
         shard_id: u32,
@@ -2295,23 +2321,23 @@ must have a nonce_index in valid range, regular transactions must not.*/
         ///Number of nonces supported by the key. 0 means no nonce_index allowed (regular key).
         num_nonces: u16,
         ///The nonce_index from the transaction (None if missing).
-        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-        tx_nonce_index: ::std::option::Option<u16>,
+        #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+        tx_nonce_index: ::core::option::Option<u16>,
     },
     ///Gas key does not have enough balance to cover gas costs.
     NotEnoughGasKeyBalance { balance: NearToken, cost: NearToken, signer_id: AccountId },
 }
-impl ::std::convert::From<InvalidAccessKeyError> for InvalidTxError {
+impl ::core::convert::From<InvalidAccessKeyError> for InvalidTxError {
     fn from(value: InvalidAccessKeyError) -> Self {
         Self::InvalidAccessKeyError(value)
     }
 }
-impl ::std::convert::From<ActionsValidationError> for InvalidTxError {
+impl ::core::convert::From<ActionsValidationError> for InvalidTxError {
     fn from(value: ActionsValidationError) -> Self {
         Self::ActionsValidation(value)
     }
 }
-impl ::std::convert::From<StorageError> for InvalidTxError {
+impl ::core::convert::From<StorageError> for InvalidTxError {
     fn from(value: StorageError) -> Self {
         Self::StorageError(value)
     }
@@ -2322,8 +2348,8 @@ the node can use to send message for this producer.*/
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub struct KnownProducerView {
     pub account_id: AccountId,
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub next_hops: ::std::option::Option<::std::vec::Vec<PublicKey>>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub next_hops: ::core::option::Option<::alloc::vec::Vec<PublicKey>>,
     pub peer_id: PublicKey,
 }
 ///`LightClientBlockLiteView`
@@ -2353,11 +2379,11 @@ NOTE: It's not a limiter itself, but it's a value we use for initial_memory_page
     ///Max contract size
     pub max_contract_size: u64,
     ///If present, stores max number of elements in a single contract's table
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub max_elements_per_contract_table: ::std::option::Option<u32>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub max_elements_per_contract_table: ::core::option::Option<u32>,
     ///If present, stores max number of functions in one contract
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub max_functions_number_per_contract: ::std::option::Option<u64>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub max_functions_number_per_contract: ::core::option::Option<u64>,
     ///Max amount of gas that can be used, excluding gas attached to promises.
     pub max_gas_burnt: NearGas,
     ///Max length of any method name (without terminating character).
@@ -2369,8 +2395,8 @@ NOTE: It's not a limiter itself, but it's a value we use for initial_memory_page
     ///Max storage value size
     pub max_length_storage_value: u64,
     ///If present, stores max number of locals declared globally in one contract
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub max_locals_per_contract: ::std::option::Option<u64>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub max_locals_per_contract: ::core::option::Option<u64>,
     ///What is the maximal memory pages amount is allowed to have for a contract.
     pub max_memory_pages: u32,
     /**Max total length of all method names (including terminating character) for a function call
@@ -2398,8 +2424,8 @@ See <https://wiki.parity.io/WebAssembly-StackHeight> to find out how the stack f
 is calculated.*/
     pub max_stack_height: u32,
     ///If present, stores max number of tables declared globally in one contract
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub max_tables_per_contract: ::std::option::Option<u32>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub max_tables_per_contract: ::core::option::Option<u32>,
     ///Maximum total length in bytes of all log messages.
     pub max_total_log_length: u64,
     ///Max total prepaid gas for all function call actions per receipt.
@@ -2435,19 +2461,21 @@ pub enum LogSummaryStyle {
     #[serde(rename = "colored")]
     Colored,
 }
-impl ::std::fmt::Display for LogSummaryStyle {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+#[cfg(feature = "client-config")]
+impl ::core::fmt::Display for LogSummaryStyle {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         match *self {
             Self::Plain => f.write_str("plain"),
             Self::Colored => f.write_str("colored"),
         }
     }
 }
-impl ::std::str::FromStr for LogSummaryStyle {
+#[cfg(feature = "client-config")]
+impl ::core::str::FromStr for LogSummaryStyle {
     type Err = self::error::ConversionError;
     fn from_str(
         value: &str,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
         match value {
             "plain" => Ok(Self::Plain),
             "colored" => Ok(Self::Colored),
@@ -2455,27 +2483,30 @@ impl ::std::str::FromStr for LogSummaryStyle {
         }
     }
 }
-impl ::std::convert::TryFrom<&str> for LogSummaryStyle {
+#[cfg(feature = "client-config")]
+impl ::core::convert::TryFrom<&str> for LogSummaryStyle {
     type Error = self::error::ConversionError;
     fn try_from(
         value: &str,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
         value.parse()
     }
 }
-impl ::std::convert::TryFrom<&::std::string::String> for LogSummaryStyle {
+#[cfg(feature = "client-config")]
+impl ::core::convert::TryFrom<&::alloc::string::String> for LogSummaryStyle {
     type Error = self::error::ConversionError;
     fn try_from(
-        value: &::std::string::String,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value: &::alloc::string::String,
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
         value.parse()
     }
 }
-impl ::std::convert::TryFrom<::std::string::String> for LogSummaryStyle {
+#[cfg(feature = "client-config")]
+impl ::core::convert::TryFrom<::alloc::string::String> for LogSummaryStyle {
     type Error = self::error::ConversionError;
     fn try_from(
-        value: ::std::string::String,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value: ::alloc::string::String,
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
         value.parse()
     }
 }
@@ -2505,8 +2536,8 @@ pub enum MethodResolveError {
     MethodNotFound,
     MethodInvalidSignature,
 }
-impl ::std::fmt::Display for MethodResolveError {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for MethodResolveError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         match *self {
             Self::MethodEmptyName => f.write_str("MethodEmptyName"),
             Self::MethodNotFound => f.write_str("MethodNotFound"),
@@ -2514,11 +2545,11 @@ impl ::std::fmt::Display for MethodResolveError {
         }
     }
 }
-impl ::std::str::FromStr for MethodResolveError {
+impl ::core::str::FromStr for MethodResolveError {
     type Err = self::error::ConversionError;
     fn from_str(
         value: &str,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
         match value {
             "MethodEmptyName" => Ok(Self::MethodEmptyName),
             "MethodNotFound" => Ok(Self::MethodNotFound),
@@ -2527,27 +2558,27 @@ impl ::std::str::FromStr for MethodResolveError {
         }
     }
 }
-impl ::std::convert::TryFrom<&str> for MethodResolveError {
+impl ::core::convert::TryFrom<&str> for MethodResolveError {
     type Error = self::error::ConversionError;
     fn try_from(
         value: &str,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
         value.parse()
     }
 }
-impl ::std::convert::TryFrom<&::std::string::String> for MethodResolveError {
+impl ::core::convert::TryFrom<&::alloc::string::String> for MethodResolveError {
     type Error = self::error::ConversionError;
     fn try_from(
-        value: &::std::string::String,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value: &::alloc::string::String,
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
         value.parse()
     }
 }
-impl ::std::convert::TryFrom<::std::string::String> for MethodResolveError {
+impl ::core::convert::TryFrom<::alloc::string::String> for MethodResolveError {
     type Error = self::error::ConversionError;
     fn try_from(
-        value: ::std::string::String,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value: ::alloc::string::String,
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
         value.parse()
     }
 }
@@ -2582,8 +2613,8 @@ pub enum MissingTrieValueContext {
     ///Missing trie value when reading from TrieStorage.
     TrieStorage,
 }
-impl ::std::fmt::Display for MissingTrieValueContext {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for MissingTrieValueContext {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         match *self {
             Self::TrieIterator => f.write_str("TrieIterator"),
             Self::TriePrefetchingStorage => f.write_str("TriePrefetchingStorage"),
@@ -2592,11 +2623,11 @@ impl ::std::fmt::Display for MissingTrieValueContext {
         }
     }
 }
-impl ::std::str::FromStr for MissingTrieValueContext {
+impl ::core::str::FromStr for MissingTrieValueContext {
     type Err = self::error::ConversionError;
     fn from_str(
         value: &str,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
         match value {
             "TrieIterator" => Ok(Self::TrieIterator),
             "TriePrefetchingStorage" => Ok(Self::TriePrefetchingStorage),
@@ -2606,27 +2637,27 @@ impl ::std::str::FromStr for MissingTrieValueContext {
         }
     }
 }
-impl ::std::convert::TryFrom<&str> for MissingTrieValueContext {
+impl ::core::convert::TryFrom<&str> for MissingTrieValueContext {
     type Error = self::error::ConversionError;
     fn try_from(
         value: &str,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
         value.parse()
     }
 }
-impl ::std::convert::TryFrom<&::std::string::String> for MissingTrieValueContext {
+impl ::core::convert::TryFrom<&::alloc::string::String> for MissingTrieValueContext {
     type Error = self::error::ConversionError;
     fn try_from(
-        value: &::std::string::String,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value: &::alloc::string::String,
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
         value.parse()
     }
 }
-impl ::std::convert::TryFrom<::std::string::String> for MissingTrieValueContext {
+impl ::core::convert::TryFrom<::alloc::string::String> for MissingTrieValueContext {
     type Error = self::error::ConversionError;
     fn try_from(
-        value: ::std::string::String,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value: ::alloc::string::String,
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
         value.parse()
     }
 }
@@ -2644,31 +2675,36 @@ impl ::std::convert::TryFrom<::std::string::String> for MissingTrieValueContext
     PartialOrd
 )]
 #[serde(transparent)]
-pub struct MutableConfigValue(pub ::std::string::String);
-impl ::std::ops::Deref for MutableConfigValue {
-    type Target = ::std::string::String;
-    fn deref(&self) -> &::std::string::String {
+pub struct MutableConfigValue(pub ::alloc::string::String);
+#[cfg(feature = "client-config")]
+impl ::core::ops::Deref for MutableConfigValue {
+    type Target = ::alloc::string::String;
+    fn deref(&self) -> &::alloc::string::String {
         &self.0
     }
 }
-impl ::std::convert::From<MutableConfigValue> for ::std::string::String {
+#[cfg(feature = "client-config")]
+impl ::core::convert::From<MutableConfigValue> for ::alloc::string::String {
     fn from(value: MutableConfigValue) -> Self {
         value.0
     }
 }
-impl ::std::convert::From<::std::string::String> for MutableConfigValue {
-    fn from(value: ::std::string::String) -> Self {
+#[cfg(feature = "client-config")]
+impl ::core::convert::From<::alloc::string::String> for MutableConfigValue {
+    fn from(value: ::alloc::string::String) -> Self {
         Self(value)
     }
 }
-impl ::std::str::FromStr for MutableConfigValue {
-    type Err = ::std::convert::Infallible;
-    fn from_str(value: &str) -> ::std::result::Result<Self, Self::Err> {
+#[cfg(feature = "client-config")]
+impl ::core::str::FromStr for MutableConfigValue {
+    type Err = ::core::convert::Infallible;
+    fn from_str(value: &str) -> ::core::result::Result<Self, Self::Err> {
         Ok(Self(value.to_string()))
     }
 }
-impl ::std::fmt::Display for MutableConfigValue {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+#[cfg(feature = "client-config")]
+impl ::core::fmt::Display for MutableConfigValue {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         self.0.fmt(f)
     }
 }
@@ -2677,42 +2713,42 @@ impl ::std::fmt::Display for MutableConfigValue {
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 #[serde(transparent)]
 pub struct NearGas(pub u64);
-impl ::std::ops::Deref for NearGas {
+impl ::core::ops::Deref for NearGas {
     type Target = u64;
     fn deref(&self) -> &u64 {
         &self.0
     }
 }
-impl ::std::convert::From<NearGas> for u64 {
+impl ::core::convert::From<NearGas> for u64 {
     fn from(value: NearGas) -> Self {
         value.0
     }
 }
-impl ::std::convert::From<u64> for NearGas {
+impl ::core::convert::From<u64> for NearGas {
     fn from(value: u64) -> Self {
         Self(value)
     }
 }
-impl ::std::str::FromStr for NearGas {
-    type Err = <u64 as ::std::str::FromStr>::Err;
-    fn from_str(value: &str) -> ::std::result::Result<Self, Self::Err> {
+impl ::core::str::FromStr for NearGas {
+    type Err = <u64 as ::core::str::FromStr>::Err;
+    fn from_str(value: &str) -> ::core::result::Result<Self, Self::Err> {
         Ok(Self(value.parse()?))
     }
 }
-impl ::std::convert::TryFrom<&str> for NearGas {
-    type Error = <u64 as ::std::str::FromStr>::Err;
-    fn try_from(value: &str) -> ::std::result::Result<Self, Self::Error> {
+impl ::core::convert::TryFrom<&str> for NearGas {
+    type Error = <u64 as ::core::str::FromStr>::Err;
+    fn try_from(value: &str) -> ::core::result::Result<Self, Self::Error> {
         value.parse()
     }
 }
-impl ::std::convert::TryFrom<String> for NearGas {
-    type Error = <u64 as ::std::str::FromStr>::Err;
-    fn try_from(value: String) -> ::std::result::Result<Self, Self::Error> {
+impl ::core::convert::TryFrom<String> for NearGas {
+    type Error = <u64 as ::core::str::FromStr>::Err;
+    fn try_from(value: String) -> ::core::result::Result<Self, Self::Error> {
         value.parse()
     }
 }
-impl ::std::fmt::Display for NearGas {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for NearGas {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         self.0.fmt(f)
     }
 }
@@ -2730,31 +2766,31 @@ impl ::std::fmt::Display for NearGas {
     PartialOrd
 )]
 #[serde(transparent)]
-pub struct NearToken(pub ::std::string::String);
-impl ::std::ops::Deref for NearToken {
-    type Target = ::std::string::String;
-    fn deref(&self) -> &::std::string::String {
+pub struct NearToken(pub ::alloc::string::String);
+impl ::core::ops::Deref for NearToken {
+    type Target = ::alloc::string::String;
+    fn deref(&self) -> &::alloc::string::String {
         &self.0
     }
 }
-impl ::std::convert::From<NearToken> for ::std::string::String {
+impl ::core::convert::From<NearToken> for ::alloc::string::String {
     fn from(value: NearToken) -> Self {
         value.0
     }
 }
-impl ::std::convert::From<::std::string::String> for NearToken {
-    fn from(value: ::std::string::String) -> Self {
+impl ::core::convert::From<::alloc::string::String> for NearToken {
+    fn from(value: ::alloc::string::String) -> Self {
         Self(value)
     }
 }
-impl ::std::str::FromStr for NearToken {
-    type Err = ::std::convert::Infallible;
-    fn from_str(value: &str) -> ::std::result::Result<Self, Self::Err> {
+impl ::core::str::FromStr for NearToken {
+    type Err = ::core::convert::Infallible;
+    fn from_str(value: &str) -> ::core::result::Result<Self, Self::Err> {
         Ok(Self(value.to_string()))
     }
 }
-impl ::std::fmt::Display for NearToken {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for NearToken {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         self.0.fmt(f)
     }
 }
@@ -2762,13 +2798,13 @@ impl ::std::fmt::Display for NearToken {
 ///
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub struct NetworkInfoView {
-    pub connected_peers: ::std::vec::Vec<PeerInfoView>,
-    pub known_producers: ::std::vec::Vec<KnownProducerView>,
+    pub connected_peers: ::alloc::vec::Vec<PeerInfoView>,
+    pub known_producers: ::alloc::vec::Vec<KnownProducerView>,
     pub num_connected_peers: u32,
     pub peer_max_count: u32,
-    pub tier1_accounts_data: ::std::vec::Vec<AccountDataView>,
-    pub tier1_accounts_keys: ::std::vec::Vec<PublicKey>,
-    pub tier1_connections: ::std::vec::Vec<PeerInfoView>,
+    pub tier1_accounts_data: ::alloc::vec::Vec<AccountDataView>,
+    pub tier1_accounts_keys: ::alloc::vec::Vec<PublicKey>,
+    pub tier1_connections: ::alloc::vec::Vec<PeerInfoView>,
 }
 ///`NextEpochValidatorInfo`
 ///
@@ -2776,7 +2812,7 @@ pub struct NetworkInfoView {
 pub struct NextEpochValidatorInfo {
     pub account_id: AccountId,
     pub public_key: PublicKey,
-    pub shards: ::std::vec::Vec<ShardId>,
+    pub shards: ::alloc::vec::Vec<ShardId>,
     pub stake: NearToken,
 }
 ///An Action that can be included in a transaction or receipt, excluding delegate actions. This type represents all possible action types except DelegateAction to prevent infinite recursion in meta-transactions.
@@ -2801,67 +2837,67 @@ a new account ID must pass validation rules described here
     TransferToGasKey(TransferToGasKeyAction),
     WithdrawFromGasKey(WithdrawFromGasKeyAction),
 }
-impl ::std::convert::From<CreateAccountAction> for NonDelegateAction {
+impl ::core::convert::From<CreateAccountAction> for NonDelegateAction {
     fn from(value: CreateAccountAction) -> Self {
         Self::CreateAccount(value)
     }
 }
-impl ::std::convert::From<DeployContractAction> for NonDelegateAction {
+impl ::core::convert::From<DeployContractAction> for NonDelegateAction {
     fn from(value: DeployContractAction) -> Self {
         Self::DeployContract(value)
     }
 }
-impl ::std::convert::From<FunctionCallAction> for NonDelegateAction {
+impl ::core::convert::From<FunctionCallAction> for NonDelegateAction {
     fn from(value: FunctionCallAction) -> Self {
         Self::FunctionCall(value)
     }
 }
-impl ::std::convert::From<TransferAction> for NonDelegateAction {
+impl ::core::convert::From<TransferAction> for NonDelegateAction {
     fn from(value: TransferAction) -> Self {
         Self::Transfer(value)
     }
 }
-impl ::std::convert::From<StakeAction> for NonDelegateAction {
+impl ::core::convert::From<StakeAction> for NonDelegateAction {
     fn from(value: StakeAction) -> Self {
         Self::Stake(value)
     }
 }
-impl ::std::convert::From<AddKeyAction> for NonDelegateAction {
+impl ::core::convert::From<AddKeyAction> for NonDelegateAction {
     fn from(value: AddKeyAction) -> Self {
         Self::AddKey(value)
     }
 }
-impl ::std::convert::From<DeleteKeyAction> for NonDelegateAction {
+impl ::core::convert::From<DeleteKeyAction> for NonDelegateAction {
     fn from(value: DeleteKeyAction) -> Self {
         Self::DeleteKey(value)
     }
 }
-impl ::std::convert::From<DeleteAccountAction> for NonDelegateAction {
+impl ::core::convert::From<DeleteAccountAction> for NonDelegateAction {
     fn from(value: DeleteAccountAction) -> Self {
         Self::DeleteAccount(value)
     }
 }
-impl ::std::convert::From<DeployGlobalContractAction> for NonDelegateAction {
+impl ::core::convert::From<DeployGlobalContractAction> for NonDelegateAction {
     fn from(value: DeployGlobalContractAction) -> Self {
         Self::DeployGlobalContract(value)
     }
 }
-impl ::std::convert::From<UseGlobalContractAction> for NonDelegateAction {
+impl ::core::convert::From<UseGlobalContractAction> for NonDelegateAction {
     fn from(value: UseGlobalContractAction) -> Self {
         Self::UseGlobalContract(value)
     }
 }
-impl ::std::convert::From<DeterministicStateInitAction> for NonDelegateAction {
+impl ::core::convert::From<DeterministicStateInitAction> for NonDelegateAction {
     fn from(value: DeterministicStateInitAction) -> Self {
         Self::DeterministicStateInit(value)
     }
 }
-impl ::std::convert::From<TransferToGasKeyAction> for NonDelegateAction {
+impl ::core::convert::From<TransferToGasKeyAction> for NonDelegateAction {
     fn from(value: TransferToGasKeyAction) -> Self {
         Self::TransferToGasKey(value)
     }
 }
-impl ::std::convert::From<WithdrawFromGasKeyAction> for NonDelegateAction {
+impl ::core::convert::From<WithdrawFromGasKeyAction> for NonDelegateAction {
     fn from(value: WithdrawFromGasKeyAction) -> Self {
         Self::WithdrawFromGasKey(value)
     }
@@ -2871,42 +2907,42 @@ impl ::std::convert::From<WithdrawFromGasKeyAction> for NonDelegateAction {
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 #[serde(transparent)]
 pub struct PeerId(pub PublicKey);
-impl ::std::ops::Deref for PeerId {
+impl ::core::ops::Deref for PeerId {
     type Target = PublicKey;
     fn deref(&self) -> &PublicKey {
         &self.0
     }
 }
-impl ::std::convert::From<PeerId> for PublicKey {
+impl ::core::convert::From<PeerId> for PublicKey {
     fn from(value: PeerId) -> Self {
         value.0
     }
 }
-impl ::std::convert::From<PublicKey> for PeerId {
+impl ::core::convert::From<PublicKey> for PeerId {
     fn from(value: PublicKey) -> Self {
         Self(value)
     }
 }
-impl ::std::str::FromStr for PeerId {
-    type Err = <PublicKey as ::std::str::FromStr>::Err;
-    fn from_str(value: &str) -> ::std::result::Result<Self, Self::Err> {
+impl ::core::str::FromStr for PeerId {
+    type Err = <PublicKey as ::core::str::FromStr>::Err;
+    fn from_str(value: &str) -> ::core::result::Result<Self, Self::Err> {
         Ok(Self(value.parse()?))
     }
 }
-impl ::std::convert::TryFrom<&str> for PeerId {
-    type Error = <PublicKey as ::std::str::FromStr>::Err;
-    fn try_from(value: &str) -> ::std::result::Result<Self, Self::Error> {
+impl ::core::convert::TryFrom<&str> for PeerId {
+    type Error = <PublicKey as ::core::str::FromStr>::Err;
+    fn try_from(value: &str) -> ::core::result::Result<Self, Self::Error> {
         value.parse()
     }
 }
-impl ::std::convert::TryFrom<String> for PeerId {
-    type Error = <PublicKey as ::std::str::FromStr>::Err;
-    fn try_from(value: String) -> ::std::result::Result<Self, Self::Error> {
+impl ::core::convert::TryFrom<String> for PeerId {
+    type Error = <PublicKey as ::core::str::FromStr>::Err;
+    fn try_from(value: String) -> ::core::result::Result<Self, Self::Error> {
         value.parse()
     }
 }
-impl ::std::fmt::Display for PeerId {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for PeerId {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         self.0.fmt(f)
     }
 }
@@ -2914,15 +2950,15 @@ impl ::std::fmt::Display for PeerId {
 ///
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub struct PeerInfoView {
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub account_id: ::std::option::Option<AccountId>,
-    pub addr: ::std::string::String,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub account_id: ::core::option::Option<AccountId>,
+    pub addr: ::alloc::string::String,
     pub archival: bool,
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub block_hash: ::std::option::Option<CryptoHash>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub block_hash: ::core::option::Option<CryptoHash>,
     pub connection_established_time_millis: u64,
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub height: ::std::option::Option<u64>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub height: ::core::option::Option<u64>,
     pub is_highest_block_invalid: bool,
     pub is_outbound_peer: bool,
     pub last_time_peer_requested_millis: u64,
@@ -2932,7 +2968,7 @@ pub struct PeerInfoView {
     pub peer_id: PublicKey,
     pub received_bytes_per_sec: u64,
     pub sent_bytes_per_sec: u64,
-    pub tracked_shards: ::std::vec::Vec<ShardId>,
+    pub tracked_shards: ::alloc::vec::Vec<ShardId>,
 }
 ///Error that can occur while preparing or executing Wasm smart-contract.
 ///
@@ -2979,8 +3015,8 @@ instantiable and/or un-linkable.*/
     ///Contract contains too many table elements.
     TooManyTableElements,
 }
-impl ::std::fmt::Display for PrepareError {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for PrepareError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         match *self {
             Self::Serialization => f.write_str("Serialization"),
             Self::Deserialization => f.write_str("Deserialization"),
@@ -2996,11 +3032,11 @@ impl ::std::fmt::Display for PrepareError {
         }
     }
 }
-impl ::std::str::FromStr for PrepareError {
+impl ::core::str::FromStr for PrepareError {
     type Err = self::error::ConversionError;
     fn from_str(
         value: &str,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
         match value {
             "Serialization" => Ok(Self::Serialization),
             "Deserialization" => Ok(Self::Deserialization),
@@ -3017,27 +3053,27 @@ impl ::std::str::FromStr for PrepareError {
         }
     }
 }
-impl ::std::convert::TryFrom<&str> for PrepareError {
+impl ::core::convert::TryFrom<&str> for PrepareError {
     type Error = self::error::ConversionError;
     fn try_from(
         value: &str,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
         value.parse()
     }
 }
-impl ::std::convert::TryFrom<&::std::string::String> for PrepareError {
+impl ::core::convert::TryFrom<&::alloc::string::String> for PrepareError {
     type Error = self::error::ConversionError;
     fn try_from(
-        value: &::std::string::String,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value: &::alloc::string::String,
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
         value.parse()
     }
 }
-impl ::std::convert::TryFrom<::std::string::String> for PrepareError {
+impl ::core::convert::TryFrom<::alloc::string::String> for PrepareError {
     type Error = self::error::ConversionError;
     fn try_from(
-        value: ::std::string::String,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value: ::alloc::string::String,
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
         value.parse()
     }
 }
@@ -3059,19 +3095,21 @@ pub enum ProtocolVersionCheckConfig {
     Next,
     NextNext,
 }
-impl ::std::fmt::Display for ProtocolVersionCheckConfig {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+#[cfg(feature = "client-config")]
+impl ::core::fmt::Display for ProtocolVersionCheckConfig {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         match *self {
             Self::Next => f.write_str("Next"),
             Self::NextNext => f.write_str("NextNext"),
         }
     }
 }
-impl ::std::str::FromStr for ProtocolVersionCheckConfig {
+#[cfg(feature = "client-config")]
+impl ::core::str::FromStr for ProtocolVersionCheckConfig {
     type Err = self::error::ConversionError;
     fn from_str(
         value: &str,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
         match value {
             "Next" => Ok(Self::Next),
             "NextNext" => Ok(Self::NextNext),
@@ -3079,27 +3117,30 @@ impl ::std::str::FromStr for ProtocolVersionCheckConfig {
         }
     }
 }
-impl ::std::convert::TryFrom<&str> for ProtocolVersionCheckConfig {
+#[cfg(feature = "client-config")]
+impl ::core::convert::TryFrom<&str> for ProtocolVersionCheckConfig {
     type Error = self::error::ConversionError;
     fn try_from(
         value: &str,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
         value.parse()
     }
 }
-impl ::std::convert::TryFrom<&::std::string::String> for ProtocolVersionCheckConfig {
+#[cfg(feature = "client-config")]
+impl ::core::convert::TryFrom<&::alloc::string::String> for ProtocolVersionCheckConfig {
     type Error = self::error::ConversionError;
     fn try_from(
-        value: &::std::string::String,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value: &::alloc::string::String,
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
         value.parse()
     }
 }
-impl ::std::convert::TryFrom<::std::string::String> for ProtocolVersionCheckConfig {
+#[cfg(feature = "client-config")]
+impl ::core::convert::TryFrom<::alloc::string::String> for ProtocolVersionCheckConfig {
     type Error = self::error::ConversionError;
     fn try_from(
-        value: ::std::string::String,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value: ::alloc::string::String,
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
         value.parse()
     }
 }
@@ -3117,31 +3158,31 @@ impl ::std::convert::TryFrom<::std::string::String> for ProtocolVersionCheckConf
     PartialOrd
 )]
 #[serde(transparent)]
-pub struct PublicKey(pub ::std::string::String);
-impl ::std::ops::Deref for PublicKey {
-    type Target = ::std::string::String;
-    fn deref(&self) -> &::std::string::String {
+pub struct PublicKey(pub ::alloc::string::String);
+impl ::core::ops::Deref for PublicKey {
+    type Target = ::alloc::string::String;
+    fn deref(&self) -> &::alloc::string::String {
         &self.0
     }
 }
-impl ::std::convert::From<PublicKey> for ::std::string::String {
+impl ::core::convert::From<PublicKey> for ::alloc::string::String {
     fn from(value: PublicKey) -> Self {
         value.0
     }
 }
-impl ::std::convert::From<::std::string::String> for PublicKey {
-    fn from(value: ::std::string::String) -> Self {
+impl ::core::convert::From<::alloc::string::String> for PublicKey {
+    fn from(value: ::alloc::string::String) -> Self {
         Self(value)
     }
 }
-impl ::std::str::FromStr for PublicKey {
-    type Err = ::std::convert::Infallible;
-    fn from_str(value: &str) -> ::std::result::Result<Self, Self::Err> {
+impl ::core::str::FromStr for PublicKey {
+    type Err = ::core::convert::Infallible;
+    fn from_str(value: &str) -> ::core::result::Result<Self, Self::Err> {
         Ok(Self(value.to_string()))
     }
 }
-impl ::std::fmt::Display for PublicKey {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for PublicKey {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         self.0.fmt(f)
     }
 }
@@ -3160,8 +3201,8 @@ pub enum QueryRequest {
     #[serde(rename = "view_state")]
     ViewState {
         account_id: AccountId,
-        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-        include_proof: ::std::option::Option<bool>,
+        #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+        include_proof: ::core::option::Option<bool>,
         prefix_base64: StoreKey,
     },
     ///ViewAccessKey
@@ -3178,7 +3219,7 @@ pub enum QueryRequest {
     CallFunction {
         account_id: AccountId,
         args_base64: FunctionArgs,
-        method_name: ::std::string::String,
+        method_name: ::alloc::string::String,
     },
     ///ViewGlobalContractCode
     #[serde(rename = "view_global_contract_code")]
@@ -3192,27 +3233,27 @@ pub enum QueryRequest {
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub enum ReceiptEnumView {
     Action {
-        actions: ::std::vec::Vec<ActionView>,
+        actions: ::alloc::vec::Vec<ActionView>,
         gas_price: NearToken,
-        input_data_ids: ::std::vec::Vec<CryptoHash>,
+        input_data_ids: ::alloc::vec::Vec<CryptoHash>,
         #[serde(default)]
         is_promise_yield: bool,
-        output_data_receivers: ::std::vec::Vec<DataReceiverView>,
-        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-        refund_to: ::std::option::Option<AccountId>,
+        output_data_receivers: ::alloc::vec::Vec<DataReceiverView>,
+        #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+        refund_to: ::core::option::Option<AccountId>,
         signer_id: AccountId,
         signer_public_key: PublicKey,
     },
     Data {
-        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-        data: ::std::option::Option<::std::string::String>,
+        #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+        data: ::core::option::Option<::alloc::string::String>,
         data_id: CryptoHash,
         #[serde(default)]
         is_promise_resume: bool,
     },
     GlobalContractDistribution {
-        already_delivered_shards: ::std::vec::Vec<ShardId>,
-        code: ::std::string::String,
+        already_delivered_shards: ::alloc::vec::Vec<ShardId>,
+        code: ::alloc::string::String,
         id: GlobalContractIdentifier,
         target_shard: ShardId,
     },
@@ -3222,13 +3263,13 @@ pub enum ReceiptEnumView {
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub enum ReceiptValidationError {
     ///The `predecessor_id` of a Receipt is not valid.
-    InvalidPredecessorId { account_id: ::std::string::String },
+    InvalidPredecessorId { account_id: ::alloc::string::String },
     ///The `receiver_id` of a Receipt is not valid.
-    InvalidReceiverId { account_id: ::std::string::String },
+    InvalidReceiverId { account_id: ::alloc::string::String },
     ///The `signer_id` of an ActionReceipt is not valid.
-    InvalidSignerId { account_id: ::std::string::String },
+    InvalidSignerId { account_id: ::alloc::string::String },
     ///The `receiver_id` of a DataReceiver within an ActionReceipt is not valid.
-    InvalidDataReceiverId { account_id: ::std::string::String },
+    InvalidDataReceiverId { account_id: ::alloc::string::String },
     ///The length of the returned data exceeded the limit in a DataReceipt.
     ReturnedValueLengthExceeded { length: u64, limit: u64 },
     ///The number of input data dependencies exceeds the limit in an ActionReceipt.
@@ -3241,9 +3282,9 @@ pub enum ReceiptValidationError {
     ///Receipt is bigger than the limit.
     ReceiptSizeExceeded { limit: u64, size: u64 },
     ///The `refund_to` of an ActionReceipt is not valid.
-    InvalidRefundTo { account_id: ::std::string::String },
+    InvalidRefundTo { account_id: ::alloc::string::String },
 }
-impl ::std::convert::From<ActionsValidationError> for ReceiptValidationError {
+impl ::core::convert::From<ActionsValidationError> for ReceiptValidationError {
     fn from(value: ActionsValidationError) -> Self {
         Self::ActionsValidation(value)
     }
@@ -3271,17 +3312,17 @@ pub enum RpcBlockRequest {
     #[serde(rename = "sync_checkpoint")]
     SyncCheckpoint(SyncCheckpoint),
 }
-impl ::std::convert::From<BlockId> for RpcBlockRequest {
+impl ::core::convert::From<BlockId> for RpcBlockRequest {
     fn from(value: BlockId) -> Self {
         Self::BlockId(value)
     }
 }
-impl ::std::convert::From<Finality> for RpcBlockRequest {
+impl ::core::convert::From<Finality> for RpcBlockRequest {
     fn from(value: Finality) -> Self {
         Self::Finality(value)
     }
 }
-impl ::std::convert::From<SyncCheckpoint> for RpcBlockRequest {
+impl ::core::convert::From<SyncCheckpoint> for RpcBlockRequest {
     fn from(value: SyncCheckpoint) -> Self {
         Self::SyncCheckpoint(value)
     }
@@ -3292,7 +3333,7 @@ impl ::std::convert::From<SyncCheckpoint> for RpcBlockRequest {
 pub struct RpcBlockResponse {
     ///The AccountId of the author of the Block
     pub author: AccountId,
-    pub chunks: ::std::vec::Vec<ChunkHeaderView>,
+    pub chunks: ::alloc::vec::Vec<ChunkHeaderView>,
     pub header: BlockHeaderView,
 }
 ///`RpcChunkRequest`
@@ -3309,38 +3350,40 @@ pub enum RpcChunkRequest {
 pub struct RpcChunkResponse {
     pub author: AccountId,
     pub header: ChunkHeaderView,
-    pub receipts: ::std::vec::Vec<ReceiptView>,
-    pub transactions: ::std::vec::Vec<SignedTransactionView>,
+    pub receipts: ::alloc::vec::Vec<ReceiptView>,
+    pub transactions: ::alloc::vec::Vec<SignedTransactionView>,
 }
 ///`RpcClientConfigRequest`
 ///
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 #[serde(transparent)]
 pub struct RpcClientConfigRequest(
-    pub ::serde_json::Map<::std::string::String, ::serde_json::Value>,
+    pub ::serde_json::Map<::alloc::string::String, ::serde_json::Value>,
 );
-impl ::std::ops::Deref for RpcClientConfigRequest {
-    type Target = ::serde_json::Map<::std::string::String, ::serde_json::Value>;
-    fn deref(&self) -> &::serde_json::Map<::std::string::String, ::serde_json::Value> {
+impl ::core::ops::Deref for RpcClientConfigRequest {
+    type Target = ::serde_json::Map<::alloc::string::String, ::serde_json::Value>;
+    fn deref(&self) -> &::serde_json::Map<::alloc::string::String, ::serde_json::Value> {
         &self.0
     }
 }
-impl ::std::convert::From<RpcClientConfigRequest>
-for ::serde_json::Map<::std::string::String, ::serde_json::Value> {
+impl ::core::convert::From<RpcClientConfigRequest>
+for ::serde_json::Map<::alloc::string::String, ::serde_json::Value> {
     fn from(value: RpcClientConfigRequest) -> Self {
         value.0
     }
 }
-impl ::std::convert::From<::serde_json::Map<::std::string::String, ::serde_json::Value>>
-for RpcClientConfigRequest {
+impl ::core::convert::From<
+    ::serde_json::Map<::alloc::string::String, ::serde_json::Value>,
+> for RpcClientConfigRequest {
     fn from(
-        value: ::serde_json::Map<::std::string::String, ::serde_json::Value>,
+        value: ::serde_json::Map<::alloc::string::String, ::serde_json::Value>,
     ) -> Self {
         Self(value)
     }
 }
 ///ClientConfig where some fields can be updated at runtime.
 ///
+#[cfg(feature = "client-config")]
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub struct RpcClientConfigResponse {
     ///Not clear old data, set `true` for archive nodes.
@@ -3354,13 +3397,13 @@ pub struct RpcClientConfigResponse {
     ///Time between check to perform catchup.
     pub catchup_step_period: [u64; 2usize],
     ///Chain id for status.
-    pub chain_id: ::std::string::String,
+    pub chain_id: ::alloc::string::String,
     /**Optional config for the Chunk Distribution Network feature.
 If set to `None` then this node does not participate in the Chunk Distribution Network.
 Nodes not participating will still function fine, but possibly with higher
 latency due to the need of requesting chunks over the peer-to-peer network.*/
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub chunk_distribution_network: ::std::option::Option<
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub chunk_distribution_network: ::core::option::Option<
         ChunkDistributionNetworkConfig,
     >,
     ///Time between checking to re-request chunks.
@@ -3377,8 +3420,8 @@ The default value is DEFAULT_CHUNKS_CACHE_HEIGHT_HORIZON.*/
     pub client_background_migration_threads: u32,
     /**Configuration for a cloud-based archival writer. If this config is present, the writer is enabled and
 writes chunk-related data based on the tracked shards.*/
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub cloud_archival_writer: ::std::option::Option<CloudArchivalWriterConfig>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub cloud_archival_writer: ::core::option::Option<CloudArchivalWriterConfig>,
     ///If true, the node won't forward transactions to next the chunk producers.
     pub disable_tx_routing: bool,
     ///Time between running doomslug timer.
@@ -3418,8 +3461,8 @@ The default is disabled.*/
     /**Max burnt gas per view method.  If present, overrides value stored in
 genesis file.  The value only affects the RPCs without influencing the
 protocol thus changing it per-node doesn’t affect the blockchain.*/
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub max_gas_burnt_view: ::std::option::Option<NearGas>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub max_gas_burnt_view: ::core::option::Option<NearGas>,
     ///Minimum duration before producing block.
     pub min_block_production_delay: [u64; 2usize],
     ///Minimum number of peers to start syncing.
@@ -3439,7 +3482,7 @@ required block appears. This variable controls how many witnesses can be stored
 A node produces a chunk by adding transactions from the transaction pool until
 some limit is reached. This time limit ensures that adding transactions won't take
 longer than the specified duration, which helps to produce the chunk quickly.*/
-    pub produce_chunk_add_transactions_time_limit: ::std::string::String,
+    pub produce_chunk_add_transactions_time_limit: ::alloc::string::String,
     ///Produce empty blocks, use `false` for testing.
     pub produce_empty_blocks: bool,
     /**Determines whether client should exit if the protocol version is not supported
@@ -3447,8 +3490,8 @@ for the next or next next epoch.*/
     pub protocol_version_check: ProtocolVersionCheckConfig,
     pub resharding_config: MutableConfigValue,
     ///Listening rpc port for status.
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub rpc_addr: ::std::option::Option<::std::string::String>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub rpc_addr: ::core::option::Option<::alloc::string::String>,
     /**Save observed instances of invalid ChunkStateWitness to the database in DBCol::InvalidChunkStateWitnesses.
 Saving invalid witnesses is useful for analysis and debugging.
 This option can cause extra load on the database and is not recommended for production use.*/
@@ -3501,12 +3544,12 @@ If disabled, the node will do Block Sync instead of State Sync.*/
     pub tracked_shards_config: TrackedShardsConfig,
     /**Limit of the size of per-shard transaction pool measured in bytes. If not set, the size
 will be unbounded.*/
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub transaction_pool_size_limit: ::std::option::Option<u64>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub transaction_pool_size_limit: ::core::option::Option<u64>,
     pub transaction_request_handler_threads: u32,
     ///Upper bound of the byte size of contract state that is still viewable. None is no limit
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub trie_viewer_state_size_limit: ::std::option::Option<u64>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub trie_viewer_state_size_limit: ::core::option::Option<u64>,
     ///Time to persist Accounts Id in the router without removing them.
     pub ttl_account_id_router: [u64; 2usize],
     /**If the node is not a chunk producer within that many blocks, then route
@@ -3535,10 +3578,10 @@ pub struct RpcCongestionLevelResponse {
 ///
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub struct RpcGasPriceRequest {
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub block_id: ::std::option::Option<BlockId>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub block_id: ::core::option::Option<BlockId>,
 }
-impl ::std::default::Default for RpcGasPriceRequest {
+impl ::core::default::Default for RpcGasPriceRequest {
     fn default() -> Self {
         Self {
             block_id: Default::default(),
@@ -3556,24 +3599,25 @@ pub struct RpcGasPriceResponse {
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 #[serde(transparent)]
 pub struct RpcHealthRequest(
-    pub ::serde_json::Map<::std::string::String, ::serde_json::Value>,
+    pub ::serde_json::Map<::alloc::string::String, ::serde_json::Value>,
 );
-impl ::std::ops::Deref for RpcHealthRequest {
-    type Target = ::serde_json::Map<::std::string::String, ::serde_json::Value>;
-    fn deref(&self) -> &::serde_json::Map<::std::string::String, ::serde_json::Value> {
+impl ::core::ops::Deref for RpcHealthRequest {
+    type Target = ::serde_json::Map<::alloc::string::String, ::serde_json::Value>;
+    fn deref(&self) -> &::serde_json::Map<::alloc::string::String, ::serde_json::Value> {
         &self.0
     }
 }
-impl ::std::convert::From<RpcHealthRequest>
-for ::serde_json::Map<::std::string::String, ::serde_json::Value> {
+impl ::core::convert::From<RpcHealthRequest>
+for ::serde_json::Map<::alloc::string::String, ::serde_json::Value> {
     fn from(value: RpcHealthRequest) -> Self {
         value.0
     }
 }
-impl ::std::convert::From<::serde_json::Map<::std::string::String, ::serde_json::Value>>
-for RpcHealthRequest {
+impl ::core::convert::From<
+    ::serde_json::Map<::alloc::string::String, ::serde_json::Value>,
+> for RpcHealthRequest {
     fn from(
-        value: ::serde_json::Map<::std::string::String, ::serde_json::Value>,
+        value: ::serde_json::Map<::alloc::string::String, ::serde_json::Value>,
     ) -> Self {
         Self(value)
     }
@@ -3583,18 +3627,18 @@ for RpcHealthRequest {
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 #[serde(transparent)]
 pub struct RpcHealthResponse(pub ());
-impl ::std::ops::Deref for RpcHealthResponse {
+impl ::core::ops::Deref for RpcHealthResponse {
     type Target = ();
     fn deref(&self) -> &() {
         &self.0
     }
 }
-impl ::std::convert::From<RpcHealthResponse> for () {
+impl ::core::convert::From<RpcHealthResponse> for () {
     fn from(value: RpcHealthResponse) -> Self {
         value.0
     }
 }
-impl ::std::convert::From<()> for RpcHealthResponse {
+impl ::core::convert::From<()> for RpcHealthResponse {
     fn from(value: ()) -> Self {
         Self(value)
     }
@@ -3604,8 +3648,8 @@ impl ::std::convert::From<()> for RpcHealthResponse {
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub struct RpcKnownProducer {
     pub account_id: AccountId,
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub addr: ::std::option::Option<::std::string::String>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub addr: ::core::option::Option<::alloc::string::String>,
     pub peer_id: PeerId,
 }
 ///`RpcLightClientBlockProofRequest`
@@ -3620,7 +3664,7 @@ pub struct RpcLightClientBlockProofRequest {
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub struct RpcLightClientBlockProofResponse {
     pub block_header_lite: LightClientBlockLiteView,
-    pub block_proof: ::std::vec::Vec<MerklePathItem>,
+    pub block_proof: ::alloc::vec::Vec<MerklePathItem>,
 }
 ///`RpcLightClientExecutionProofRequest`
 ///
@@ -3647,9 +3691,9 @@ pub enum RpcLightClientExecutionProofRequest {
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub struct RpcLightClientExecutionProofResponse {
     pub block_header_lite: LightClientBlockLiteView,
-    pub block_proof: ::std::vec::Vec<MerklePathItem>,
+    pub block_proof: ::alloc::vec::Vec<MerklePathItem>,
     pub outcome_proof: ExecutionOutcomeWithIdView,
-    pub outcome_root_proof: ::std::vec::Vec<MerklePathItem>,
+    pub outcome_root_proof: ::alloc::vec::Vec<MerklePathItem>,
 }
 ///`RpcLightClientNextBlockRequest`
 ///
@@ -3661,22 +3705,22 @@ pub struct RpcLightClientNextBlockRequest {
 ///
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub struct RpcLightClientNextBlockResponse {
-    #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
-    pub approvals_after_next: ::std::vec::Vec<::std::option::Option<Signature>>,
+    #[serde(default, skip_serializing_if = "::alloc::vec::Vec::is_empty")]
+    pub approvals_after_next: ::alloc::vec::Vec<::core::option::Option<Signature>>,
     /**Inner part of the block header that gets hashed, split into two parts, one that is sent
    to light clients, and the rest*/
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub inner_lite: ::std::option::Option<BlockHeaderInnerLiteView>,
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub inner_rest_hash: ::std::option::Option<CryptoHash>,
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub next_block_inner_hash: ::std::option::Option<CryptoHash>,
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub next_bps: ::std::option::Option<::std::vec::Vec<ValidatorStakeView>>,
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub prev_block_hash: ::std::option::Option<CryptoHash>,
-}
-impl ::std::default::Default for RpcLightClientNextBlockResponse {
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub inner_lite: ::core::option::Option<BlockHeaderInnerLiteView>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub inner_rest_hash: ::core::option::Option<CryptoHash>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub next_block_inner_hash: ::core::option::Option<CryptoHash>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub next_bps: ::core::option::Option<::alloc::vec::Vec<ValidatorStakeView>>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub prev_block_hash: ::core::option::Option<CryptoHash>,
+}
+impl ::core::default::Default for RpcLightClientNextBlockResponse {
     fn default() -> Self {
         Self {
             approvals_after_next: Default::default(),
@@ -3699,24 +3743,25 @@ pub struct RpcMaintenanceWindowsRequest {
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 #[serde(transparent)]
 pub struct RpcNetworkInfoRequest(
-    pub ::serde_json::Map<::std::string::String, ::serde_json::Value>,
+    pub ::serde_json::Map<::alloc::string::String, ::serde_json::Value>,
 );
-impl ::std::ops::Deref for RpcNetworkInfoRequest {
-    type Target = ::serde_json::Map<::std::string::String, ::serde_json::Value>;
-    fn deref(&self) -> &::serde_json::Map<::std::string::String, ::serde_json::Value> {
+impl ::core::ops::Deref for RpcNetworkInfoRequest {
+    type Target = ::serde_json::Map<::alloc::string::String, ::serde_json::Value>;
+    fn deref(&self) -> &::serde_json::Map<::alloc::string::String, ::serde_json::Value> {
         &self.0
     }
 }
-impl ::std::convert::From<RpcNetworkInfoRequest>
-for ::serde_json::Map<::std::string::String, ::serde_json::Value> {
+impl ::core::convert::From<RpcNetworkInfoRequest>
+for ::serde_json::Map<::alloc::string::String, ::serde_json::Value> {
     fn from(value: RpcNetworkInfoRequest) -> Self {
         value.0
     }
 }
-impl ::std::convert::From<::serde_json::Map<::std::string::String, ::serde_json::Value>>
-for RpcNetworkInfoRequest {
+impl ::core::convert::From<
+    ::serde_json::Map<::alloc::string::String, ::serde_json::Value>,
+> for RpcNetworkInfoRequest {
     fn from(
-        value: ::serde_json::Map<::std::string::String, ::serde_json::Value>,
+        value: ::serde_json::Map<::alloc::string::String, ::serde_json::Value>,
     ) -> Self {
         Self(value)
     }
@@ -3725,9 +3770,9 @@ for RpcNetworkInfoRequest {
 ///
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub struct RpcNetworkInfoResponse {
-    pub active_peers: ::std::vec::Vec<RpcPeerInfo>,
+    pub active_peers: ::alloc::vec::Vec<RpcPeerInfo>,
     ///Accounts of known block and chunk producers from routing table.
-    pub known_producers: ::std::vec::Vec<RpcKnownProducer>,
+    pub known_producers: ::alloc::vec::Vec<RpcKnownProducer>,
     pub num_active_peers: u32,
     pub peer_max_count: u32,
     pub received_bytes_per_sec: u64,
@@ -3737,10 +3782,10 @@ pub struct RpcNetworkInfoResponse {
 ///
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub struct RpcPeerInfo {
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub account_id: ::std::option::Option<AccountId>,
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub addr: ::std::option::Option<::std::string::String>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub account_id: ::core::option::Option<AccountId>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub addr: ::core::option::Option<::alloc::string::String>,
     pub id: PeerId,
 }
 ///`RpcProtocolConfigRequest`
@@ -3754,17 +3799,17 @@ pub enum RpcProtocolConfigRequest {
     #[serde(rename = "sync_checkpoint")]
     SyncCheckpoint(SyncCheckpoint),
 }
-impl ::std::convert::From<BlockId> for RpcProtocolConfigRequest {
+impl ::core::convert::From<BlockId> for RpcProtocolConfigRequest {
     fn from(value: BlockId) -> Self {
         Self::BlockId(value)
     }
 }
-impl ::std::convert::From<Finality> for RpcProtocolConfigRequest {
+impl ::core::convert::From<Finality> for RpcProtocolConfigRequest {
     fn from(value: Finality) -> Self {
         Self::Finality(value)
     }
 }
-impl ::std::convert::From<SyncCheckpoint> for RpcProtocolConfigRequest {
+impl ::core::convert::From<SyncCheckpoint> for RpcProtocolConfigRequest {
     fn from(value: SyncCheckpoint) -> Self {
         Self::SyncCheckpoint(value)
     }
@@ -3774,12 +3819,12 @@ impl ::std::convert::From<SyncCheckpoint> for RpcProtocolConfigRequest {
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub struct RpcProtocolConfigResponse {
     ///Expected number of hidden validators per shard.
-    pub avg_hidden_validator_seats_per_shard: ::std::vec::Vec<u64>,
+    pub avg_hidden_validator_seats_per_shard: ::alloc::vec::Vec<u64>,
     ///Threshold for kicking out block producers, between 0 and 100.
     pub block_producer_kickout_threshold: u8,
     /**ID of the blockchain. This must be unique for every blockchain.
 If your testnet blockchains do not have unique chain IDs, you will have a bad time.*/
-    pub chain_id: ::std::string::String,
+    pub chain_id: ::alloc::string::String,
     ///Threshold for kicking out chunk producers, between 0 and 100.
     pub chunk_producer_kickout_threshold: u8,
     ///Threshold for kicking out nodes which are only chunk validators, between 0 and 100.
@@ -3797,7 +3842,7 @@ If your testnet blockchains do not have unique chain IDs, you will have a bad ti
     ///Height of genesis block.
     pub genesis_height: u64,
     ///Official time of blockchain start.
-    pub genesis_time: ::chrono::DateTime<::chrono::offset::Utc>,
+    pub genesis_time: crate::DateTime,
     ///Maximum gas price.
     pub max_gas_price: NearToken,
     ///Maximum inflation on the total supply every epoch.
@@ -3816,7 +3861,7 @@ See <https://github.com/near/NEPs/pull/167> for details*/
     ///Number of block producer seats at genesis.
     pub num_block_producer_seats: u64,
     ///Defines number of shards and number of block producer seats per each shard at genesis.
-    pub num_block_producer_seats_per_shard: ::std::vec::Vec<u64>,
+    pub num_block_producer_seats_per_shard: ::alloc::vec::Vec<u64>,
     ///Expected number of blocks per year
     pub num_blocks_per_year: u64,
     ///Online maximum threshold above which validator gets full reward.
@@ -3853,157 +3898,157 @@ pub enum RpcQueryRequest {
     ViewAccountBlockId {
         account_id: AccountId,
         block_id: BlockId,
-        request_type: ::std::string::String,
+        request_type: ::alloc::string::String,
     },
     ViewCodeBlockId {
         account_id: AccountId,
         block_id: BlockId,
-        request_type: ::std::string::String,
+        request_type: ::alloc::string::String,
     },
     ViewStateBlockId {
         account_id: AccountId,
         block_id: BlockId,
-        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-        include_proof: ::std::option::Option<bool>,
+        #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+        include_proof: ::core::option::Option<bool>,
         prefix_base64: StoreKey,
-        request_type: ::std::string::String,
+        request_type: ::alloc::string::String,
     },
     ViewAccessKeyBlockId {
         account_id: AccountId,
         block_id: BlockId,
         public_key: PublicKey,
-        request_type: ::std::string::String,
+        request_type: ::alloc::string::String,
     },
     ViewAccessKeyListBlockId {
         account_id: AccountId,
         block_id: BlockId,
-        request_type: ::std::string::String,
+        request_type: ::alloc::string::String,
     },
     ViewGasKeyNoncesBlockId {
         account_id: AccountId,
         block_id: BlockId,
         public_key: PublicKey,
-        request_type: ::std::string::String,
+        request_type: ::alloc::string::String,
     },
     CallFunctionBlockId {
         account_id: AccountId,
         args_base64: FunctionArgs,
         block_id: BlockId,
-        method_name: ::std::string::String,
-        request_type: ::std::string::String,
+        method_name: ::alloc::string::String,
+        request_type: ::alloc::string::String,
     },
     ViewGlobalContractCodeBlockId {
         block_id: BlockId,
         code_hash: CryptoHash,
-        request_type: ::std::string::String,
+        request_type: ::alloc::string::String,
     },
     ViewGlobalContractCodeByAccountIdBlockId {
         account_id: AccountId,
         block_id: BlockId,
-        request_type: ::std::string::String,
+        request_type: ::alloc::string::String,
     },
     ViewAccountFinality {
         account_id: AccountId,
         finality: Finality,
-        request_type: ::std::string::String,
+        request_type: ::alloc::string::String,
     },
     ViewCodeFinality {
         account_id: AccountId,
         finality: Finality,
-        request_type: ::std::string::String,
+        request_type: ::alloc::string::String,
     },
     ViewStateFinality {
         account_id: AccountId,
         finality: Finality,
-        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-        include_proof: ::std::option::Option<bool>,
+        #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+        include_proof: ::core::option::Option<bool>,
         prefix_base64: StoreKey,
-        request_type: ::std::string::String,
+        request_type: ::alloc::string::String,
     },
     ViewAccessKeyFinality {
         account_id: AccountId,
         finality: Finality,
         public_key: PublicKey,
-        request_type: ::std::string::String,
+        request_type: ::alloc::string::String,
     },
     ViewAccessKeyListFinality {
         account_id: AccountId,
         finality: Finality,
-        request_type: ::std::string::String,
+        request_type: ::alloc::string::String,
     },
     ViewGasKeyNoncesFinality {
         account_id: AccountId,
         finality: Finality,
         public_key: PublicKey,
-        request_type: ::std::string::String,
+        request_type: ::alloc::string::String,
     },
     CallFunctionFinality {
         account_id: AccountId,
         args_base64: FunctionArgs,
         finality: Finality,
-        method_name: ::std::string::String,
-        request_type: ::std::string::String,
+        method_name: ::alloc::string::String,
+        request_type: ::alloc::string::String,
     },
     ViewGlobalContractCodeFinality {
         code_hash: CryptoHash,
         finality: Finality,
-        request_type: ::std::string::String,
+        request_type: ::alloc::string::String,
     },
     ViewGlobalContractCodeByAccountIdFinality {
         account_id: AccountId,
         finality: Finality,
-        request_type: ::std::string::String,
+        request_type: ::alloc::string::String,
     },
     ViewAccountSyncCheckpoint {
         account_id: AccountId,
-        request_type: ::std::string::String,
+        request_type: ::alloc::string::String,
         sync_checkpoint: SyncCheckpoint,
     },
     ViewCodeSyncCheckpoint {
         account_id: AccountId,
-        request_type: ::std::string::String,
+        request_type: ::alloc::string::String,
         sync_checkpoint: SyncCheckpoint,
     },
     ViewStateSyncCheckpoint {
         account_id: AccountId,
-        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-        include_proof: ::std::option::Option<bool>,
+        #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+        include_proof: ::core::option::Option<bool>,
         prefix_base64: StoreKey,
-        request_type: ::std::string::String,
+        request_type: ::alloc::string::String,
         sync_checkpoint: SyncCheckpoint,
     },
     ViewAccessKeySyncCheckpoint {
         account_id: AccountId,
         public_key: PublicKey,
-        request_type: ::std::string::String,
+        request_type: ::alloc::string::String,
         sync_checkpoint: SyncCheckpoint,
     },
     ViewAccessKeyListSyncCheckpoint {
         account_id: AccountId,
-        request_type: ::std::string::String,
+        request_type: ::alloc::string::String,
         sync_checkpoint: SyncCheckpoint,
     },
     ViewGasKeyNoncesSyncCheckpoint {
         account_id: AccountId,
         public_key: PublicKey,
-        request_type: ::std::string::String,
+        request_type: ::alloc::string::String,
         sync_checkpoint: SyncCheckpoint,
     },
     CallFunctionSyncCheckpoint {
         account_id: AccountId,
         args_base64: FunctionArgs,
-        method_name: ::std::string::String,
-        request_type: ::std::string::String,
+        method_name: ::alloc::string::String,
+        request_type: ::alloc::string::String,
         sync_checkpoint: SyncCheckpoint,
     },
     ViewGlobalContractCodeSyncCheckpoint {
         code_hash: CryptoHash,
-        request_type: ::std::string::String,
+        request_type: ::alloc::string::String,
         sync_checkpoint: SyncCheckpoint,
     },
     ViewGlobalContractCodeByAccountIdSyncCheckpoint {
         account_id: AccountId,
-        request_type: ::std::string::String,
+        request_type: ::alloc::string::String,
         sync_checkpoint: SyncCheckpoint,
     },
 }
@@ -4017,10 +4062,10 @@ pub enum RpcQueryResponse {
         block_hash: CryptoHash,
         block_height: u64,
         code_hash: CryptoHash,
-        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-        global_contract_account_id: ::std::option::Option<AccountId>,
-        #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-        global_contract_hash: ::std::option::Option<CryptoHash>,
+        #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+        global_contract_account_id: ::core::option::Option<AccountId>,
+        #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+        global_contract_hash: ::core::option::Option<CryptoHash>,
         locked: NearToken,
         ///TODO(2271): deprecated.
         #[serde(default)]
@@ -4030,21 +4075,21 @@ pub enum RpcQueryResponse {
     ContractCodeView {
         block_hash: CryptoHash,
         block_height: u64,
-        code_base64: ::std::string::String,
+        code_base64: ::alloc::string::String,
         hash: CryptoHash,
     },
     ViewStateResult {
         block_hash: CryptoHash,
         block_height: u64,
-        #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
-        proof: ::std::vec::Vec<::std::string::String>,
-        values: ::std::vec::Vec<StateItem>,
+        #[serde(default, skip_serializing_if = "::alloc::vec::Vec::is_empty")]
+        proof: ::alloc::vec::Vec<::alloc::string::String>,
+        values: ::alloc::vec::Vec<StateItem>,
     },
     CallResult {
         block_hash: CryptoHash,
         block_height: u64,
-        logs: ::std::vec::Vec<::std::string::String>,
-        result: ::std::vec::Vec<u8>,
+        logs: ::alloc::vec::Vec<::alloc::string::String>,
+        result: ::alloc::vec::Vec<u8>,
     },
     AccessKeyView {
         block_hash: CryptoHash,
@@ -4055,11 +4100,11 @@ pub enum RpcQueryResponse {
     AccessKeyList {
         block_hash: CryptoHash,
         block_height: u64,
-        keys: ::std::vec::Vec<AccessKeyInfoView>,
+        keys: ::alloc::vec::Vec<AccessKeyInfoView>,
     },
     BlockHeightBlockHash(RpcQueryResponseBlockHeightBlockHash),
 }
-impl ::std::convert::From<RpcQueryResponseBlockHeightBlockHash> for RpcQueryResponse {
+impl ::core::convert::From<RpcQueryResponseBlockHeightBlockHash> for RpcQueryResponse {
     fn from(value: RpcQueryResponseBlockHeightBlockHash) -> Self {
         Self::BlockHeightBlockHash(value)
     }
@@ -4111,24 +4156,25 @@ pub struct RpcSendTransactionRequest {
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 #[serde(transparent)]
 pub struct RpcSplitStorageInfoRequest(
-    pub ::serde_json::Map<::std::string::String, ::serde_json::Value>,
+    pub ::serde_json::Map<::alloc::string::String, ::serde_json::Value>,
 );
-impl ::std::ops::Deref for RpcSplitStorageInfoRequest {
-    type Target = ::serde_json::Map<::std::string::String, ::serde_json::Value>;
-    fn deref(&self) -> &::serde_json::Map<::std::string::String, ::serde_json::Value> {
+impl ::core::ops::Deref for RpcSplitStorageInfoRequest {
+    type Target = ::serde_json::Map<::alloc::string::String, ::serde_json::Value>;
+    fn deref(&self) -> &::serde_json::Map<::alloc::string::String, ::serde_json::Value> {
         &self.0
     }
 }
-impl ::std::convert::From<RpcSplitStorageInfoRequest>
-for ::serde_json::Map<::std::string::String, ::serde_json::Value> {
+impl ::core::convert::From<RpcSplitStorageInfoRequest>
+for ::serde_json::Map<::alloc::string::String, ::serde_json::Value> {
     fn from(value: RpcSplitStorageInfoRequest) -> Self {
         value.0
     }
 }
-impl ::std::convert::From<::serde_json::Map<::std::string::String, ::serde_json::Value>>
-for RpcSplitStorageInfoRequest {
+impl ::core::convert::From<
+    ::serde_json::Map<::alloc::string::String, ::serde_json::Value>,
+> for RpcSplitStorageInfoRequest {
     fn from(
-        value: ::serde_json::Map<::std::string::String, ::serde_json::Value>,
+        value: ::serde_json::Map<::alloc::string::String, ::serde_json::Value>,
     ) -> Self {
         Self(value)
     }
@@ -4137,16 +4183,16 @@ for RpcSplitStorageInfoRequest {
 ///
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub struct RpcSplitStorageInfoResponse {
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub cold_head_height: ::std::option::Option<u64>,
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub final_head_height: ::std::option::Option<u64>,
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub head_height: ::std::option::Option<u64>,
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub hot_db_kind: ::std::option::Option<::std::string::String>,
-}
-impl ::std::default::Default for RpcSplitStorageInfoResponse {
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub cold_head_height: ::core::option::Option<u64>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub final_head_height: ::core::option::Option<u64>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub head_height: ::core::option::Option<u64>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub hot_db_kind: ::core::option::Option<::alloc::string::String>,
+}
+impl ::core::default::Default for RpcSplitStorageInfoResponse {
     fn default() -> Self {
         Self {
             cold_head_height: Default::default(),
@@ -4162,80 +4208,80 @@ impl ::std::default::Default for RpcSplitStorageInfoResponse {
 #[serde(untagged)]
 pub enum RpcStateChangesInBlockByTypeRequest {
     AccountChangesBlockId {
-        account_ids: ::std::vec::Vec<AccountId>,
+        account_ids: ::alloc::vec::Vec<AccountId>,
         block_id: BlockId,
-        changes_type: ::std::string::String,
+        changes_type: ::alloc::string::String,
     },
     SingleAccessKeyChangesBlockId {
         block_id: BlockId,
-        changes_type: ::std::string::String,
-        keys: ::std::vec::Vec<AccountWithPublicKey>,
+        changes_type: ::alloc::string::String,
+        keys: ::alloc::vec::Vec<AccountWithPublicKey>,
     },
     AllAccessKeyChangesBlockId {
-        account_ids: ::std::vec::Vec<AccountId>,
+        account_ids: ::alloc::vec::Vec<AccountId>,
         block_id: BlockId,
-        changes_type: ::std::string::String,
+        changes_type: ::alloc::string::String,
     },
     ContractCodeChangesBlockId {
-        account_ids: ::std::vec::Vec<AccountId>,
+        account_ids: ::alloc::vec::Vec<AccountId>,
         block_id: BlockId,
-        changes_type: ::std::string::String,
+        changes_type: ::alloc::string::String,
     },
     DataChangesBlockId {
-        account_ids: ::std::vec::Vec<AccountId>,
+        account_ids: ::alloc::vec::Vec<AccountId>,
         block_id: BlockId,
-        changes_type: ::std::string::String,
+        changes_type: ::alloc::string::String,
         key_prefix_base64: StoreKey,
     },
     AccountChangesFinality {
-        account_ids: ::std::vec::Vec<AccountId>,
-        changes_type: ::std::string::String,
+        account_ids: ::alloc::vec::Vec<AccountId>,
+        changes_type: ::alloc::string::String,
         finality: Finality,
     },
     SingleAccessKeyChangesFinality {
-        changes_type: ::std::string::String,
+        changes_type: ::alloc::string::String,
         finality: Finality,
-        keys: ::std::vec::Vec<AccountWithPublicKey>,
+        keys: ::alloc::vec::Vec<AccountWithPublicKey>,
     },
     AllAccessKeyChangesFinality {
-        account_ids: ::std::vec::Vec<AccountId>,
-        changes_type: ::std::string::String,
+        account_ids: ::alloc::vec::Vec<AccountId>,
+        changes_type: ::alloc::string::String,
         finality: Finality,
     },
     ContractCodeChangesFinality {
-        account_ids: ::std::vec::Vec<AccountId>,
-        changes_type: ::std::string::String,
+        account_ids: ::alloc::vec::Vec<AccountId>,
+        changes_type: ::alloc::string::String,
         finality: Finality,
     },
     DataChangesFinality {
-        account_ids: ::std::vec::Vec<AccountId>,
-        changes_type: ::std::string::String,
+        account_ids: ::alloc::vec::Vec<AccountId>,
+        changes_type: ::alloc::string::String,
         finality: Finality,
         key_prefix_base64: StoreKey,
     },
     AccountChangesSyncCheckpoint {
-        account_ids: ::std::vec::Vec<AccountId>,
-        changes_type: ::std::string::String,
+        account_ids: ::alloc::vec::Vec<AccountId>,
+        changes_type: ::alloc::string::String,
         sync_checkpoint: SyncCheckpoint,
     },
     SingleAccessKeyChangesSyncCheckpoint {
-        changes_type: ::std::string::String,
-        keys: ::std::vec::Vec<AccountWithPublicKey>,
+        changes_type: ::alloc::string::String,
+        keys: ::alloc::vec::Vec<AccountWithPublicKey>,
         sync_checkpoint: SyncCheckpoint,
     },
     AllAccessKeyChangesSyncCheckpoint {
-        account_ids: ::std::vec::Vec<AccountId>,
-        changes_type: ::std::string::String,
+        account_ids: ::alloc::vec::Vec<AccountId>,
+        changes_type: ::alloc::string::String,
         sync_checkpoint: SyncCheckpoint,
     },
     ContractCodeChangesSyncCheckpoint {
-        account_ids: ::std::vec::Vec<AccountId>,
-        changes_type: ::std::string::String,
+        account_ids: ::alloc::vec::Vec<AccountId>,
+        changes_type: ::alloc::string::String,
         sync_checkpoint: SyncCheckpoint,
     },
     DataChangesSyncCheckpoint {
-        account_ids: ::std::vec::Vec<AccountId>,
-        changes_type: ::std::string::String,
+        account_ids: ::alloc::vec::Vec<AccountId>,
+        changes_type: ::alloc::string::String,
         key_prefix_base64: StoreKey,
         sync_checkpoint: SyncCheckpoint,
     },
@@ -4245,7 +4291,7 @@ pub enum RpcStateChangesInBlockByTypeRequest {
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub struct RpcStateChangesInBlockByTypeResponse {
     pub block_hash: CryptoHash,
-    pub changes: ::std::vec::Vec<StateChangeKindView>,
+    pub changes: ::alloc::vec::Vec<StateChangeKindView>,
 }
 ///`RpcStateChangesInBlockRequest`
 ///
@@ -4258,17 +4304,17 @@ pub enum RpcStateChangesInBlockRequest {
     #[serde(rename = "sync_checkpoint")]
     SyncCheckpoint(SyncCheckpoint),
 }
-impl ::std::convert::From<BlockId> for RpcStateChangesInBlockRequest {
+impl ::core::convert::From<BlockId> for RpcStateChangesInBlockRequest {
     fn from(value: BlockId) -> Self {
         Self::BlockId(value)
     }
 }
-impl ::std::convert::From<Finality> for RpcStateChangesInBlockRequest {
+impl ::core::convert::From<Finality> for RpcStateChangesInBlockRequest {
     fn from(value: Finality) -> Self {
         Self::Finality(value)
     }
 }
-impl ::std::convert::From<SyncCheckpoint> for RpcStateChangesInBlockRequest {
+impl ::core::convert::From<SyncCheckpoint> for RpcStateChangesInBlockRequest {
     fn from(value: SyncCheckpoint) -> Self {
         Self::SyncCheckpoint(value)
     }
@@ -4278,31 +4324,32 @@ impl ::std::convert::From<SyncCheckpoint> for RpcStateChangesInBlockRequest {
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub struct RpcStateChangesInBlockResponse {
     pub block_hash: CryptoHash,
-    pub changes: ::std::vec::Vec<StateChangeWithCauseView>,
+    pub changes: ::alloc::vec::Vec<StateChangeWithCauseView>,
 }
 ///`RpcStatusRequest`
 ///
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 #[serde(transparent)]
 pub struct RpcStatusRequest(
-    pub ::serde_json::Map<::std::string::String, ::serde_json::Value>,
+    pub ::serde_json::Map<::alloc::string::String, ::serde_json::Value>,
 );
-impl ::std::ops::Deref for RpcStatusRequest {
-    type Target = ::serde_json::Map<::std::string::String, ::serde_json::Value>;
-    fn deref(&self) -> &::serde_json::Map<::std::string::String, ::serde_json::Value> {
+impl ::core::ops::Deref for RpcStatusRequest {
+    type Target = ::serde_json::Map<::alloc::string::String, ::serde_json::Value>;
+    fn deref(&self) -> &::serde_json::Map<::alloc::string::String, ::serde_json::Value> {
         &self.0
     }
 }
-impl ::std::convert::From<RpcStatusRequest>
-for ::serde_json::Map<::std::string::String, ::serde_json::Value> {
+impl ::core::convert::From<RpcStatusRequest>
+for ::serde_json::Map<::alloc::string::String, ::serde_json::Value> {
     fn from(value: RpcStatusRequest) -> Self {
         value.0
     }
 }
-impl ::std::convert::From<::serde_json::Map<::std::string::String, ::serde_json::Value>>
-for RpcStatusRequest {
+impl ::core::convert::From<
+    ::serde_json::Map<::alloc::string::String, ::serde_json::Value>,
+> for RpcStatusRequest {
     fn from(
-        value: ::serde_json::Map<::std::string::String, ::serde_json::Value>,
+        value: ::serde_json::Map<::alloc::string::String, ::serde_json::Value>,
     ) -> Self {
         Self(value)
     }
@@ -4312,36 +4359,36 @@ for RpcStatusRequest {
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub struct RpcStatusResponse {
     ///Unique chain id.
-    pub chain_id: ::std::string::String,
+    pub chain_id: ::alloc::string::String,
     ///Information about last blocks, network, epoch and chain & chunk info.
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub detailed_debug_status: ::std::option::Option<DetailedDebugStatus>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub detailed_debug_status: ::core::option::Option<DetailedDebugStatus>,
     ///Genesis hash of the chain.
     pub genesis_hash: CryptoHash,
     ///Latest protocol version that this client supports.
     pub latest_protocol_version: u32,
     ///Deprecated; same as `validator_public_key` which you should use instead.
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub node_key: ::std::option::Option<PublicKey>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub node_key: ::core::option::Option<PublicKey>,
     ///Public key of the node.
     pub node_public_key: PublicKey,
     ///Currently active protocol version.
     pub protocol_version: u32,
     ///Address for RPC server.  None if node doesn't have RPC endpoint enabled.
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub rpc_addr: ::std::option::Option<::std::string::String>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub rpc_addr: ::core::option::Option<::alloc::string::String>,
     ///Sync status of the node.
     pub sync_info: StatusSyncInfo,
     ///Uptime of the node.
     pub uptime_sec: i64,
     ///Validator id of the node
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub validator_account_id: ::std::option::Option<AccountId>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub validator_account_id: ::core::option::Option<AccountId>,
     ///Public key of the validator.
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub validator_public_key: ::std::option::Option<PublicKey>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub validator_public_key: ::core::option::Option<PublicKey>,
     ///Current epoch validators.
-    pub validators: ::std::vec::Vec<ValidatorInfo>,
+    pub validators: ::alloc::vec::Vec<ValidatorInfo>,
     ///Binary version.
     pub version: Version,
 }
@@ -4353,9 +4400,9 @@ pub enum RpcTransactionResponse {
     FinalExecutionOutcomeWithReceiptView {
         final_execution_status: TxExecutionStatus,
         ///Receipts generated from the transaction
-        receipts: ::std::vec::Vec<ReceiptView>,
+        receipts: ::alloc::vec::Vec<ReceiptView>,
         ///The execution outcome of receipts.
-        receipts_outcome: ::std::vec::Vec<ExecutionOutcomeWithIdView>,
+        receipts_outcome: ::alloc::vec::Vec<ExecutionOutcomeWithIdView>,
         /**Execution status defined by chain.rs:get_final_transaction_result
 FinalExecutionStatus::NotStarted - the tx is not converted to the receipt yet
 FinalExecutionStatus::Started - we have at least 1 receipt, but the first leaf receipt_id (using dfs) hasn't finished the execution
@@ -4370,7 +4417,7 @@ FinalExecutionStatus::SuccessValue - the result of the first leaf receipt_id*/
     FinalExecutionOutcomeView {
         final_execution_status: TxExecutionStatus,
         ///The execution outcome of receipts.
-        receipts_outcome: ::std::vec::Vec<ExecutionOutcomeWithIdView>,
+        receipts_outcome: ::alloc::vec::Vec<ExecutionOutcomeWithIdView>,
         /**Execution status defined by chain.rs:get_final_transaction_result
 FinalExecutionStatus::NotStarted - the tx is not converted to the receipt yet
 FinalExecutionStatus::Started - we have at least 1 receipt, but the first leaf receipt_id (using dfs) hasn't finished the execution
@@ -4416,12 +4463,12 @@ pub enum RpcValidatorRequest {
     #[serde(rename = "latest")]
     Latest,
 }
-impl ::std::convert::From<EpochId> for RpcValidatorRequest {
+impl ::core::convert::From<EpochId> for RpcValidatorRequest {
     fn from(value: EpochId) -> Self {
         Self::EpochId(value)
     }
 }
-impl ::std::convert::From<BlockId> for RpcValidatorRequest {
+impl ::core::convert::From<BlockId> for RpcValidatorRequest {
     fn from(value: BlockId) -> Self {
         Self::BlockId(value)
     }
@@ -4431,30 +4478,30 @@ impl ::std::convert::From<BlockId> for RpcValidatorRequest {
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub struct RpcValidatorResponse {
     ///Fishermen for the current epoch
-    pub current_fishermen: ::std::vec::Vec<ValidatorStakeView>,
+    pub current_fishermen: ::alloc::vec::Vec<ValidatorStakeView>,
     ///Proposals in the current epoch
-    pub current_proposals: ::std::vec::Vec<ValidatorStakeView>,
+    pub current_proposals: ::alloc::vec::Vec<ValidatorStakeView>,
     ///Validators for the current epoch
-    pub current_validators: ::std::vec::Vec<CurrentEpochValidatorInfo>,
+    pub current_validators: ::alloc::vec::Vec<CurrentEpochValidatorInfo>,
     ///Epoch height
     pub epoch_height: u64,
     ///Epoch start block height
     pub epoch_start_height: u64,
     ///Fishermen for the next epoch
-    pub next_fishermen: ::std::vec::Vec<ValidatorStakeView>,
+    pub next_fishermen: ::alloc::vec::Vec<ValidatorStakeView>,
     ///Validators for the next epoch
-    pub next_validators: ::std::vec::Vec<NextEpochValidatorInfo>,
+    pub next_validators: ::alloc::vec::Vec<NextEpochValidatorInfo>,
     ///Kickout in the previous epoch
-    pub prev_epoch_kickout: ::std::vec::Vec<ValidatorKickoutView>,
+    pub prev_epoch_kickout: ::alloc::vec::Vec<ValidatorKickoutView>,
 }
 ///`RpcValidatorsOrderedRequest`
 ///
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub struct RpcValidatorsOrderedRequest {
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub block_id: ::std::option::Option<BlockId>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub block_id: ::core::option::Option<BlockId>,
 }
-impl ::std::default::Default for RpcValidatorsOrderedRequest {
+impl ::core::default::Default for RpcValidatorsOrderedRequest {
     fn default() -> Self {
         Self {
             block_id: Default::default(),
@@ -4512,42 +4559,42 @@ if fully complete it potentially may be simplified to a regular type alias.*/
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 #[serde(transparent)]
 pub struct ShardId(pub u64);
-impl ::std::ops::Deref for ShardId {
+impl ::core::ops::Deref for ShardId {
     type Target = u64;
     fn deref(&self) -> &u64 {
         &self.0
     }
 }
-impl ::std::convert::From<ShardId> for u64 {
+impl ::core::convert::From<ShardId> for u64 {
     fn from(value: ShardId) -> Self {
         value.0
     }
 }
-impl ::std::convert::From<u64> for ShardId {
+impl ::core::convert::From<u64> for ShardId {
     fn from(value: u64) -> Self {
         Self(value)
     }
 }
-impl ::std::str::FromStr for ShardId {
-    type Err = <u64 as ::std::str::FromStr>::Err;
-    fn from_str(value: &str) -> ::std::result::Result<Self, Self::Err> {
+impl ::core::str::FromStr for ShardId {
+    type Err = <u64 as ::core::str::FromStr>::Err;
+    fn from_str(value: &str) -> ::core::result::Result<Self, Self::Err> {
         Ok(Self(value.parse()?))
     }
 }
-impl ::std::convert::TryFrom<&str> for ShardId {
-    type Error = <u64 as ::std::str::FromStr>::Err;
-    fn try_from(value: &str) -> ::std::result::Result<Self, Self::Error> {
+impl ::core::convert::TryFrom<&str> for ShardId {
+    type Error = <u64 as ::core::str::FromStr>::Err;
+    fn try_from(value: &str) -> ::core::result::Result<Self, Self::Error> {
         value.parse()
     }
 }
-impl ::std::convert::TryFrom<String> for ShardId {
-    type Error = <u64 as ::std::str::FromStr>::Err;
-    fn try_from(value: String) -> ::std::result::Result<Self, Self::Error> {
+impl ::core::convert::TryFrom<String> for ShardId {
+    type Error = <u64 as ::core::str::FromStr>::Err;
+    fn try_from(value: String) -> ::core::result::Result<Self, Self::Error> {
         value.parse()
     }
 }
-impl ::std::fmt::Display for ShardId {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for ShardId {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         self.0.fmt(f)
     }
 }
@@ -4567,22 +4614,22 @@ pub enum ShardLayout {
     V2(ShardLayoutV2),
     V3(ShardLayoutV3),
 }
-impl ::std::convert::From<ShardLayoutV0> for ShardLayout {
+impl ::core::convert::From<ShardLayoutV0> for ShardLayout {
     fn from(value: ShardLayoutV0) -> Self {
         Self::V0(value)
     }
 }
-impl ::std::convert::From<ShardLayoutV1> for ShardLayout {
+impl ::core::convert::From<ShardLayoutV1> for ShardLayout {
     fn from(value: ShardLayoutV1) -> Self {
         Self::V1(value)
     }
 }
-impl ::std::convert::From<ShardLayoutV2> for ShardLayout {
+impl ::core::convert::From<ShardLayoutV2> for ShardLayout {
     fn from(value: ShardLayoutV2) -> Self {
         Self::V2(value)
     }
 }
-impl ::std::convert::From<ShardLayoutV3> for ShardLayout {
+impl ::core::convert::From<ShardLayoutV3> for ShardLayout {
     fn from(value: ShardLayoutV3) -> Self {
         Self::V3(value)
     }
@@ -4608,18 +4655,18 @@ pub struct ShardLayoutV1 {
 Each shard contains a range of accounts from one boundary account to
 another - or the smallest or largest account possible. The total
 number of shards is equal to the number of boundary accounts plus 1.*/
-    pub boundary_accounts: ::std::vec::Vec<AccountId>,
+    pub boundary_accounts: ::alloc::vec::Vec<AccountId>,
     /**Maps shards from the last shard layout to shards that it splits to in this shard layout,
 Useful for constructing states for the shards.
 None for the genesis shard layout*/
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub shards_split_map: ::std::option::Option<
-        ::std::vec::Vec<::std::vec::Vec<ShardId>>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub shards_split_map: ::core::option::Option<
+        ::alloc::vec::Vec<::alloc::vec::Vec<ShardId>>,
     >,
     /**Maps shard in this shard layout to their parent shard
 Since shard_ids always range from 0 to num_shards - 1, we use vec instead of a hashmap*/
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub to_parent_shard_map: ::std::option::Option<::std::vec::Vec<ShardId>>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub to_parent_shard_map: ::core::option::Option<::alloc::vec::Vec<ShardId>>,
     ///Version of the shard layout, this is useful for uniquely identify the shard layout
     pub version: u32,
 }
@@ -4628,17 +4675,23 @@ serde serialization.*/
 ///
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub struct ShardLayoutV2 {
-    pub boundary_accounts: ::std::vec::Vec<AccountId>,
-    pub id_to_index_map: ::std::collections::HashMap<::std::string::String, u32>,
-    pub index_to_id_map: ::std::collections::HashMap<::std::string::String, ShardId>,
-    pub shard_ids: ::std::vec::Vec<ShardId>,
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub shards_parent_map: ::std::option::Option<
-        ::std::collections::HashMap<::std::string::String, ShardId>,
+    pub boundary_accounts: ::alloc::vec::Vec<AccountId>,
+    pub id_to_index_map: ::alloc::collections::BTreeMap<::alloc::string::String, u32>,
+    pub index_to_id_map: ::alloc::collections::BTreeMap<
+        ::alloc::string::String,
+        ShardId,
     >,
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub shards_split_map: ::std::option::Option<
-        ::std::collections::HashMap<::std::string::String, ::std::vec::Vec<ShardId>>,
+    pub shard_ids: ::alloc::vec::Vec<ShardId>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub shards_parent_map: ::core::option::Option<
+        ::alloc::collections::BTreeMap<::alloc::string::String, ShardId>,
+    >,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub shards_split_map: ::core::option::Option<
+        ::alloc::collections::BTreeMap<
+            ::alloc::string::String,
+            ::alloc::vec::Vec<ShardId>,
+        >,
     >,
     pub version: u32,
 }
@@ -4647,13 +4700,13 @@ serde serialization.*/
 ///
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub struct ShardLayoutV3 {
-    pub boundary_accounts: ::std::vec::Vec<AccountId>,
-    pub id_to_index_map: ::std::collections::HashMap<::std::string::String, u32>,
+    pub boundary_accounts: ::alloc::vec::Vec<AccountId>,
+    pub id_to_index_map: ::alloc::collections::BTreeMap<::alloc::string::String, u32>,
     pub last_split: ShardId,
-    pub shard_ids: ::std::vec::Vec<ShardId>,
-    pub shards_split_map: ::std::collections::HashMap<
-        ::std::string::String,
-        ::std::vec::Vec<ShardId>,
+    pub shard_ids: ::alloc::vec::Vec<ShardId>,
+    pub shards_split_map: ::alloc::collections::BTreeMap<
+        ::alloc::string::String,
+        ::alloc::vec::Vec<ShardId>,
     >,
 }
 /**`ShardUId` is a unique representation for shards from different shard layouts.
@@ -4685,31 +4738,31 @@ pub struct ShardUId {
     PartialOrd
 )]
 #[serde(transparent)]
-pub struct Signature(pub ::std::string::String);
-impl ::std::ops::Deref for Signature {
-    type Target = ::std::string::String;
-    fn deref(&self) -> &::std::string::String {
+pub struct Signature(pub ::alloc::string::String);
+impl ::core::ops::Deref for Signature {
+    type Target = ::alloc::string::String;
+    fn deref(&self) -> &::alloc::string::String {
         &self.0
     }
 }
-impl ::std::convert::From<Signature> for ::std::string::String {
+impl ::core::convert::From<Signature> for ::alloc::string::String {
     fn from(value: Signature) -> Self {
         value.0
     }
 }
-impl ::std::convert::From<::std::string::String> for Signature {
-    fn from(value: ::std::string::String) -> Self {
+impl ::core::convert::From<::alloc::string::String> for Signature {
+    fn from(value: ::alloc::string::String) -> Self {
         Self(value)
     }
 }
-impl ::std::str::FromStr for Signature {
-    type Err = ::std::convert::Infallible;
-    fn from_str(value: &str) -> ::std::result::Result<Self, Self::Err> {
+impl ::core::str::FromStr for Signature {
+    type Err = ::core::convert::Infallible;
+    fn from_str(value: &str) -> ::core::result::Result<Self, Self::Err> {
         Ok(Self(value.to_string()))
     }
 }
-impl ::std::fmt::Display for Signature {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for Signature {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         self.0.fmt(f)
     }
 }
@@ -4734,31 +4787,31 @@ pub struct SignedDelegateAction {
     PartialOrd
 )]
 #[serde(transparent)]
-pub struct SignedTransaction(pub ::std::string::String);
-impl ::std::ops::Deref for SignedTransaction {
-    type Target = ::std::string::String;
-    fn deref(&self) -> &::std::string::String {
+pub struct SignedTransaction(pub ::alloc::string::String);
+impl ::core::ops::Deref for SignedTransaction {
+    type Target = ::alloc::string::String;
+    fn deref(&self) -> &::alloc::string::String {
         &self.0
     }
 }
-impl ::std::convert::From<SignedTransaction> for ::std::string::String {
+impl ::core::convert::From<SignedTransaction> for ::alloc::string::String {
     fn from(value: SignedTransaction) -> Self {
         value.0
     }
 }
-impl ::std::convert::From<::std::string::String> for SignedTransaction {
-    fn from(value: ::std::string::String) -> Self {
+impl ::core::convert::From<::alloc::string::String> for SignedTransaction {
+    fn from(value: ::alloc::string::String) -> Self {
         Self(value)
     }
 }
-impl ::std::str::FromStr for SignedTransaction {
-    type Err = ::std::convert::Infallible;
-    fn from_str(value: &str) -> ::std::result::Result<Self, Self::Err> {
+impl ::core::str::FromStr for SignedTransaction {
+    type Err = ::core::convert::Infallible;
+    fn from_str(value: &str) -> ::core::result::Result<Self, Self::Err> {
         Ok(Self(value.to_string()))
     }
 }
-impl ::std::fmt::Display for SignedTransaction {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for SignedTransaction {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         self.0.fmt(f)
     }
 }
@@ -4766,11 +4819,11 @@ impl ::std::fmt::Display for SignedTransaction {
 ///
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub struct SignedTransactionView {
-    pub actions: ::std::vec::Vec<ActionView>,
+    pub actions: ::alloc::vec::Vec<ActionView>,
     pub hash: CryptoHash,
     pub nonce: u64,
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub nonce_index: ::std::option::Option<u16>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub nonce_index: ::core::option::Option<u16>,
     ///Deprecated, retained for backward compatibility.
     #[serde(default)]
     pub priority_fee: u64,
@@ -4910,10 +4963,10 @@ pub struct StateChangeWithCauseViewChange {
     pub account_id: AccountId,
     pub amount: NearToken,
     pub code_hash: CryptoHash,
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub global_contract_account_id: ::std::option::Option<AccountId>,
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub global_contract_hash: ::std::option::Option<CryptoHash>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub global_contract_account_id: ::core::option::Option<AccountId>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub global_contract_hash: ::core::option::Option<CryptoHash>,
     pub locked: NearToken,
     ///TODO(2271): deprecated.
     #[serde(default)]
@@ -4927,19 +4980,22 @@ pub struct StateChangeWithCauseViewChange {
 pub enum StateChangesRequestView {
     ///AccountChanges
     #[serde(rename = "account_changes")]
-    AccountChanges { account_ids: ::std::vec::Vec<AccountId> },
+    AccountChanges { account_ids: ::alloc::vec::Vec<AccountId> },
     ///SingleAccessKeyChanges
     #[serde(rename = "single_access_key_changes")]
-    SingleAccessKeyChanges { keys: ::std::vec::Vec<AccountWithPublicKey> },
+    SingleAccessKeyChanges { keys: ::alloc::vec::Vec<AccountWithPublicKey> },
     ///AllAccessKeyChanges
     #[serde(rename = "all_access_key_changes")]
-    AllAccessKeyChanges { account_ids: ::std::vec::Vec<AccountId> },
+    AllAccessKeyChanges { account_ids: ::alloc::vec::Vec<AccountId> },
     ///ContractCodeChanges
     #[serde(rename = "contract_code_changes")]
-    ContractCodeChanges { account_ids: ::std::vec::Vec<AccountId> },
+    ContractCodeChanges { account_ids: ::alloc::vec::Vec<AccountId> },
     ///DataChanges
     #[serde(rename = "data_changes")]
-    DataChanges { account_ids: ::std::vec::Vec<AccountId>, key_prefix_base64: StoreKey },
+    DataChanges {
+        account_ids: ::alloc::vec::Vec<AccountId>,
+        key_prefix_base64: StoreKey,
+    },
 }
 ///Item of the state, key and value are serialized in base64 and proof for inclusion of given state item.
 ///
@@ -4950,20 +5006,22 @@ pub struct StateItem {
 }
 ///`StateSyncConfig`
 ///
+#[cfg(feature = "client-config")]
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub struct StateSyncConfig {
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub concurrency: ::std::option::Option<SyncConcurrency>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub concurrency: ::core::option::Option<SyncConcurrency>,
     ///`none` value disables state dump to external storage.
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub dump: ::std::option::Option<DumpConfig>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub dump: ::core::option::Option<DumpConfig>,
     ///Zstd compression level for state parts.
     #[serde(default = "defaults::default_u64::<i32, 1>")]
     pub parts_compression_lvl: i32,
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub sync: ::std::option::Option<SyncConfig>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub sync: ::core::option::Option<SyncConfig>,
 }
-impl ::std::default::Default for StateSyncConfig {
+#[cfg(feature = "client-config")]
+impl ::core::default::Default for StateSyncConfig {
     fn default() -> Self {
         Self {
             concurrency: Default::default(),
@@ -4977,19 +5035,19 @@ impl ::std::default::Default for StateSyncConfig {
 ///
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub struct StatusSyncInfo {
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub earliest_block_hash: ::std::option::Option<CryptoHash>,
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub earliest_block_height: ::std::option::Option<u64>,
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub earliest_block_time: ::std::option::Option<::std::string::String>,
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub epoch_id: ::std::option::Option<EpochId>,
-    #[serde(default, skip_serializing_if = "::std::option::Option::is_none")]
-    pub epoch_start_height: ::std::option::Option<u64>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub earliest_block_hash: ::core::option::Option<CryptoHash>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub earliest_block_height: ::core::option::Option<u64>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub earliest_block_time: ::core::option::Option<::alloc::string::String>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub epoch_id: ::core::option::Option<EpochId>,
+    #[serde(default, skip_serializing_if = "::core::option::Option::is_none")]
+    pub epoch_start_height: ::core::option::Option<u64>,
     pub latest_block_hash: CryptoHash,
     pub latest_block_height: u64,
-    pub latest_block_time: ::std::string::String,
+    pub latest_block_time: ::alloc::string::String,
     pub latest_state_root: CryptoHash,
     pub syncing: bool,
 }
@@ -5011,15 +5069,15 @@ For PartialStorage it cannot be corrupted.
 Error message is unreliable and for debugging purposes only. It's also probably ok to
 panic in every place that produces this error.
 We can check if db is corrupted by verifying everything in the state trie.*/
-    StorageInconsistentState(::std::string::String),
+    StorageInconsistentState(::alloc::string::String),
     /**Flat storage error, meaning that it doesn't support some block anymore.
 We guarantee that such block cannot become final, thus block processing
 must resume normally.*/
-    FlatStorageBlockNotSupported(::std::string::String),
+    FlatStorageBlockNotSupported(::alloc::string::String),
     ///In-memory trie could not be loaded for some reason.
-    MemTrieLoadingError(::std::string::String),
+    MemTrieLoadingError(::alloc::string::String),
 }
-impl ::std::convert::From<MissingTrieValue> for StorageError {
+impl ::core::convert::From<MissingTrieValue> for StorageError {
     fn from(value: MissingTrieValue) -> Self {
         Self::MissingTrieValue(value)
     }
@@ -5042,19 +5100,19 @@ pub enum StorageGetMode {
     FlatStorage,
     Trie,
 }
-impl ::std::fmt::Display for StorageGetMode {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for StorageGetMode {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         match *self {
             Self::FlatStorage => f.write_str("FlatStorage"),
             Self::Trie => f.write_str("Trie"),
         }
     }
 }
-impl ::std::str::FromStr for StorageGetMode {
+impl ::core::str::FromStr for StorageGetMode {
     type Err = self::error::ConversionError;
     fn from_str(
         value: &str,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
         match value {
             "FlatStorage" => Ok(Self::FlatStorage),
             "Trie" => Ok(Self::Trie),
@@ -5062,27 +5120,27 @@ impl ::std::str::FromStr for StorageGetMode {
         }
     }
 }
-impl ::std::convert::TryFrom<&str> for StorageGetMode {
+impl ::core::convert::TryFrom<&str> for StorageGetMode {
     type Error = self::error::ConversionError;
     fn try_from(
         value: &str,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
         value.parse()
     }
 }
-impl ::std::convert::TryFrom<&::std::string::String> for StorageGetMode {
+impl ::core::convert::TryFrom<&::alloc::string::String> for StorageGetMode {
     type Error = self::error::ConversionError;
     fn try_from(
-        value: &::std::string::String,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value: &::alloc::string::String,
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
         value.parse()
     }
 }
-impl ::std::convert::TryFrom<::std::string::String> for StorageGetMode {
+impl ::core::convert::TryFrom<::alloc::string::String> for StorageGetMode {
     type Error = self::error::ConversionError;
     fn try_from(
-        value: ::std::string::String,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value: ::alloc::string::String,
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
         value.parse()
     }
 }
@@ -5112,31 +5170,31 @@ pretty-serialize the bytes arrays as base64-encoded strings (see `serialize.rs`)
     PartialOrd
 )]
 #[serde(transparent)]
-pub struct StoreKey(pub ::std::string::String);
-impl ::std::ops::Deref for StoreKey {
-    type Target = ::std::string::String;
-    fn deref(&self) -> &::std::string::String {
+pub struct StoreKey(pub ::alloc::string::String);
+impl ::core::ops::Deref for StoreKey {
+    type Target = ::alloc::string::String;
+    fn deref(&self) -> &::alloc::string::String {
         &self.0
     }
 }
-impl ::std::convert::From<StoreKey> for ::std::string::String {
+impl ::core::convert::From<StoreKey> for ::alloc::string::String {
     fn from(value: StoreKey) -> Self {
         value.0
     }
 }
-impl ::std::convert::From<::std::string::String> for StoreKey {
-    fn from(value: ::std::string::String) -> Self {
+impl ::core::convert::From<::alloc::string::String> for StoreKey {
+    fn from(value: ::alloc::string::String) -> Self {
         Self(value)
     }
 }
-impl ::std::str::FromStr for StoreKey {
-    type Err = ::std::convert::Infallible;
-    fn from_str(value: &str) -> ::std::result::Result<Self, Self::Err> {
+impl ::core::str::FromStr for StoreKey {
+    type Err = ::core::convert::Infallible;
+    fn from_str(value: &str) -> ::core::result::Result<Self, Self::Err> {
         Ok(Self(value.to_string()))
     }
 }
-impl ::std::fmt::Display for StoreKey {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for StoreKey {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         self.0.fmt(f)
     }
 }
@@ -5157,31 +5215,31 @@ pretty-serialize the bytes arrays as base64-encoded strings (see `serialize.rs`)
     PartialOrd
 )]
 #[serde(transparent)]
-pub struct StoreValue(pub ::std::string::String);
-impl ::std::ops::Deref for StoreValue {
-    type Target = ::std::string::String;
-    fn deref(&self) -> &::std::string::String {
+pub struct StoreValue(pub ::alloc::string::String);
+impl ::core::ops::Deref for StoreValue {
+    type Target = ::alloc::string::String;
+    fn deref(&self) -> &::alloc::string::String {
         &self.0
     }
 }
-impl ::std::convert::From<StoreValue> for ::std::string::String {
+impl ::core::convert::From<StoreValue> for ::alloc::string::String {
     fn from(value: StoreValue) -> Self {
         value.0
     }
 }
-impl ::std::convert::From<::std::string::String> for StoreValue {
-    fn from(value: ::std::string::String) -> Self {
+impl ::core::convert::From<::alloc::string::String> for StoreValue {
+    fn from(value: ::alloc::string::String) -> Self {
         Self(value)
     }
 }
-impl ::std::str::FromStr for StoreValue {
-    type Err = ::std::convert::Infallible;
-    fn from_str(value: &str) -> ::std::result::Result<Self, Self::Err> {
+impl ::core::str::FromStr for StoreValue {
+    type Err = ::core::convert::Infallible;
+    fn from_str(value: &str) -> ::core::result::Result<Self, Self::Err> {
         Ok(Self(value.to_string()))
     }
 }
-impl ::std::fmt::Display for StoreValue {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for StoreValue {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         self.0.fmt(f)
     }
 }
@@ -5205,19 +5263,19 @@ pub enum SyncCheckpoint {
     #[serde(rename = "earliest_available")]
     EarliestAvailable,
 }
-impl ::std::fmt::Display for SyncCheckpoint {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for SyncCheckpoint {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         match *self {
             Self::Genesis => f.write_str("genesis"),
             Self::EarliestAvailable => f.write_str("earliest_available"),
         }
     }
 }
-impl ::std::str::FromStr for SyncCheckpoint {
+impl ::core::str::FromStr for SyncCheckpoint {
     type Err = self::error::ConversionError;
     fn from_str(
         value: &str,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
         match value {
             "genesis" => Ok(Self::Genesis),
             "earliest_available" => Ok(Self::EarliestAvailable),
@@ -5225,27 +5283,27 @@ impl ::std::str::FromStr for SyncCheckpoint {
         }
     }
 }
-impl ::std::convert::TryFrom<&str> for SyncCheckpoint {
+impl ::core::convert::TryFrom<&str> for SyncCheckpoint {
     type Error = self::error::ConversionError;
     fn try_from(
         value: &str,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
         value.parse()
     }
 }
-impl ::std::convert::TryFrom<&::std::string::String> for SyncCheckpoint {
+impl ::core::convert::TryFrom<&::alloc::string::String> for SyncCheckpoint {
     type Error = self::error::ConversionError;
     fn try_from(
-        value: &::std::string::String,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value: &::alloc::string::String,
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
         value.parse()
     }
 }
-impl ::std::convert::TryFrom<::std::string::String> for SyncCheckpoint {
+impl ::core::convert::TryFrom<::alloc::string::String> for SyncCheckpoint {
     type Error = self::error::ConversionError;
     fn try_from(
-        value: ::std::string::String,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value: ::alloc::string::String,
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
         value.parse()
     }
 }
@@ -5270,6 +5328,7 @@ balancing the shards a little.*/
 }
 ///Configures how to fetch state parts during state sync.
 ///
+#[cfg(feature = "client-config")]
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub enum SyncConfig {
     ///Syncs state from the peers without reading anything from external storage.
@@ -5279,7 +5338,8 @@ pub enum SyncConfig {
 Usually as a fallback after some number of attempts to use peers.*/
     ExternalStorage(ExternalStorageConfig),
 }
-impl ::std::convert::From<ExternalStorageConfig> for SyncConfig {
+#[cfg(feature = "client-config")]
+impl ::core::convert::From<ExternalStorageConfig> for SyncConfig {
     fn from(value: ExternalStorageConfig) -> Self {
         Self::ExternalStorage(value)
     }
@@ -5288,46 +5348,51 @@ impl ::std::convert::From<ExternalStorageConfig> for SyncConfig {
 ///
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub struct Tier1ProxyView {
-    pub addr: ::std::string::String,
+    pub addr: ::alloc::string::String,
     pub peer_id: PublicKey,
 }
 /**Describes the expected behavior of the node regarding shard tracking.
 If the node is an active validator, it will also track the shards it is responsible for as a validator.*/
 ///
+#[cfg(feature = "client-config")]
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub enum TrackedShardsConfig {
     ///Tracks no shards (light client).
     NoShards,
     ///Tracks arbitrary shards.
-    Shards(::std::vec::Vec<ShardUId>),
+    Shards(::alloc::vec::Vec<ShardUId>),
     ///Tracks all shards.
     AllShards,
     ///Tracks shards that are assigned to given validator account.
     ShadowValidator(AccountId),
     /**Rotate between these sets of tracked shards.
 Used to simulate the behavior of chunk only producers without staking tokens.*/
-    Schedule(::std::vec::Vec<::std::vec::Vec<ShardId>>),
+    Schedule(::alloc::vec::Vec<::alloc::vec::Vec<ShardId>>),
     ///Tracks shards that contain one of the given account.
-    Accounts(::std::vec::Vec<AccountId>),
+    Accounts(::alloc::vec::Vec<AccountId>),
 }
-impl ::std::convert::From<::std::vec::Vec<ShardUId>> for TrackedShardsConfig {
-    fn from(value: ::std::vec::Vec<ShardUId>) -> Self {
+#[cfg(feature = "client-config")]
+impl ::core::convert::From<::alloc::vec::Vec<ShardUId>> for TrackedShardsConfig {
+    fn from(value: ::alloc::vec::Vec<ShardUId>) -> Self {
         Self::Shards(value)
     }
 }
-impl ::std::convert::From<AccountId> for TrackedShardsConfig {
+#[cfg(feature = "client-config")]
+impl ::core::convert::From<AccountId> for TrackedShardsConfig {
     fn from(value: AccountId) -> Self {
         Self::ShadowValidator(value)
     }
 }
-impl ::std::convert::From<::std::vec::Vec<::std::vec::Vec<ShardId>>>
+#[cfg(feature = "client-config")]
+impl ::core::convert::From<::alloc::vec::Vec<::alloc::vec::Vec<ShardId>>>
 for TrackedShardsConfig {
-    fn from(value: ::std::vec::Vec<::std::vec::Vec<ShardId>>) -> Self {
+    fn from(value: ::alloc::vec::Vec<::alloc::vec::Vec<ShardId>>) -> Self {
         Self::Schedule(value)
     }
 }
-impl ::std::convert::From<::std::vec::Vec<AccountId>> for TrackedShardsConfig {
-    fn from(value: ::std::vec::Vec<AccountId>) -> Self {
+#[cfg(feature = "client-config")]
+impl ::core::convert::From<::alloc::vec::Vec<AccountId>> for TrackedShardsConfig {
+    fn from(value: ::alloc::vec::Vec<AccountId>) -> Self {
         Self::Accounts(value)
     }
 }
@@ -5355,12 +5420,12 @@ pub enum TxExecutionError {
     ///An error happened during Transaction execution
     InvalidTxError(InvalidTxError),
 }
-impl ::std::convert::From<ActionError> for TxExecutionError {
+impl ::core::convert::From<ActionError> for TxExecutionError {
     fn from(value: ActionError) -> Self {
         Self::ActionError(value)
     }
 }
-impl ::std::convert::From<InvalidTxError> for TxExecutionError {
+impl ::core::convert::From<InvalidTxError> for TxExecutionError {
     fn from(value: InvalidTxError) -> Self {
         Self::InvalidTxError(value)
     }
@@ -5404,8 +5469,8 @@ Execution of all transaction receipts is finalized, including refund receipts*/
     #[serde(rename = "FINAL")]
     Final,
 }
-impl ::std::fmt::Display for TxExecutionStatus {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for TxExecutionStatus {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         match *self {
             Self::None => f.write_str("NONE"),
             Self::Included => f.write_str("INCLUDED"),
@@ -5416,11 +5481,11 @@ impl ::std::fmt::Display for TxExecutionStatus {
         }
     }
 }
-impl ::std::str::FromStr for TxExecutionStatus {
+impl ::core::str::FromStr for TxExecutionStatus {
     type Err = self::error::ConversionError;
     fn from_str(
         value: &str,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
         match value {
             "NONE" => Ok(Self::None),
             "INCLUDED" => Ok(Self::Included),
@@ -5432,27 +5497,27 @@ impl ::std::str::FromStr for TxExecutionStatus {
         }
     }
 }
-impl ::std::convert::TryFrom<&str> for TxExecutionStatus {
+impl ::core::convert::TryFrom<&str> for TxExecutionStatus {
     type Error = self::error::ConversionError;
     fn try_from(
         value: &str,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
         value.parse()
     }
 }
-impl ::std::convert::TryFrom<&::std::string::String> for TxExecutionStatus {
+impl ::core::convert::TryFrom<&::alloc::string::String> for TxExecutionStatus {
     type Error = self::error::ConversionError;
     fn try_from(
-        value: &::std::string::String,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value: &::alloc::string::String,
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
         value.parse()
     }
 }
-impl ::std::convert::TryFrom<::std::string::String> for TxExecutionStatus {
+impl ::core::convert::TryFrom<::alloc::string::String> for TxExecutionStatus {
     type Error = self::error::ConversionError;
     fn try_from(
-        value: ::std::string::String,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value: ::alloc::string::String,
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
         value.parse()
     }
 }
@@ -5505,7 +5570,7 @@ pub struct ValidatorStakeView {
     pub account_id: AccountId,
     pub public_key: PublicKey,
     pub stake: NearToken,
-    pub validator_stake_struct_version: ::std::string::String,
+    pub validator_stake_struct_version: ::alloc::string::String,
 }
 ///`ValidatorStakeViewV1`
 ///
@@ -5519,20 +5584,22 @@ pub struct ValidatorStakeViewV1 {
 ///
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 #[serde(transparent)]
-pub struct ValidatorStakeViews(pub ::std::vec::Vec<ValidatorStakeView>);
-impl ::std::ops::Deref for ValidatorStakeViews {
-    type Target = ::std::vec::Vec<ValidatorStakeView>;
-    fn deref(&self) -> &::std::vec::Vec<ValidatorStakeView> {
+pub struct ValidatorStakeViews(pub ::alloc::vec::Vec<ValidatorStakeView>);
+impl ::core::ops::Deref for ValidatorStakeViews {
+    type Target = ::alloc::vec::Vec<ValidatorStakeView>;
+    fn deref(&self) -> &::alloc::vec::Vec<ValidatorStakeView> {
         &self.0
     }
 }
-impl ::std::convert::From<ValidatorStakeViews> for ::std::vec::Vec<ValidatorStakeView> {
+impl ::core::convert::From<ValidatorStakeViews>
+for ::alloc::vec::Vec<ValidatorStakeView> {
     fn from(value: ValidatorStakeViews) -> Self {
         value.0
     }
 }
-impl ::std::convert::From<::std::vec::Vec<ValidatorStakeView>> for ValidatorStakeViews {
-    fn from(value: ::std::vec::Vec<ValidatorStakeView>) -> Self {
+impl ::core::convert::From<::alloc::vec::Vec<ValidatorStakeView>>
+for ValidatorStakeViews {
+    fn from(value: ::alloc::vec::Vec<ValidatorStakeView>) -> Self {
         Self(value)
     }
 }
@@ -5540,19 +5607,19 @@ impl ::std::convert::From<::std::vec::Vec<ValidatorStakeView>> for ValidatorStak
 ///
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub struct Version {
-    pub build: ::std::string::String,
-    pub commit: ::std::string::String,
+    pub build: ::alloc::string::String,
+    pub commit: ::alloc::string::String,
     #[serde(default)]
-    pub rustc_version: ::std::string::String,
-    pub version: ::std::string::String,
+    pub rustc_version: ::alloc::string::String,
+    pub version: ::alloc::string::String,
 }
 ///Resulting state values for a view state query request
 ///
 #[derive(::serde::Deserialize, ::serde::Serialize, Clone, Debug)]
 pub struct ViewStateResult {
-    #[serde(default, skip_serializing_if = "::std::vec::Vec::is_empty")]
-    pub proof: ::std::vec::Vec<::std::string::String>,
-    pub values: ::std::vec::Vec<StateItem>,
+    #[serde(default, skip_serializing_if = "::alloc::vec::Vec::is_empty")]
+    pub proof: ::alloc::vec::Vec<::alloc::string::String>,
+    pub values: ::alloc::vec::Vec<StateItem>,
 }
 ///`VmConfigView`
 ///
@@ -5616,8 +5683,8 @@ pub enum VmKind {
     ///NearVM.
     NearVm,
 }
-impl ::std::fmt::Display for VmKind {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for VmKind {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         match *self {
             Self::Wasmer0 => f.write_str("Wasmer0"),
             Self::Wasmtime => f.write_str("Wasmtime"),
@@ -5626,11 +5693,11 @@ impl ::std::fmt::Display for VmKind {
         }
     }
 }
-impl ::std::str::FromStr for VmKind {
+impl ::core::str::FromStr for VmKind {
     type Err = self::error::ConversionError;
     fn from_str(
         value: &str,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
         match value {
             "Wasmer0" => Ok(Self::Wasmer0),
             "Wasmtime" => Ok(Self::Wasmtime),
@@ -5640,27 +5707,27 @@ impl ::std::str::FromStr for VmKind {
         }
     }
 }
-impl ::std::convert::TryFrom<&str> for VmKind {
+impl ::core::convert::TryFrom<&str> for VmKind {
     type Error = self::error::ConversionError;
     fn try_from(
         value: &str,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
         value.parse()
     }
 }
-impl ::std::convert::TryFrom<&::std::string::String> for VmKind {
+impl ::core::convert::TryFrom<&::alloc::string::String> for VmKind {
     type Error = self::error::ConversionError;
     fn try_from(
-        value: &::std::string::String,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value: &::alloc::string::String,
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
         value.parse()
     }
 }
-impl ::std::convert::TryFrom<::std::string::String> for VmKind {
+impl ::core::convert::TryFrom<::alloc::string::String> for VmKind {
     type Error = self::error::ConversionError;
     fn try_from(
-        value: ::std::string::String,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value: ::alloc::string::String,
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
         value.parse()
     }
 }
@@ -5699,8 +5766,8 @@ pub enum WasmTrap {
     ///Generic trap.
     GenericTrap,
 }
-impl ::std::fmt::Display for WasmTrap {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl ::core::fmt::Display for WasmTrap {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         match *self {
             Self::Unreachable => f.write_str("Unreachable"),
             Self::IncorrectCallIndirectSignature => {
@@ -5716,11 +5783,11 @@ impl ::std::fmt::Display for WasmTrap {
         }
     }
 }
-impl ::std::str::FromStr for WasmTrap {
+impl ::core::str::FromStr for WasmTrap {
     type Err = self::error::ConversionError;
     fn from_str(
         value: &str,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
         match value {
             "Unreachable" => Ok(Self::Unreachable),
             "IncorrectCallIndirectSignature" => Ok(Self::IncorrectCallIndirectSignature),
@@ -5735,27 +5802,27 @@ impl ::std::str::FromStr for WasmTrap {
         }
     }
 }
-impl ::std::convert::TryFrom<&str> for WasmTrap {
+impl ::core::convert::TryFrom<&str> for WasmTrap {
     type Error = self::error::ConversionError;
     fn try_from(
         value: &str,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
         value.parse()
     }
 }
-impl ::std::convert::TryFrom<&::std::string::String> for WasmTrap {
+impl ::core::convert::TryFrom<&::alloc::string::String> for WasmTrap {
     type Error = self::error::ConversionError;
     fn try_from(
-        value: &::std::string::String,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value: &::alloc::string::String,
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
         value.parse()
     }
 }
-impl ::std::convert::TryFrom<::std::string::String> for WasmTrap {
+impl ::core::convert::TryFrom<::alloc::string::String> for WasmTrap {
     type Error = self::error::ConversionError;
     fn try_from(
-        value: ::std::string::String,
-    ) -> ::std::result::Result<Self, self::error::ConversionError> {
+        value: ::alloc::string::String,
+    ) -> ::core::result::Result<Self, self::error::ConversionError> {
         value.parse()
     }
 }
@@ -5785,10 +5852,12 @@ After this limit is reached we defer execution of any new receipts.*/
 }
 /// Generation of default values for serde.
 pub mod defaults {
+    use ::alloc::string::ToString;
+    #[cfg(any(feature = "client-config", feature = "genesis-config"))]
     pub(super) fn default_u64<T, const V: u64>() -> T
     where
-        T: ::std::convert::TryFrom<u64>,
-        <T as ::std::convert::TryFrom<u64>>::Error: ::std::fmt::Debug,
+        T: ::core::convert::TryFrom<u64>,
+        <T as ::core::convert::TryFrom<u64>>::Error: ::core::fmt::Debug,
     {
         T::try_from(V).unwrap()
     }
@@ -5804,6 +5873,7 @@ pub mod defaults {
     pub(super) fn chunk_header_view_validator_reward() -> super::NearToken {
         super::NearToken("0".to_string())
     }
+    #[cfg(feature = "client-config")]
     pub(super) fn cloud_archival_writer_config_polling_interval() -> super::DurationAsStdSchemaProvider {
         super::DurationAsStdSchemaProvider {
             nanos: 0_i32,
@@ -5812,38 +5882,44 @@ pub mod defaults {
     }
     pub(super) fn execution_outcome_view_metadata() -> super::ExecutionMetadataView {
         super::ExecutionMetadataView {
-            gas_profile: ::std::option::Option::None,
+            gas_profile: ::core::option::Option::None,
             version: 1_u32,
         }
     }
+    #[cfg(feature = "client-config")]
     pub(super) fn gc_config_gc_step_period() -> super::DurationAsStdSchemaProvider {
         super::DurationAsStdSchemaProvider {
             nanos: 500000000_i32,
             secs: 0_i64,
         }
     }
+    #[cfg(feature = "genesis-config")]
     pub(super) fn genesis_config_minimum_stake_ratio() -> [i32; 2usize] {
         [1_i32, 6250_i32]
     }
+    #[cfg(feature = "genesis-config")]
     pub(super) fn genesis_config_online_max_threshold() -> [i32; 2usize] {
         [99_i32, 100_i32]
     }
+    #[cfg(feature = "genesis-config")]
     pub(super) fn genesis_config_online_min_threshold() -> [i32; 2usize] {
         [9_i32, 10_i32]
     }
+    #[cfg(feature = "genesis-config")]
     pub(super) fn genesis_config_protocol_upgrade_stake_threshold() -> [i32; 2usize] {
         [4_i32, 5_i32]
     }
+    #[cfg(feature = "genesis-config")]
     pub(super) fn genesis_config_shard_layout() -> super::ShardLayout {
         super::ShardLayout::V2(super::ShardLayoutV2 {
-            boundary_accounts: vec![],
+            boundary_accounts: ::alloc::vec![],
             id_to_index_map: [("0".to_string(), 0_u32)].into_iter().collect(),
             index_to_id_map: [("0".to_string(), super::ShardId(0_u64))]
                 .into_iter()
                 .collect(),
-            shard_ids: vec![super::ShardId(0_u64)],
-            shards_parent_map: ::std::option::Option::None,
-            shards_split_map: ::std::option::Option::None,
+            shard_ids: ::alloc::vec![super::ShardId(0_u64)],
+            shards_parent_map: ::core::option::Option::None,
+            shards_split_map: ::core::option::Option::None,
             version: 0_u32,
         })
     }
@@ -0,0 +1,46 @@
+//! Generated Rust types for NEAR Protocol JSON-RPC API.
+//!
+//! All RPC request/response types, generated by typify from the OpenRPC schema. This crate
+//! only depends on `serde` and `serde_json`, and is `no_std` with `alloc` without the
+//! default `std` feature, for signers and wallets that do not need the client of
+//! `near-rpc-client`, which re-exports these types.
+//!
+//! # Features
+//!
+//! - `std` (default): Implement the `std` traits of `serde` and `serde_json`
+//! - `chrono` (default): [`DateTime`] fields, e.g. `genesis_time`, are `chrono::DateTime<Utc>`
+//!   rather than RFC 3339 strings
+//! - `genesis-config` (default): [`GenesisConfig`], the response of `genesis_config`, and the
+//!   types only it uses
+//! - `client-config` (default): [`RpcClientConfigResponse`], the response of `client_config`,
+//!   and the types only it uses
+//!
+//! Do not edit `generated.rs` manually: `build.rs` regenerates it from `openrpc.json`.
+
+#![no_std]
+#![allow(clippy::all)]
+
+extern crate alloc;
+
+/// A `date-time` of the schema, with the `chrono` feature
+#[cfg(feature = "chrono")]
+pub type DateTime = chrono::DateTime<chrono::Utc>;
+
+/// A `date-time` of the schema, as an RFC 3339 string without the `chrono` feature
+#[cfg(not(feature = "chrono"))]
+pub type DateTime = alloc::string::String;
+
+include!("generated.rs");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pattern_check() {
+        assert!("12".parse::<CatchupStatusViewShardSyncStatusKey>().is_ok());
+        for key in ["", "1a", "-1"] {
+            assert!(key.parse::<CatchupStatusViewShardSyncStatusKey>().is_err());
+        }
+    }
+}